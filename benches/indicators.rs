@@ -18,6 +18,11 @@ fn bench_indicator<T: IndicatorConfig + Default>(b: &mut test::Bencher) {
 	b.iter(|| indicator.next(iter.next().unwrap()))
 }
 
+#[bench]
+fn bench_adaptive_relative_strength_index(b: &mut test::Bencher) {
+	bench_indicator::<Adaptive<RSI>>(b);
+}
+
 #[bench]
 fn bench_indicator_aroon(b: &mut test::Bencher) {
 	bench_indicator::<Aroon>(b);
@@ -83,6 +88,26 @@ fn bench_ease_of_movement(b: &mut test::Bencher) {
 	bench_indicator::<EaseOfMovement>(b);
 }
 
+#[bench]
+fn bench_ehlers_cyber_cycle(b: &mut test::Bencher) {
+	bench_indicator::<EhlersCyberCycle>(b);
+}
+
+#[bench]
+fn bench_ehlers_decycler(b: &mut test::Bencher) {
+	bench_indicator::<EhlersDecycler>(b);
+}
+
+#[bench]
+fn bench_ehlers_instantaneous_trendline(b: &mut test::Bencher) {
+	bench_indicator::<EhlersInstantaneousTrendline>(b);
+}
+
+#[bench]
+fn bench_ehlers_roofing_filter(b: &mut test::Bencher) {
+	bench_indicator::<EhlersRoofingFilter>(b);
+}
+
 #[bench]
 fn bench_elders_force_index(b: &mut test::Bencher) {
 	bench_indicator::<EldersForceIndex>(b);
@@ -128,11 +153,21 @@ fn bench_know_sure_thing(b: &mut test::Bencher) {
 	bench_indicator::<KnowSureThing>(b);
 }
 
+#[bench]
+fn bench_linear_regression_channel(b: &mut test::Bencher) {
+	bench_indicator::<LinearRegressionChannel>(b);
+}
+
 #[bench]
 fn bench_macd(b: &mut test::Bencher) {
 	bench_indicator::<MACD>(b);
 }
 
+#[bench]
+fn bench_mesa_adaptive_moving_average(b: &mut test::Bencher) {
+	bench_indicator::<MESAAdaptiveMovingAverage>(b);
+}
+
 #[bench]
 fn bench_momentum_index(b: &mut test::Bencher) {
 	bench_indicator::<MomentumIndex>(b);
@@ -52,6 +52,203 @@ fn bench_conv_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// Butterworth -----------------------------------------------------------------------------------
+#[bench]
+fn bench_butterworth_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Butterworth::new((10, 4), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_butterworth_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Butterworth::new((100, 4), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Chebyshev -----------------------------------------------------------------------------------
+#[bench]
+fn bench_chebyshev_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Chebyshev::new((10, 4, 0.5), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_chebyshev_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Chebyshev::new((100, 4, 0.5), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// SuperSmoother -----------------------------------------------------------------------------------
+#[bench]
+fn bench_super_smoother_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SuperSmoother::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_super_smoother_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SuperSmoother::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RoofingFilter -----------------------------------------------------------------------------------
+#[bench]
+fn bench_roofing_filter_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RoofingFilter::new((10, 5), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_roofing_filter_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RoofingFilter::new((100, 10), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Decycler -----------------------------------------------------------------------------------
+#[bench]
+fn bench_decycler_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Decycler::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_decycler_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Decycler::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// CyberCycle -----------------------------------------------------------------------------------
+#[bench]
+fn bench_cyber_cycle_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CyberCycle::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_cyber_cycle_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CyberCycle::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// InstantaneousTrendline -----------------------------------------------------------------------------------
+#[bench]
+fn bench_instantaneous_trendline_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = InstantaneousTrendline::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_instantaneous_trendline_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = InstantaneousTrendline::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// MAMA -----------------------------------------------------------------------------------
+#[bench]
+fn bench_mama(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = MAMA::new((0.5, 0.05), &candles[0]).unwrap();
+	for _ in 0..50 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// DominantCycle ---------------------------------------------------------------------------
+#[bench]
+fn bench_dominant_cycle_homodyne(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = DominantCycle::new((CycleEstimator::Homodyne, 10, 48), &candles[0]).unwrap();
+	for _ in 0..50 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_dominant_cycle_autocorrelation(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method =
+		DominantCycle::new((CycleEstimator::Autocorrelation, 10, 48), &candles[0]).unwrap();
+	for _ in 0..50 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // Cross -----------------------------------------------------------------------------------
 #[bench]
 fn bench_cross(b: &mut test::Bencher) {
@@ -89,6 +286,130 @@ fn bench_cross_under(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// Covariance -----------------------------------------------------------------------------------
+#[bench]
+fn bench_covariance_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Covariance::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_covariance_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Covariance::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Correlation -----------------------------------------------------------------------------------
+#[bench]
+fn bench_correlation_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Correlation::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_correlation_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Correlation::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Beta -----------------------------------------------------------------------------------
+#[bench]
+fn bench_beta_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Beta::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_beta_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Beta::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// SpearmanCorrelation -----------------------------------------------------------------------------------
+#[bench]
+fn bench_spearman_correlation_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SpearmanCorrelation::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_spearman_correlation_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SpearmanCorrelation::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // SMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_sma_w10(b: &mut test::Bencher) {
@@ -252,10 +573,126 @@ fn bench_tema_w100(b: &mut test::Bencher) {
 
 // SMM -----------------------------------------------------------------------------------
 #[bench]
-fn bench_smm_w10(b: &mut test::Bencher) {
+fn bench_smm_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SMM::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_smm_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SMM::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Quantile -----------------------------------------------------------------------------------
+#[bench]
+fn bench_quantile_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Quantile::new((10, 0.95, Interpolation::Linear), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_quantile_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Quantile::new((100, 0.95, Interpolation::Linear), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_quantile_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Quantile::new((1000, 0.95, Interpolation::Linear), &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_quantile_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Quantile::new((10000, 0.95, Interpolation::Linear), &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// PercentRank -----------------------------------------------------------------------------------
+#[bench]
+fn bench_percent_rank_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = PercentRank::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_percent_rank_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = PercentRank::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// HMA -----------------------------------------------------------------------------------
+#[bench]
+fn bench_hma_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HMA::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_hma_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HMA::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// LinReg -----------------------------------------------------------------------------------
+#[bench]
+fn bench_lin_reg_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = SMM::new(10, &candles[0]).unwrap();
+	let mut method = LinReg::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
 		method.next(iter.next().unwrap());
 	}
@@ -263,22 +700,22 @@ fn bench_smm_w10(b: &mut test::Bencher) {
 }
 
 #[bench]
-fn bench_smm_w100(b: &mut test::Bencher) {
+fn bench_lin_reg_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = SMM::new(100, &candles[0]).unwrap();
+	let mut method = LinReg::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
-// HMA -----------------------------------------------------------------------------------
+// PolyReg -----------------------------------------------------------------------------------
 #[bench]
-fn bench_hma_w10(b: &mut test::Bencher) {
+fn bench_poly_reg_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = HMA::new(10, &candles[0]).unwrap();
+	let mut method = PolyReg::new((10, 2, 5), &candles[0]).unwrap();
 	for _ in 0..10 {
 		method.next(iter.next().unwrap());
 	}
@@ -286,22 +723,22 @@ fn bench_hma_w10(b: &mut test::Bencher) {
 }
 
 #[bench]
-fn bench_hma_w100(b: &mut test::Bencher) {
+fn bench_poly_reg_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = HMA::new(100, &candles[0]).unwrap();
+	let mut method = PolyReg::new((100, 2, 5), &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
-// LinReg -----------------------------------------------------------------------------------
+// SavitzkyGolay -----------------------------------------------------------------------------------
 #[bench]
-fn bench_lin_reg_w10(b: &mut test::Bencher) {
+fn bench_savitzky_golay_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = LinReg::new(10, &candles[0]).unwrap();
+	let mut method = SavitzkyGolay::new((10, 2, 1), &candles[0]).unwrap();
 	for _ in 0..10 {
 		method.next(iter.next().unwrap());
 	}
@@ -309,10 +746,10 @@ fn bench_lin_reg_w10(b: &mut test::Bencher) {
 }
 
 #[bench]
-fn bench_lin_reg_w100(b: &mut test::Bencher) {
+fn bench_savitzky_golay_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = LinReg::new(100, &candles[0]).unwrap();
+	let mut method = SavitzkyGolay::new((100, 2, 1), &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
@@ -549,6 +986,98 @@ fn bench_st_dev_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// ZScore -----------------------------------------------------------------------------------
+#[bench]
+fn bench_z_score_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZScore::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_z_score_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZScore::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Skewness -----------------------------------------------------------------------------------
+#[bench]
+fn bench_skewness_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Skewness::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_skewness_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Skewness::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Kurtosis -----------------------------------------------------------------------------------
+#[bench]
+fn bench_kurtosis_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Kurtosis::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_kurtosis_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Kurtosis::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Entropy -----------------------------------------------------------------------------------
+#[bench]
+fn bench_entropy_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Entropy::new((10, Binning::Width(0.01)), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_entropy_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Entropy::new((100, Binning::Width(0.01)), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // SWMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_swma_w10(b: &mut test::Bencher) {
@@ -672,6 +1201,30 @@ fn bench_highest_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Highest::new(1000, &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Highest::new(10000, &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // Lowest -----------------------------------------------------------------------------------
 #[bench]
 fn bench_lowest_w10(b: &mut test::Bencher) {
@@ -695,6 +1248,30 @@ fn bench_lowest_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_lowest_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Lowest::new(1000, &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_lowest_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Lowest::new(10000, &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // HighestLowestDelta -----------------------------------------------------------------------------------
 #[bench]
 fn bench_highest_lowest_delta_w10(b: &mut test::Bencher) {
@@ -711,19 +1288,43 @@ fn bench_highest_lowest_delta_w10(b: &mut test::Bencher) {
 fn bench_highest_lowest_delta_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = HighestLowestDelta::new(10, &candles[0]).unwrap();
+	let mut method = HighestLowestDelta::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_lowest_delta_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestLowestDelta::new(1000, &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_lowest_delta_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestLowestDelta::new(10000, &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // HighestIndex -----------------------------------------------------------------------------------
 #[bench]
 fn bench_highest_index_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Highest::new(10, &candles[0]).unwrap();
+	let mut method = HighestIndex::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
 		method.next(iter.next().unwrap());
 	}
@@ -734,19 +1335,43 @@ fn bench_highest_index_w10(b: &mut test::Bencher) {
 fn bench_highest_index_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Highest::new(100, &candles[0]).unwrap();
+	let mut method = HighestIndex::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_index_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestIndex::new(1000, &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_highest_index_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestIndex::new(10000, &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // LowestIndex -----------------------------------------------------------------------------------
 #[bench]
 fn bench_lowest_index_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Lowest::new(10, &candles[0]).unwrap();
+	let mut method = LowestIndex::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
 		method.next(iter.next().unwrap());
 	}
@@ -757,13 +1382,37 @@ fn bench_lowest_index_w10(b: &mut test::Bencher) {
 fn bench_lowest_index_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Lowest::new(100, &candles[0]).unwrap();
+	let mut method = LowestIndex::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
 		method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_lowest_index_w1000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(10000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = LowestIndex::new(1000, &candles[0]).unwrap();
+	for _ in 0..1000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[cfg(feature = "period_type_u16")]
+#[bench]
+fn bench_lowest_index_w10000(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(100000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = LowestIndex::new(10000, &candles[0]).unwrap();
+	for _ in 0..10000 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // MeanAbsDev -----------------------------------------------------------------------------------
 #[bench]
 fn bench_mean_abs_dev_w10(b: &mut test::Bencher) {
@@ -887,6 +1536,121 @@ fn bench_vidya_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// ALMA ----------------------------------------------------------------------------------
+#[bench]
+fn bench_alma_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ALMA::new((10, 0.85, 6.0), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_alma_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ALMA::new((100, 0.85, 6.0), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// T3 ------------------------------------------------------------------------------------
+#[bench]
+fn bench_t3_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = T3::new((10, 0.7), &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_t3_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = T3::new((100, 0.7), &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// ZLEMA ---------------------------------------------------------------------------------
+#[bench]
+fn bench_zlema_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZLEMA::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_zlema_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZLEMA::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// McGinley ------------------------------------------------------------------------------
+#[bench]
+fn bench_mcginley_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = McGinley::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_mcginley_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = McGinley::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// FRAMA ---------------------------------------------------------------------------------
+#[bench]
+fn bench_frama_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = FRAMA::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_frama_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = FRAMA::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 #[bench]
 fn bench_heikin_ashi(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
@@ -2,9 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
-use crate::core::{Error, ValueType, OHLCV};
+use crate::core::{Error, Timestamp, Timestamped, ValueType, OHLCV};
 
 /// Source enum represents common parts of a *Candle*
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
@@ -95,6 +96,12 @@ impl From<Source> for String {
 	}
 }
 
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str((*self).into())
+	}
+}
+
 /// Simple Candlestick structure for implementing [`OHLCV`]
 ///
 /// Can be also used by an alias [`Candlestick`]
@@ -233,9 +240,144 @@ impl<T: OHLCV> std::ops::Add<T> for Candle {
 	}
 }
 
+/// [`Candle`] with a [`Timestamp`] attached to it.
+///
+/// Implements both [`OHLCV`] and [`Timestamped`], so it is an [`OHLCVT`](crate::core::OHLCVT).
+///
+/// You may convert simple tuples of a timestamp and 5 float values into `TimedCandle`:
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::TimedCandle;
+/// //                   time  open  high  low  close  volume
+/// let my_candle = (1_000, 3.0,  5.0,  2.0, 4.0,   50.0 );
+/// let converted: TimedCandle = my_candle.into();
+///
+/// assert_eq!(converted.timestamp(), 1_000);
+/// assert_eq!(converted.close(), 4.0);
+/// ```
+///
+/// Or attach a timestamp to any existing [`OHLCV`]-object:
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::TimedCandle;
+///
+/// let candle: Candle = (3.0, 5.0, 2.0, 4.0, 50.0).into();
+/// let timed = TimedCandle::new(1_000, &candle);
+///
+/// assert_eq!(timed.candle, candle);
+/// ```
+///
+/// When `serde` feature is enabled, `TimedCandle` is (de)serialized as a flat structure with `timestamp`, `open`, `high`, `low`, `close` and `volume` fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedCandle {
+	/// Timestamp of the candle. Usually it is an *open time* of the candle.
+	pub timestamp: Timestamp,
+
+	/// OHLCV values of the candle
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub candle: Candle,
+}
+
+impl TimedCandle {
+	/// Creates `TimedCandle` from the `timestamp` and any another `OHLCV`-object.
+	pub fn new<T: OHLCV + ?Sized>(timestamp: Timestamp, src: &T) -> Self {
+		Self {
+			timestamp,
+			candle: Candle::from(src),
+		}
+	}
+}
+
+impl OHLCV for TimedCandle {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.candle.open
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.candle.high
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.candle.low
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.candle.close
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.candle.volume
+	}
+}
+
+impl Timestamped for TimedCandle {
+	#[inline]
+	fn timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+}
+
+impl From<TimedCandle> for Candle {
+	fn from(value: TimedCandle) -> Self {
+		value.candle
+	}
+}
+
+impl From<(Timestamp, Candle)> for TimedCandle {
+	fn from((timestamp, candle): (Timestamp, Candle)) -> Self {
+		Self { timestamp, candle }
+	}
+}
+
+impl
+	From<(
+		Timestamp,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+	)> for TimedCandle
+{
+	fn from(
+		value: (
+			Timestamp,
+			ValueType,
+			ValueType,
+			ValueType,
+			ValueType,
+			ValueType,
+		),
+	) -> Self {
+		Self {
+			timestamp: value.0,
+			candle: (value.1, value.2, value.3, value.4, value.5).into(),
+		}
+	}
+}
+
+/// Sum of two `TimedCandle`s keeps the `timestamp` of the left one.
+impl<T: OHLCV> std::ops::Add<T> for TimedCandle {
+	type Output = Self;
+
+	fn add(self, rhs: T) -> Self::Output {
+		Self {
+			candle: self.candle + rhs,
+			..self
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::Source;
+	use super::{Candle, Source, TimedCandle};
+	use crate::core::Timestamped;
 
 	#[test]
 	fn test_source_to_string_str() {
@@ -302,4 +444,28 @@ mod tests {
 
 		assert!(src.is_err());
 	}
+
+	#[test]
+	fn test_timed_candle() {
+		let candle: Candle = (3.0, 5.0, 2.0, 4.0, 50.0).into();
+		let timed: TimedCandle = (60, 3.0, 5.0, 2.0, 4.0, 50.0).into();
+
+		assert_eq!(timed, TimedCandle::new(60, &candle));
+		assert_eq!(timed, (60, candle).into());
+		assert_eq!(timed.timestamp(), 60);
+		assert_eq!(Into::<Candle>::into(timed), candle);
+		assert_eq!(Candle::from(&timed), candle);
+	}
+
+	#[test]
+	fn test_timed_candle_add() {
+		let a: TimedCandle = (60, 3.0, 5.0, 2.0, 4.0, 50.0).into();
+		let b: TimedCandle = (120, 4.0, 7.0, 3.0, 6.0, 20.0).into();
+
+		let c = a + b;
+
+		assert_eq!(c.timestamp(), 60);
+		assert_eq!(c.candle, a.candle + b.candle);
+		assert_eq!(c.candle, (3.0, 7.0, 2.0, 6.0, 70.0).into());
+	}
 }
@@ -8,12 +8,33 @@ pub enum Error {
 	/// Error parsing indicator parameter
 	ParameterParse(String, String),
 
+	/// Unknown indicator parameter name
+	UnknownParameter(String),
+
+	/// Unknown indicator name
+	UnknownIndicator(String),
+
+	/// Error parsing textual indicator specification at the given byte position
+	SpecParse(usize, String),
+
 	/// Error parsing moving average
 	MovingAverageParse,
 
 	/// Invalid parameters for method creation
 	WrongMethodParameters,
 
+	/// Parameter value violates some constraint
+	///
+	/// Displayed as `{name} {value} {constraint}`, f.e. "ma1 period 30 must be < ma2 period 26"
+	InvalidParameter {
+		/// Name of the parameter
+		name: String,
+		/// Value of the parameter
+		value: String,
+		/// Violated constraint
+		constraint: String,
+	},
+
 	/// Invalid indicator config error
 	WrongConfig,
 
@@ -24,6 +45,54 @@ pub enum Error {
 	Other(String),
 }
 
+impl Error {
+	/// Creates [`Error::InvalidParameter`]
+	///
+	/// ```
+	/// use yata::core::Error;
+	///
+	/// let error = Error::invalid_parameter("length", 0, "must be > 0");
+	/// assert_eq!(error.to_string(), "length 0 must be > 0");
+	/// ```
+	pub fn invalid_parameter(
+		name: impl Into<String>,
+		value: impl std::fmt::Display,
+		constraint: impl std::fmt::Display,
+	) -> Self {
+		Self::InvalidParameter {
+			name: name.into(),
+			value: value.to_string(),
+			constraint: constraint.to_string(),
+		}
+	}
+
+	/// Returns [`Error::InvalidParameter`] if the `condition` does not hold
+	///
+	/// Useful for implementing [`IndicatorConfig::validate_detailed`](crate::core::IndicatorConfig::validate_detailed).
+	/// The `constraint` is only formatted when the check fails, so `format_args!` may be used without any allocation.
+	///
+	/// ```
+	/// use yata::core::Error;
+	///
+	/// let (ma1, ma2) = (30, 26);
+	/// let result = Error::ensure(ma1 < ma2, "ma1 period", ma1, format_args!("must be < ma2 period {ma2}"));
+	///
+	/// assert_eq!(result.unwrap_err().to_string(), "ma1 period 30 must be < ma2 period 26");
+	/// ```
+	pub fn ensure(
+		condition: bool,
+		name: &str,
+		value: impl std::fmt::Display,
+		constraint: impl std::fmt::Display,
+	) -> Result<(), Self> {
+		if condition {
+			Ok(())
+		} else {
+			Err(Self::invalid_parameter(name, value, constraint))
+		}
+	}
+}
+
 impl std::fmt::Display for Error {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -31,7 +100,20 @@ impl std::fmt::Display for Error {
 			Self::ParameterParse(name, value) => {
 				write!(f, "Unable to parse into {name}: {value:?}")
 			}
+			Self::UnknownParameter(name) => write!(f, "Unknown parameter: {name:?}"),
+			Self::UnknownIndicator(name) => write!(f, "Unknown indicator: {name:?}"),
+			Self::SpecParse(position, reason) => {
+				write!(
+					f,
+					"Unable to parse indicator specification at {position}: {reason}"
+				)
+			}
 			Self::WrongMethodParameters => write!(f, "Wrong method parameters"),
+			Self::InvalidParameter {
+				name,
+				value,
+				constraint,
+			} => write!(f, "{name} {value} {constraint}"),
 			Self::WrongConfig => write!(f, "Wrong config"),
 			Self::InvalidCandles => write!(f, "Invalid candles"),
 			Self::Other(reason) => f.write_str(reason),
@@ -1,5 +1,6 @@
-use super::{IndicatorInstance, IndicatorResult};
+use super::{IndicatorInstance, IndicatorOutputs, IndicatorResult, ParamDescriptor};
 use crate::core::{Error, OHLCV};
+use crate::helpers::WithWarmup;
 
 /// Each indicator has it's own **Configuration** with parameters
 ///
@@ -14,23 +15,105 @@ pub trait IndicatorConfig: Clone {
 	/// Name of an indicator
 	const NAME: &'static str;
 
+	/// Descriptors of raw values and signals returned by the indicator
+	///
+	/// Must be consistent with [`size`](IndicatorConfig::size) when provided.
+	/// Empty by default, which means the outputs of the indicator are not described.
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[],
+		signals: &[],
+	};
+
+	/// Descriptors of all the parameters, which can be changed by [`set`](IndicatorConfig::set) and read by [`get`](IndicatorConfig::get)
+	///
+	/// Empty by default, which means the parameters of the indicator are not described.
+	const PARAMS: &'static [ParamDescriptor] = &[];
+
 	/// Validates if **Configuration** is OK
 	fn validate(&self) -> bool;
 
+	/// Validates **Configuration** and returns an error, which describes the first violated constraint
+	///
+	/// Indicators of this crate return [`Error::InvalidParameter`]. The default implementation
+	/// only returns [`Error::WrongConfig`] when [`validate`](IndicatorConfig::validate) fails.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	/// use yata::indicators::MACD;
+	///
+	/// let mut macd = MACD::default();
+	/// macd.set("ma1", "ema-30".to_string()).unwrap();
+	///
+	/// let error = macd.validate_detailed().unwrap_err();
+	/// assert_eq!(error.to_string(), "ma1 period 30 must be < ma2 period 26");
+	/// assert!(macd.init(&Candle::default()).is_err());
+	/// ```
+	fn validate_detailed(&self) -> Result<(), Error> {
+		if self.validate() {
+			Ok(())
+		} else {
+			Err(Error::WrongConfig)
+		}
+	}
+
 	/// Dynamically sets **Configuration** parameters
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
 
+	/// Dynamically gets **Configuration** parameters in the same format as [`set`](IndicatorConfig::set) accepts
+	///
+	/// By default returns [`Error::UnknownParameter`] for any parameter.
+	fn get(&self, name: &str) -> Result<String, Error> {
+		Err(Error::UnknownParameter(name.to_string()))
+	}
+
 	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw values, count of signals)`
 	fn size(&self) -> (u8, u8);
 
 	/// Initializes the **State** based on current **Configuration**
 	fn init<T: OHLCV>(self, initial_value: &T) -> Result<Self::Instance, Error>;
 
+	/// Initializes the **State** based on current **Configuration**, wrapped by warm-up tracker
+	///
+	/// See also [`WithWarmup`]
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+	/// let mut state = BollingerBands::default().init_with_warmup(&candles[0]).unwrap();
+	///
+	/// let results = state.over(&candles);
+	/// assert!(results[..19].iter().all(Option::is_none));
+	/// assert!(results[19..].iter().all(Option::is_some));
+	/// ```
+	fn init_with_warmup<T: OHLCV>(
+		self,
+		initial_value: &T,
+	) -> Result<WithWarmup<Self::Instance>, Error> {
+		WithWarmup::init(self, initial_value)
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::NAME
 	}
 
+	/// Returns descriptors of raw values and signals returned by the indicator
+	///
+	/// See more at [`IndicatorConfig::OUTPUTS`]
+	fn outputs(&self) -> IndicatorOutputs {
+		Self::OUTPUTS
+	}
+
+	/// Returns descriptors of all the parameters of the indicator
+	///
+	/// See more at [`IndicatorConfig::PARAMS`]
+	fn params(&self) -> &'static [ParamDescriptor] {
+		Self::PARAMS
+	}
+
 	/// Creates an `IndicatorInstance` function from this `IndicatorConfig`.
 	fn init_fn<'a, T: OHLCV>(
 		self,
@@ -71,4 +154,99 @@ pub trait IndicatorConfig: Clone {
 
 		Ok(IndicatorInstance::over(&mut state, inputs))
 	}
+
+	/// Evaluates indicator config over sequence of OHLC and returns sequence of `IndicatorResult`s,
+	/// dropping the results produced during the warm-up period
+	///
+	/// So the length of the output `Vec` is equal to the length of `inputs` minus [`warmup_len`](IndicatorInstance::warmup_len).
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(30).collect();
+	/// let results = BollingerBands::default().over_ready(&candles).unwrap();
+	/// assert_eq!(results.len(), 30 - 19);
+	/// ```
+	fn over_ready<T, S>(self, inputs: S) -> Result<Vec<IndicatorResult>, Error>
+	where
+		T: OHLCV,
+		S: AsRef<[T]>,
+		Self: Sized,
+	{
+		let inputs_ref = inputs.as_ref();
+
+		if inputs_ref.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let mut state = self.init(&inputs_ref[0])?;
+		let warmup_len = state.warmup_len();
+
+		Ok(inputs_ref
+			.iter()
+			.map(|x| state.next(x))
+			.skip(warmup_len)
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorConfig;
+	use crate::core::{Candle, Error, IndicatorInstance, IndicatorResult, OHLCV};
+
+	/// Indicator, which implements only the required items, as the indicators outside this crate may do
+	#[derive(Debug, Clone, Copy)]
+	struct Minimal;
+
+	#[derive(Debug)]
+	struct MinimalInstance(Minimal);
+
+	impl IndicatorConfig for Minimal {
+		type Instance = MinimalInstance;
+
+		const NAME: &'static str = "Minimal";
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(1, 0)
+		}
+
+		fn init<T: OHLCV>(self, _: &T) -> Result<Self::Instance, Error> {
+			Ok(MinimalInstance(self))
+		}
+	}
+
+	impl IndicatorInstance for MinimalInstance {
+		type Config = Minimal;
+
+		fn config(&self) -> &Self::Config {
+			&self.0
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			IndicatorResult::new(&[candle.close()], &[])
+		}
+	}
+
+	#[test]
+	fn test_indicator_config_defaults() {
+		assert_eq!(Minimal::OUTPUTS.size(), (0, 0));
+		assert!(Minimal::PARAMS.is_empty());
+		assert!(matches!(
+			Minimal.get("period"),
+			Err(Error::UnknownParameter(name)) if name == "period"
+		));
+
+		let mut instance = Minimal.init(&Candle::default()).unwrap();
+		assert_eq!(instance.next(&Candle::default()).size(), (1, 0));
+	}
 }
@@ -1,4 +1,6 @@
-use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use super::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, ParamDescriptor,
+};
 use crate::core::{Error, OHLCV};
 
 /// Dynamically dispatchable [`IndicatorConfig`](crate::core::IndicatorConfig)
@@ -20,17 +22,41 @@ pub trait IndicatorConfigDyn<T: OHLCV> {
 	/// ```
 	fn over(&self, inputs: &dyn AsRef<[T]>) -> Result<Vec<IndicatorResult>, Error>;
 
+	/// Evaluates dynamically dispatched [`IndicatorConfig`](crate::core::IndicatorConfig) over series of OHLC and returns series of `IndicatorResult`s,
+	/// dropping the results produced during the warm-up period
+	///
+	/// See more at [`IndicatorConfig::over_ready`](crate::core::IndicatorConfig::over_ready)
+	fn over_ready(&self, inputs: &dyn AsRef<[T]>) -> Result<Vec<IndicatorResult>, Error>;
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str;
 
 	/// Validates if **Configuration** is OK
 	fn validate(&self) -> bool;
 
+	/// Validates **Configuration** and returns an error, which describes the first violated constraint
+	///
+	/// See more at [`IndicatorConfig::validate_detailed`](crate::core::IndicatorConfig::validate_detailed)
+	fn validate_detailed(&self) -> Result<(), Error>;
+
 	/// Dynamically sets **Configuration** parameters
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error>;
 
+	/// Dynamically gets **Configuration** parameters
+	fn get(&self, name: &str) -> Result<String, Error>;
+
+	/// Returns descriptors of all the parameters of the indicator
+	///
+	/// See more at [`IndicatorConfig::PARAMS`](crate::core::IndicatorConfig::PARAMS)
+	fn params(&self) -> &'static [ParamDescriptor];
+
 	/// Returns an [`IndicatorResult`](crate::core::IndicatorResult) size processing by the indicator `(count of raw values, count of signals)`
 	fn size(&self) -> (u8, u8);
+
+	/// Returns descriptors of raw values and signals returned by the indicator
+	///
+	/// See more at [`IndicatorConfig::OUTPUTS`](crate::core::IndicatorConfig::OUTPUTS)
+	fn outputs(&self) -> IndicatorOutputs;
 }
 
 impl<T, I, C> IndicatorConfigDyn<T> for C
@@ -48,6 +74,10 @@ where
 		IndicatorConfig::over(self.clone(), inputs)
 	}
 
+	fn over_ready(&self, inputs: &dyn AsRef<[T]>) -> Result<Vec<IndicatorResult>, Error> {
+		IndicatorConfig::over_ready(self.clone(), inputs)
+	}
+
 	fn name(&self) -> &'static str {
 		<Self as IndicatorConfig>::NAME
 	}
@@ -56,13 +86,29 @@ where
 		IndicatorConfig::validate(self)
 	}
 
+	fn validate_detailed(&self) -> Result<(), Error> {
+		IndicatorConfig::validate_detailed(self)
+	}
+
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		IndicatorConfig::set(self, name, value)
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		IndicatorConfig::get(self, name)
+	}
+
+	fn params(&self) -> &'static [ParamDescriptor] {
+		<Self as IndicatorConfig>::PARAMS
+	}
+
 	fn size(&self) -> (u8, u8) {
 		IndicatorConfig::size(self)
 	}
+
+	fn outputs(&self) -> IndicatorOutputs {
+		<Self as IndicatorConfig>::OUTPUTS
+	}
 }
 
 /// Dynamically dispatchable [`IndicatorInstance`](crate::core::IndicatorInstance)
@@ -94,8 +140,18 @@ pub trait IndicatorInstanceDyn<T: OHLCV> {
 	/// See more at [`IndicatorConfig`](crate::core::IndicatorConfig::size)
 	fn size(&self) -> (u8, u8);
 
+	/// Returns descriptors of raw values and signals returned by the indicator
+	///
+	/// See more at [`IndicatorConfig::OUTPUTS`](crate::core::IndicatorConfig::OUTPUTS)
+	fn outputs(&self) -> IndicatorOutputs;
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str;
+
+	/// Returns count of the leading results, which are affected by the seeded initial value
+	///
+	/// See more at [`IndicatorInstance::warmup_len`](crate::core::IndicatorInstance::warmup_len)
+	fn warmup_len(&self) -> usize;
 }
 
 impl<T, I> IndicatorInstanceDyn<T> for I
@@ -119,7 +175,15 @@ where
 		IndicatorInstance::size(self)
 	}
 
+	fn outputs(&self) -> IndicatorOutputs {
+		IndicatorInstance::outputs(self)
+	}
+
 	fn name(&self) -> &'static str {
 		IndicatorInstance::name(self)
 	}
+
+	fn warmup_len(&self) -> usize {
+		IndicatorInstance::warmup_len(self)
+	}
 }
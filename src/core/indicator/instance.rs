@@ -1,4 +1,4 @@
-use super::{IndicatorConfig, IndicatorResult};
+use super::{IndicatorConfig, IndicatorOutputs, IndicatorResult};
 use crate::core::OHLCV;
 
 /// Base trait for implementing indicators **State**
@@ -12,6 +12,25 @@ pub trait IndicatorInstance: Sized {
 	/// Evaluates given candle and returns [`IndicatorResult`](crate::core::IndicatorResult)
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult;
 
+	/// Returns count of the leading results, which are affected by the seeded initial value instead of a real history of candles.
+	///
+	/// The count is always taken from the moment of initializing the **State**.
+	///
+	/// See more at [`Method::warmup_len`](crate::core::Method::warmup_len)
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::{RandomCandles};
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let state = BollingerBands::default().init(&candles[0]).unwrap();
+	///
+	/// assert_eq!(state.warmup_len(), 19);
+	/// ```
+	fn warmup_len(&self) -> usize {
+		0
+	}
+
 	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s.
 	/// ```
 	/// use yata::prelude::*;
@@ -42,6 +61,13 @@ pub trait IndicatorInstance: Sized {
 		self.config().size()
 	}
 
+	/// Returns descriptors of raw values and signals returned by the indicator
+	///
+	/// See more at [`IndicatorConfig::OUTPUTS`](crate::core::IndicatorConfig::OUTPUTS)
+	fn outputs(&self) -> IndicatorOutputs {
+		Self::Config::OUTPUTS
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::Config::NAME
@@ -7,9 +7,13 @@
 mod config;
 mod dd;
 mod instance;
+mod output;
+mod params;
 mod result;
 
 pub use config::*;
 pub use dd::*;
 pub use instance::*;
+pub use output::*;
+pub use params::*;
 pub use result::*;
@@ -0,0 +1,138 @@
+use crate::core::ValueType;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Describes how the output of an indicator should be interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum OutputKind {
+	/// Value is in the same scale as the prices, so it can be plotted over the candles
+	Price,
+
+	/// Value is an oscillator with its own scale
+	Oscillator,
+
+	/// Value is in the same scale as the volume (or volume multiplied by price)
+	Volume,
+}
+
+/// Range of possible output values
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum OutputRange {
+	/// Value has no fixed bounds
+	Unbounded,
+
+	/// Value is always in \[`min`; `max`\]
+	Bounded {
+		/// Lower bound of the value
+		min: ValueType,
+		/// Upper bound of the value
+		max: ValueType,
+	},
+}
+
+/// Describes a single raw value or a signal of the [`IndicatorResult`](crate::core::IndicatorResult)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OutputDescriptor {
+	/// Name of the output
+	pub name: &'static str,
+
+	/// Kind of the output
+	pub kind: OutputKind,
+
+	/// Range of the output
+	pub range: OutputRange,
+}
+
+impl OutputDescriptor {
+	/// Creates a new descriptor
+	#[must_use]
+	pub const fn new(name: &'static str, kind: OutputKind, range: OutputRange) -> Self {
+		Self { name, kind, range }
+	}
+
+	/// Creates a descriptor for an unbounded price-scaled value
+	#[must_use]
+	pub const fn price(name: &'static str) -> Self {
+		Self::new(name, OutputKind::Price, OutputRange::Unbounded)
+	}
+
+	/// Creates a descriptor for an oscillator value in \[`min`; `max`\]
+	#[must_use]
+	pub const fn oscillator(name: &'static str, min: ValueType, max: ValueType) -> Self {
+		Self::new(
+			name,
+			OutputKind::Oscillator,
+			OutputRange::Bounded { min, max },
+		)
+	}
+
+	/// Creates a descriptor for an unbounded oscillator value
+	#[must_use]
+	pub const fn unbounded(name: &'static str) -> Self {
+		Self::new(name, OutputKind::Oscillator, OutputRange::Unbounded)
+	}
+
+	/// Creates a descriptor for an unbounded volume-scaled value
+	#[must_use]
+	pub const fn volume(name: &'static str) -> Self {
+		Self::new(name, OutputKind::Volume, OutputRange::Unbounded)
+	}
+
+	/// Creates a descriptor for a signal
+	///
+	/// Signals are always oscillators in \[`-1.0`; `1.0`\] (see [`Action::ratio`](crate::core::Action::ratio))
+	#[must_use]
+	pub const fn signal(name: &'static str) -> Self {
+		Self::oscillator(name, -1.0, 1.0)
+	}
+}
+
+/// Descriptors of all the raw values and signals of an indicator in the same order as they appear in [`IndicatorResult`](crate::core::IndicatorResult)
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let macd = MACD::default();
+/// let outputs = macd.outputs();
+/// assert_eq!(outputs.value_index("signal"), Some(1));
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+/// let results = macd.over(&candles).unwrap();
+/// assert_eq!(results[9].value_by_name(&outputs, "signal"), Some(results[9].value(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct IndicatorOutputs {
+	/// Descriptors of raw values
+	pub values: &'static [OutputDescriptor],
+
+	/// Descriptors of signals
+	pub signals: &'static [OutputDescriptor],
+}
+
+impl IndicatorOutputs {
+	/// Returns an index of the raw value with the given name
+	#[must_use]
+	pub fn value_index(&self, name: &str) -> Option<usize> {
+		self.values.iter().position(|x| x.name == name)
+	}
+
+	/// Returns an index of the signal with the given name
+	#[must_use]
+	pub fn signal_index(&self, name: &str) -> Option<usize> {
+		self.signals.iter().position(|x| x.name == name)
+	}
+
+	/// Returns `(count of raw values, count of signals)`
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn size(&self) -> (u8, u8) {
+		(self.values.len() as u8, self.signals.len() as u8)
+	}
+}
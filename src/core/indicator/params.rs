@@ -0,0 +1,150 @@
+use crate::core::{PeriodType, ValueType};
+use std::ops::Bound;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Type of an indicator parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ParamType {
+	/// [`PeriodType`] value
+	Period,
+
+	/// [`ValueType`] value
+	Value,
+
+	/// Integer value, which is not a period
+	Integer,
+
+	/// `bool` value
+	Bool,
+
+	/// [`Source`](crate::core::Source) value
+	Source,
+
+	/// Moving average constructor value, f.e. [`MA`](crate::helpers::MA)
+	MovingAverage,
+
+	/// [`Seed`](crate::core::Seed) value
+	Seed,
+
+	/// [`CycleEstimator`](crate::methods::CycleEstimator) value
+	CycleEstimator,
+}
+
+/// Describes a single parameter of an [`IndicatorConfig`](crate::core::IndicatorConfig)
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::indicators::BollingerBands;
+/// use std::ops::RangeBounds;
+///
+/// let mut bb = BollingerBands::default();
+/// let param = bb.params().iter().find(|p| p.name == "sigma").unwrap();
+///
+/// assert_eq!(param.default, "2");
+/// assert!(param.range.contains(&1.5));
+/// assert!(!param.range.contains(&0.0));
+///
+/// bb.set("sigma", "1.5".to_string()).unwrap();
+/// assert_eq!(bb.get("sigma").unwrap(), "1.5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ParamDescriptor {
+	/// Name of the parameter, which is accepted by [`set`](crate::core::IndicatorConfig::set) and [`get`](crate::core::IndicatorConfig::get)
+	pub name: &'static str,
+
+	/// Type of the parameter
+	pub kind: ParamType,
+
+	/// Default value of the parameter in the same format as [`get`](crate::core::IndicatorConfig::get) returns
+	pub default: &'static str,
+
+	/// Valid range of the parameter value
+	///
+	/// For moving averages it is the range of the period length. For non-numeric parameters it is always unbounded.
+	///
+	/// Some parameters also depend on each other, which is checked by [`validate`](crate::core::IndicatorConfig::validate).
+	pub range: (Bound<ValueType>, Bound<ValueType>),
+}
+
+impl ParamDescriptor {
+	/// Creates a new descriptor
+	#[must_use]
+	pub const fn new(
+		name: &'static str,
+		kind: ParamType,
+		default: &'static str,
+		range: (Bound<ValueType>, Bound<ValueType>),
+	) -> Self {
+		Self {
+			name,
+			kind,
+			default,
+			range,
+		}
+	}
+
+	/// Creates a descriptor for a period parameter in \[`min`; [`PeriodType::MAX`]\)
+	#[must_use]
+	pub const fn period(name: &'static str, default: &'static str, min: PeriodType) -> Self {
+		Self::new(name, ParamType::Period, default, Self::periods(min))
+	}
+
+	/// Creates a descriptor for a value parameter in the given bounds
+	#[must_use]
+	pub const fn value(
+		name: &'static str,
+		default: &'static str,
+		min: Bound<ValueType>,
+		max: Bound<ValueType>,
+	) -> Self {
+		Self::new(name, ParamType::Value, default, (min, max))
+	}
+
+	/// Creates a descriptor for a moving average parameter with period length in \[`min`; [`PeriodType::MAX`]\)
+	#[must_use]
+	pub const fn moving_average(
+		name: &'static str,
+		default: &'static str,
+		min: PeriodType,
+	) -> Self {
+		Self::new(name, ParamType::MovingAverage, default, Self::periods(min))
+	}
+
+	/// Creates a descriptor for a [`Source`](crate::core::Source) parameter
+	#[must_use]
+	pub const fn source(name: &'static str, default: &'static str) -> Self {
+		Self::new(name, ParamType::Source, default, Self::UNBOUNDED)
+	}
+
+	/// Creates a descriptor for a `bool` parameter
+	#[must_use]
+	pub const fn flag(name: &'static str, default: &'static str) -> Self {
+		Self::new(name, ParamType::Bool, default, Self::UNBOUNDED)
+	}
+
+	/// Creates a descriptor for a [`Seed`](crate::core::Seed) parameter with default value [`Initial`](crate::core::Seed::Initial)
+	#[must_use]
+	pub const fn seed(name: &'static str) -> Self {
+		Self::new(name, ParamType::Seed, "initial", Self::UNBOUNDED)
+	}
+
+	/// Creates a descriptor for a [`CycleEstimator`](crate::methods::CycleEstimator) parameter
+	#[must_use]
+	pub const fn cycle_estimator(name: &'static str, default: &'static str) -> Self {
+		Self::new(name, ParamType::CycleEstimator, default, Self::UNBOUNDED)
+	}
+
+	const UNBOUNDED: (Bound<ValueType>, Bound<ValueType>) = (Bound::Unbounded, Bound::Unbounded);
+
+	#[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+	const fn periods(min: PeriodType) -> (Bound<ValueType>, Bound<ValueType>) {
+		(
+			Bound::Included(min as ValueType),
+			Bound::Excluded(PeriodType::MAX as ValueType),
+		)
+	}
+}
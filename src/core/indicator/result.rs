@@ -1,56 +1,57 @@
+use super::IndicatorOutputs;
 use crate::core::{Action, ValueType};
 use std::fmt;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Every `Indicator` proceed an input of [`OHLCV`](crate::core::OHLCV) and returns an `IndicatorResult` which consist of some returned raw values and some calculated signals.
 ///
-/// `Indicator` may return up to 4 signals and 4 raw values at each step
-#[derive(Clone, Copy)]
+/// `Indicator` may return any number of signals and raw values (up to [`u8::MAX`] of each) at each step.
+/// Up to [`IndicatorResult::SIZE`] values and signals are stored inline, so the most of indicators never touch the heap.
+///
+/// Since the results with more values or signals are stored on the heap, `IndicatorResult` is not [`Copy`] (it was before `0.7`).
+/// Use [`Clone`] instead, which never allocates for the results stored inline.
+#[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[allow(clippy::use_self)]
 pub struct IndicatorResult {
-	signals: [Action; IndicatorResult::SIZE],
-	values: [ValueType; IndicatorResult::SIZE],
-	length: (u8, u8),
+	signals: Buffer<Action>,
+	values: Buffer<ValueType>,
 }
 
 impl IndicatorResult {
-	/// Size of pre-allocated result array
+	/// Count of values and signals stored without heap allocation
 	/// For the most of cases it should not be used anywhere outside this crate
 	pub const SIZE: usize = 4;
 
 	/// Returns a slice of signals of current indicator result
 	#[must_use]
 	pub fn signals(&self) -> &[Action] {
-		let len = self.length.1 as usize;
-		&self.signals[..len]
+		self.signals.as_slice()
 	}
 
 	/// Returns a slice of raw indicator values of current indicator result
 	#[must_use]
 	pub fn values(&self) -> &[ValueType] {
-		let len = self.length.0 as usize;
-		&self.values[..len]
+		self.values.as_slice()
 	}
 
 	/// Returns count of signals
 	#[must_use]
 	pub const fn signals_length(&self) -> u8 {
-		self.length.1
+		self.signals.len()
 	}
 
 	/// Returns count of raw values
 	#[must_use]
 	pub const fn values_length(&self) -> u8 {
-		self.length.0
+		self.values.len()
 	}
 
 	/// Returns a tuple of count of raw values and count of signals
 	#[must_use]
 	pub const fn size(&self) -> (u8, u8) {
-		self.length
+		(self.values_length(), self.signals_length())
 	}
 
 	/// Returns a raw value at given index
@@ -61,8 +62,7 @@ impl IndicatorResult {
 	#[inline]
 	#[must_use]
 	pub fn value(&self, index: usize) -> ValueType {
-		assert!(index < self.length.0 as usize);
-		self.values[index]
+		self.values()[index]
 	}
 
 	/// Returns a signal at given index
@@ -73,46 +73,55 @@ impl IndicatorResult {
 	#[inline]
 	#[must_use]
 	pub fn signal(&self, index: usize) -> Action {
-		assert!(index < self.length.1 as usize);
-		self.signals[index]
+		self.signals()[index]
+	}
+
+	/// Returns a raw value by its name in the given `outputs`
+	///
+	/// Returns `None` if there is no value with such name.
+	/// See also [`IndicatorConfig::OUTPUTS`](crate::core::IndicatorConfig::OUTPUTS)
+	#[must_use]
+	pub fn value_by_name(&self, outputs: &IndicatorOutputs, name: &str) -> Option<ValueType> {
+		outputs
+			.value_index(name)
+			.and_then(|index| self.values().get(index).copied())
+	}
+
+	/// Returns a signal by its name in the given `outputs`
+	///
+	/// Returns `None` if there is no signal with such name.
+	/// See also [`IndicatorConfig::OUTPUTS`](crate::core::IndicatorConfig::OUTPUTS)
+	#[must_use]
+	pub fn signal_by_name(&self, outputs: &IndicatorOutputs, name: &str) -> Option<Action> {
+		outputs
+			.signal_index(name)
+			.and_then(|index| self.signals().get(index).copied())
 	}
 
 	/// Creates a new instance of `IndicatorResult` with provided *values* and *signals*
+	///
+	/// Only first [`u8::MAX`] values and signals are kept.
 	#[inline]
 	#[must_use]
 	pub fn new(values_slice: &[ValueType], signals_slice: &[Action]) -> Self {
-		let mut values = [0 as ValueType; Self::SIZE];
-		let mut signals = [Action::default(); Self::SIZE];
-
-		let values_length = Self::SIZE.min(values_slice.len());
-		values[..values_length].copy_from_slice(&values_slice[..values_length]);
-
-		let signals_length = Self::SIZE.min(signals_slice.len());
-		signals[..signals_length].copy_from_slice(&signals_slice[..signals_length]);
-
-		#[allow(clippy::cast_possible_truncation)]
-		let length = (values_length as u8, signals_length as u8);
-
 		Self {
-			signals,
-			values,
-			length,
+			signals: Buffer::from_slice(signals_slice),
+			values: Buffer::from_slice(values_slice),
 		}
 	}
+
+	/// Replaces raw value at `index`
+	pub(crate) fn set_value(&mut self, index: usize, value: ValueType) {
+		self.values.as_mut_slice()[index] = value;
+	}
 }
 
 impl fmt::Debug for IndicatorResult {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let values: Vec<String> = self
-			.values
-			.iter()
-			.take(self.length.0 as usize)
-			.map(|&x| format!("{x:>7.4}"))
-			.collect();
+		let values: Vec<String> = self.values().iter().map(|&x| format!("{x:>7.4}")).collect();
 		let signals: Vec<String> = self
-			.signals
+			.signals()
 			.iter()
-			.take(self.length.1 as usize)
 			.map(std::string::ToString::to_string)
 			.collect();
 		write!(
@@ -123,3 +132,101 @@ impl fmt::Debug for IndicatorResult {
 		)
 	}
 }
+
+/// Small vector which keeps up to [`IndicatorResult::SIZE`] items inline and spills to the heap otherwise
+#[derive(Clone)]
+enum Buffer<T> {
+	Inline(u8, [T; IndicatorResult::SIZE]),
+	Heap(u8, Vec<T>),
+}
+
+impl<T: Copy + Default> Buffer<T> {
+	fn from_slice(slice: &[T]) -> Self {
+		let slice = &slice[..slice.len().min(u8::MAX as usize)];
+
+		#[allow(clippy::cast_possible_truncation)]
+		let length = slice.len() as u8;
+
+		if slice.len() > IndicatorResult::SIZE {
+			return Self::Heap(length, slice.to_vec());
+		}
+
+		let mut items = [T::default(); IndicatorResult::SIZE];
+		items[..slice.len()].copy_from_slice(slice);
+
+		Self::Inline(length, items)
+	}
+}
+
+impl<T> Buffer<T> {
+	fn as_slice(&self) -> &[T] {
+		match self {
+			Self::Inline(length, items) => &items[..*length as usize],
+			Self::Heap(_, items) => items,
+		}
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		match self {
+			Self::Inline(length, items) => &mut items[..*length as usize],
+			Self::Heap(_, items) => items,
+		}
+	}
+
+	const fn len(&self) -> u8 {
+		match self {
+			Self::Inline(length, _) | Self::Heap(length, _) => *length,
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Buffer<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_slice().serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Copy + Default + Deserialize<'de>> Deserialize<'de> for Buffer<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Vec::<T>::deserialize(deserializer).map(|items| Self::from_slice(&items))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorResult;
+	use crate::core::{Action, ValueType};
+
+	#[test]
+	fn test_indicator_result_inline() {
+		let result = IndicatorResult::new(&[1.0, 2.0], &[Action::BUY_ALL]);
+
+		assert_eq!(result.size(), (2, 1));
+		assert_eq!(result.values(), &[1.0, 2.0]);
+		assert_eq!(result.signals(), &[Action::BUY_ALL]);
+	}
+
+	#[test]
+	fn test_indicator_result_spilled() {
+		#[allow(clippy::cast_precision_loss)]
+		let values: Vec<ValueType> = (0..9).map(|x| x as ValueType).collect();
+		let signals = [Action::SELL_ALL; IndicatorResult::SIZE + 3];
+		let result = IndicatorResult::new(&values, &signals);
+
+		assert_eq!(result.size(), (9, 7));
+		assert_eq!(result.values(), values.as_slice());
+		assert_eq!(result.signals(), &signals);
+		assert_eq!(result.value(8).to_bits(), values[8].to_bits());
+		assert_eq!(result.signal(6), Action::SELL_ALL);
+	}
+
+	#[test]
+	fn test_indicator_result_truncated() {
+		let values = vec![0.0; u8::MAX as usize + 1];
+		let result = IndicatorResult::new(&values, &[]);
+
+		assert_eq!(result.size(), (u8::MAX, 0));
+	}
+}
@@ -1,5 +1,5 @@
 use super::{Error, Sequence};
-use crate::helpers::{WithHistory, WithLastValue};
+use crate::helpers::{WithHistory, WithLastValue, WithWarmup};
 use std::fmt;
 
 type BoxedFnMethod<'a, M> = Box<dyn FnMut(&'a <M as Method>::Input) -> <M as Method>::Output>;
@@ -102,6 +102,41 @@ pub trait Method {
 		WithLastValue::new(parameters, initial_value)
 	}
 
+	/// Creates an instance of the method with given `parameters` and initial `value`, wrapped by warm-up tracker
+	///
+	/// See also [`WithWarmup`]
+	fn with_warmup(
+		parameters: Self::Params,
+		initial_value: &Self::Input,
+	) -> Result<WithWarmup<Self>, Error>
+	where
+		Self: Sized,
+	{
+		WithWarmup::new(parameters, initial_value)
+	}
+
+	/// Returns the *lookback* of the method: count of the leading output values, which TA-Lib would not produce at all.
+	///
+	/// For the [`SMA`](crate::methods::SMA) of length `N` it is equal to `N-1`,
+	/// so the `N`-th output value is the first one computed over `N` real input values.
+	/// Methods, which does not depend on the previous input values, return `0`.
+	///
+	/// It is not a guarantee, that the following output values do not depend on the initial `value`.
+	/// Recursive methods ([`EMA`](crate::methods::EMA), [`RMA`](crate::methods::RMA), IIR filters, adaptive moving averages
+	/// and others) remember the seed forever, its weight just decays over time.
+	///
+	/// The count is always taken from the moment of creating the instance.
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let ma = SMA::new(5, &1.0).unwrap();
+	/// assert_eq!(ma.warmup_len(), 4);
+	/// ```
+	fn warmup_len(&self) -> usize {
+		0
+	}
+
 	/// Returns a name of the method
 	fn name(&self) -> &str {
 		let parts = std::any::type_name::<Self>().split("::");
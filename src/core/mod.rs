@@ -10,6 +10,7 @@ mod method;
 mod moving_average;
 mod ohlcv;
 mod sequence;
+mod sum;
 mod window;
 
 pub use action::Action;
@@ -18,8 +19,10 @@ pub use errors::Error;
 pub use indicator::*;
 pub use method::Method;
 pub use moving_average::*;
-pub use ohlcv::OHLCV;
+pub use ohlcv::{Timestamp, Timestamped, OHLCV, OHLCVT};
 pub use sequence::*;
+pub use sum::NeumaierSum;
+pub(crate) use sum::{centered_sums, non_zero_m2, RunningSum};
 pub use window::Window;
 
 /// Main value type for calculations
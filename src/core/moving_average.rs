@@ -1,10 +1,15 @@
+use std::fmt;
 use std::str::FromStr;
 
 use super::{Error, Method, PeriodType, ValueType};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Marker trait for any moving average
 ///
-/// Moving average is a [`Method`] which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
+/// Moving average is a [`Method`] which input is single [`ValueType`] and output is single [`ValueType`].
+/// Usually it has parameters of single [`PeriodType`], but some moving averages have extra parameters.
 ///
 /// # See also
 ///
@@ -15,12 +20,12 @@ use super::{Error, Method, PeriodType, ValueType};
 /// [`PeriodType`]: crate::core::PeriodType
 pub trait MovingAverage: Method<Input = ValueType, Output = ValueType> {}
 
-/// Trait for dynamically creation of moving average instances based on it's type and period
+/// Trait for dynamically creation of moving average instances based on it's type, period and extra parameters
 ///
 /// This trait plays the same role for moving averages as [`IndicatorConfig`] plays for indicators.
 ///
 /// [`IndicatorConfig`]: crate::core::IndicatorConfig
-pub trait MovingAverageConstructor: Clone + FromStr {
+pub trait MovingAverageConstructor: Clone + FromStr + fmt::Display {
 	/// Used for comparing MA types
 	type Type: Eq;
 
@@ -30,14 +35,71 @@ pub trait MovingAverageConstructor: Clone + FromStr {
 	/// Creates moving average instance with the `initial_value`
 	fn init(&self, initial_value: ValueType) -> Result<Self::Instance, Error>;
 
+	/// Creates moving average instance with the `initial_value` and the given initialization policy
+	///
+	/// By default [`Seed`] is ignored, which is suitable for moving averages with finite memory,
+	/// such as [`SMA`](crate::methods::SMA) or [`WMA`](crate::methods::WMA).
+	fn init_seeded(&self, initial_value: ValueType, _seed: Seed) -> Result<Self::Instance, Error> {
+		self.init(initial_value)
+	}
+
 	/// Returns period length of
 	fn ma_period(&self) -> PeriodType;
 
 	/// Returns moving average type
 	fn ma_type(&self) -> Self::Type;
 
+	/// Returns the same moving average with the period changed to `period`, keeping the extra parameters as is
+	///
+	/// By default returns an error, so the moving averages, which period can not be changed, do not need to implement it.
+	fn with_period(&self, period: PeriodType) -> Result<Self, Error> {
+		Err(Error::Other(format!(
+			"Period of the moving average `{self}` can not be changed to {period}"
+		)))
+	}
+
 	/// Checks two moving average constructors for the same moving average type
 	fn is_similar_to(&self, other: &Self) -> bool {
 		self.ma_type() == other.ma_type()
 	}
 }
+
+/// Initialization policy for recursive moving averages, such as [`EMA`](crate::methods::EMA) or [`RMA`](crate::methods::RMA)
+///
+/// Methods with finite memory (f.e. [`SMA`](crate::methods::SMA)) does not depend on this policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Seed {
+	/// Replicates initial value as if it was present through the whole history. This is the default behaviour.
+	#[default]
+	Initial,
+
+	/// Uses [simple moving average](crate::methods::SMA) of the first `length` input values as the starting value.
+	///
+	/// Until enough values are collected, returns an average of the values seen so far.
+	/// This is the way [TA-Lib](https://ta-lib.org) and [Pine Script](https://www.tradingview.com/pine-script-docs) initialize their moving averages.
+	SMA,
+}
+
+impl FromStr for Seed {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"initial" => Ok(Self::Initial),
+			"sma" => Ok(Self::SMA),
+			_ => Err(Error::MovingAverageParse),
+		}
+	}
+}
+
+impl fmt::Display for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Initial => f.write_str("initial"),
+			Self::SMA => f.write_str("sma"),
+		}
+	}
+}
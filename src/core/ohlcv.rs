@@ -1,5 +1,11 @@
 use super::{Source, ValueType};
 
+/// Type of a timestamp of a timeseries element
+///
+/// The crate does not assume any particular unit. It may be seconds, milliseconds or anything else,
+/// as long as the same unit is used for the whole timeseries.
+pub type Timestamp = i64;
+
 /// Basic trait for implementing [Open-High-Low-Close-Volume timeseries data](https://en.wikipedia.org/wiki/Candlestick_chart).
 ///
 /// It has already implemented for tuple of 5 float values:
@@ -14,7 +20,7 @@ use super::{Source, ValueType};
 /// assert_eq!(row.volume(), row.4);
 /// ```
 ///
-/// See also [Candle](crate::prelude::Candle).
+/// See also [Candle](crate::prelude::Candle), [`OHLCVT`].
 pub trait OHLCV: 'static {
 	/// Should return an *open* value of the period
 	fn open(&self) -> ValueType;
@@ -336,3 +342,95 @@ impl OHLCV for [ValueType; 5] {
 		self[4]
 	}
 }
+
+impl OHLCV
+	for (
+		Timestamp,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+	)
+{
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.1
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.2
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.3
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.4
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.5
+	}
+}
+
+/// Basic trait for any timeseries element, which knows its own point in time.
+///
+/// It has already implemented for tuple of a [`Timestamp`] and 5 float values:
+/// ```
+/// use yata::prelude::*;
+/// //         time  open high low  close, volume
+/// let row = (60,   2.0, 5.0, 1.0,  4.0,   10.0 );
+/// assert_eq!(row.timestamp(), 60);
+/// assert_eq!(row.close(), 4.0);
+/// ```
+///
+/// See also [`OHLCVT`], [`TimedCandle`](crate::core::TimedCandle).
+pub trait Timestamped {
+	/// Should return a timestamp of the element (f.e. the *open time* of the candle)
+	fn timestamp(&self) -> Timestamp;
+}
+
+impl Timestamped
+	for (
+		Timestamp,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+	)
+{
+	#[inline]
+	fn timestamp(&self) -> Timestamp {
+		self.0
+	}
+}
+
+/// [`OHLCV`] which is also [`Timestamped`].
+///
+/// It is automatically implemented for every type which implements both [`OHLCV`] and [`Timestamped`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::TimedCandle;
+///
+/// fn duration<T: OHLCVT>(first: &T, last: &T) -> i64 {
+///     last.timestamp() - first.timestamp()
+/// }
+///
+/// let a: TimedCandle = (60, 2.0, 5.0, 1.0, 4.0, 10.0).into();
+/// let b: TimedCandle = (180, 4.0, 6.0, 3.0, 5.0, 10.0).into();
+///
+/// assert_eq!(duration(&a, &b), 120);
+/// ```
+pub trait OHLCVT: OHLCV + Timestamped {}
+
+impl<T: OHLCV + Timestamped> OHLCVT for T {}
@@ -1,5 +1,6 @@
-use crate::core::Method;
-use crate::core::{ValueType, OHLCV};
+use crate::core::{Error, Method};
+use crate::core::{TimedCandle, Timestamp, Timestamped, ValueType, OHLCV, OHLCVT};
+use crate::methods::{CollapseTimeframeByTime, TimeBuckets};
 use std::ops::Add;
 
 /// Implements some methods for sequence manipulations.
@@ -59,6 +60,166 @@ pub trait Sequence<T>: AsRef<[T]> {
 			.map(reduce)
 			.collect()
 	}
+
+	/// Converts timeframe of the time-ordered series by the wall-clock time
+	///
+	/// Partial buckets are included in the result only if [`TimeBuckets::partial`] is `true`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomTimedCandles;
+	/// use yata::methods::TimeBuckets;
+	///
+	/// // 1 minute candles with timestamps in seconds
+	/// let candles: Vec<_> = RandomTimedCandles::new(0, 60).take(120).collect();
+	///
+	/// // into 15 minutes candles
+	/// let collapsed = candles.collapse_timeframe_by_time(TimeBuckets::new(15 * 60)).unwrap();
+	///
+	/// assert_eq!(collapsed.len(), 7); // the last bucket is never closed
+	/// assert_eq!(collapsed[1].timestamp(), 15 * 60);
+	/// ```
+	///
+	/// See also [`CollapseTimeframeByTime`](crate::methods::CollapseTimeframeByTime) method.
+	fn collapse_timeframe_by_time(&self, buckets: TimeBuckets) -> Result<Vec<TimedCandle>, Error>
+	where
+		T: OHLCVT,
+	{
+		let inputs = self.as_ref();
+
+		let Some(first) = inputs.first() else {
+			return Ok(Vec::new());
+		};
+
+		let mut method = CollapseTimeframeByTime::new(buckets, first)?;
+		let mut result: Vec<_> = inputs.iter().filter_map(|x| method.next(x)).collect();
+
+		if buckets.partial {
+			result.extend(method.flush());
+		}
+
+		Ok(result)
+	}
+
+	/// Returns timestamps of the sequence's elements.
+	#[inline]
+	fn timestamps(&self) -> Vec<Timestamp>
+	where
+		T: Timestamped,
+	{
+		self.as_ref().iter().map(Timestamped::timestamp).collect()
+	}
+
+	/// Checks if timestamps of the sequence are strictly increasing.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::TimedCandle;
+	///
+	/// let candles: Vec<TimedCandle> = vec![
+	///     (60, 1.0, 2.0, 0.5, 1.5, 10.0).into(),
+	///     (120, 1.5, 2.0, 1.0, 1.8, 10.0).into(),
+	/// ];
+	///
+	/// assert!(candles.is_time_ordered());
+	/// assert!(!candles.iter().rev().copied().collect::<Vec<_>>().is_time_ordered());
+	/// ```
+	fn is_time_ordered(&self) -> bool
+	where
+		T: Timestamped,
+	{
+		self.as_ref()
+			.windows(2)
+			.all(|w| w[0].timestamp() < w[1].timestamp())
+	}
+
+	/// Searches for gaps in the sequence.
+	///
+	/// Returns indexes of elements, which timestamps differ from the previous element's timestamp by more than `step`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{TimedCandle, Timestamp};
+	///
+	/// let candles: Vec<TimedCandle> = [0, 60, 120, 240, 300, 600]
+	///     .iter()
+	///     .map(|&t| (t, 1.0, 2.0, 0.5, 1.5, 10.0).into())
+	///     .collect();
+	///
+	/// assert_eq!(candles.find_gaps(60), vec![3, 5]);
+	///
+	/// let candles: Vec<TimedCandle> = [Timestamp::MIN, Timestamp::MAX, 0]
+	///     .iter()
+	///     .map(|&t| (t, 1.0, 2.0, 0.5, 1.5, 10.0).into())
+	///     .collect();
+	///
+	/// assert_eq!(candles.find_gaps(60), vec![1]);
+	/// ```
+	fn find_gaps(&self, step: Timestamp) -> Vec<usize>
+	where
+		T: Timestamped,
+	{
+		self.as_ref()
+			.windows(2)
+			.enumerate()
+			// difference of far apart timestamps does not fit into `Timestamp`
+			.filter(|(_, w)| w[1].timestamp() as i128 - w[0].timestamp() as i128 > step as i128)
+			.map(|(i, _)| i + 1)
+			.collect()
+	}
+
+	/// Searches for an element with the given `timestamp` in the time-ordered sequence.
+	///
+	/// Useful for aligning several sequences by time.
+	///
+	/// If the element is found, then [`Result::Ok`] is returned, containing the index of the matching element.
+	/// Otherwise [`Result::Err`] is returned, containing the index where an element with such `timestamp` could be inserted.
+	///
+	/// See also [`slice::binary_search_by_key`].
+	#[inline]
+	fn find_timestamp(&self, timestamp: Timestamp) -> Result<usize, usize>
+	where
+		T: Timestamped,
+	{
+		self.as_ref()
+			.binary_search_by_key(&timestamp, Timestamped::timestamp)
+	}
+
+	/// Attaches `timestamps` to the sequence's elements and returns `Vec` of [`TimedCandle`]s.
+	///
+	/// If there are less `timestamps` than elements in the sequence, then the result is truncated.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+	/// let timestamps: Vec<i64> = (0..10).map(|i| i * 60).collect();
+	///
+	/// let timed = candles.with_timestamps(timestamps.iter().copied());
+	///
+	/// assert_eq!(timed.len(), 10);
+	/// assert_eq!(timed.timestamps(), timestamps);
+	/// ```
+	fn with_timestamps<I>(&self, timestamps: I) -> Vec<TimedCandle>
+	where
+		T: OHLCV,
+		I: IntoIterator<Item = Timestamp>,
+	{
+		self.as_ref()
+			.iter()
+			.zip(timestamps)
+			.map(|(candle, timestamp)| TimedCandle::new(timestamp, candle))
+			.collect()
+	}
 }
 
 impl<Q: AsRef<[ValueType]>> Sequence<ValueType> for Q {
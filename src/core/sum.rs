@@ -0,0 +1,177 @@
+use super::ValueType;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Running sum of [`ValueType`] values with [Neumaier compensation](https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements)
+///
+/// Rounding error of every addition is kept in a separate compensation term, so the error of the sum does not grow
+/// with the count of the added values. It is useful for long running sums, which values are constantly added and subtracted.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::NeumaierSum;
+///
+/// let mut sum = NeumaierSum::new(1.0);
+///
+/// sum.add(1e100);
+/// sum.add(1.0);
+/// sum.add(-1e100);
+///
+/// assert_eq!(sum.value(), 2.0);
+/// ```
+///
+/// # See also
+///
+/// `stable_sums` feature of the crate
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NeumaierSum {
+	sum: ValueType,
+	compensation: ValueType,
+}
+
+impl NeumaierSum {
+	/// Creates a new sum with the initial `value`
+	#[must_use]
+	pub const fn new(value: ValueType) -> Self {
+		Self {
+			sum: value,
+			compensation: 0.0,
+		}
+	}
+
+	/// Adds `value` to the sum
+	#[inline]
+	pub fn add(&mut self, value: ValueType) {
+		let sum = self.sum + value;
+
+		self.compensation += if self.sum.abs() >= value.abs() {
+			(self.sum - sum) + value
+		} else {
+			(value - sum) + self.sum
+		};
+
+		self.sum = sum;
+	}
+
+	/// Returns current value of the sum
+	#[inline]
+	#[must_use]
+	pub const fn value(&self) -> ValueType {
+		self.sum + self.compensation
+	}
+}
+
+/// Running sum, which is used by the methods
+///
+/// It is [`NeumaierSum`] when `stable_sums` feature is enabled and a plain [`ValueType`] otherwise.
+#[cfg(feature = "stable_sums")]
+pub type RunningSum = NeumaierSum;
+
+/// Running sum, which is used by the methods
+///
+/// It is [`NeumaierSum`] when `stable_sums` feature is enabled and a plain [`ValueType`] otherwise.
+#[cfg(not(feature = "stable_sums"))]
+pub type RunningSum = PlainSum;
+
+/// Returns `m2` sum of squared deviations of `length` values from their `mean` or `0.0`, when all the values seem to be equal
+///
+/// Sum of squared deviations of equal values may be a bit greater than zero because of rounding errors of the `mean`.
+#[inline]
+pub fn non_zero_m2(m2: ValueType, mean: ValueType, length: ValueType) -> ValueType {
+	let noise = mean * ValueType::EPSILON;
+
+	if m2 > noise * noise * length {
+		m2
+	} else {
+		0.0
+	}
+}
+
+/// Returns the mean of the `values` and the sums of the `terms` of the values deviations from the mean
+///
+/// Rolling updates of the central moments accumulate rounding errors, which are not negligible, when the deviations are small
+/// relative to the values. So the methods, which keep such moments, recompute them from the window by this function once per `length` values.
+///
+/// It is the corrected two-pass algorithm: the mean of the deviations from the first pass mean compensates its rounding error.
+/// `terms` are evaluated in the order of the `values`.
+pub fn centered_sums<const N: usize>(
+	values: impl ExactSizeIterator<Item = ValueType> + Clone,
+	mut terms: impl FnMut(ValueType) -> [ValueType; N],
+) -> (ValueType, [ValueType; N]) {
+	let length = values.len() as ValueType;
+
+	let mut sum = RunningSum::new(0.0);
+	values.clone().for_each(|x| sum.add(x));
+	let mean = sum.value() / length;
+
+	let mut deviations = RunningSum::new(0.0);
+	values.clone().for_each(|x| deviations.add(x - mean));
+	let mean = mean + deviations.value() / length;
+
+	let mut sums = [RunningSum::new(0.0); N];
+	values.for_each(|x| {
+		sums.iter_mut()
+			.zip(terms(x - mean))
+			.for_each(|(sum, term)| sum.add(term));
+	});
+
+	(mean, sums.map(|sum: RunningSum| sum.value()))
+}
+
+#[cfg(not(feature = "stable_sums"))]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct PlainSum(ValueType);
+
+#[cfg(not(feature = "stable_sums"))]
+impl PlainSum {
+	pub const fn new(value: ValueType) -> Self {
+		Self(value)
+	}
+
+	#[inline]
+	pub fn add(&mut self, value: ValueType) {
+		self.0 += value;
+	}
+
+	#[inline]
+	pub const fn value(self) -> ValueType {
+		self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::NeumaierSum;
+	use crate::core::ValueType;
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_neumaier_sum() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut sum = NeumaierSum::default();
+
+		for _ in 0..1000 {
+			for &x in &src {
+				sum.add(x);
+			}
+
+			for &x in &src {
+				sum.add(-x);
+			}
+		}
+
+		// plain summation drifts away from zero here
+		assert!(sum.value().abs() < ValueType::EPSILON);
+	}
+}
@@ -262,6 +262,12 @@ impl<T> Window<T> {
 		&self.buf
 	}
 
+	/// Returns `true` if the oldest value is at the start of the inner buffer, which happens after every `size` pushes
+	#[inline]
+	pub(crate) const fn is_aligned(&self) -> bool {
+		self.index == 0
+	}
+
 	/// Returns the length (elements count) of the `Window`
 	#[must_use]
 	#[inline]
@@ -304,9 +310,9 @@ impl<T> std::ops::Index<PeriodType> for Window<T> {
 	type Output = T;
 
 	fn index(&self, index: PeriodType) -> &Self::Output {
-		let buf_index = self
-			.slice_index(index)
-			.unwrap_or_else(|| panic!("Window index {index} is out of range")) as usize;
+		let buf_index =
+			self.slice_index(index)
+				.unwrap_or_else(|| panic!("Window index {index} is out of range")) as usize;
 
 		if cfg!(feature = "unsafe_performance") {
 			unsafe { self.buf.get_unchecked(buf_index) }
@@ -349,7 +355,7 @@ impl<'a, T> IntoIterator for &'a Window<T> {
 // 	}
 // }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WindowIterator<'a, T> {
 	window: &'a Window<T>,
 	index: PeriodType,
@@ -406,7 +412,7 @@ impl<'a, T> Iterator for WindowIterator<'a, T> {
 impl<'a, T> ExactSizeIterator for WindowIterator<'a, T> {}
 impl<'a, T> std::iter::FusedIterator for WindowIterator<'a, T> {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReversedWindowIterator<'a, T> {
 	window: &'a Window<T>,
 	index: PeriodType,
@@ -1,3 +1,4 @@
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, OHLCV};
 use crate::prelude::{Error, Method};
 
 /// Trait for picking the very last value for methods and indicators
@@ -60,6 +61,10 @@ where
 
 		next_value
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.instance.warmup_len()
+	}
 }
 
 impl<'a, T, V> IntoIterator for &'a WithHistory<T, V> {
@@ -111,6 +116,11 @@ where
 		self.last_value = next_value.clone();
 		next_value
 	}
+
+	fn warmup_len(&self) -> usize {
+		// the very first value is already consumed in `new`
+		self.instance.warmup_len().saturating_sub(1)
+	}
 }
 
 impl<T, V: Clone> Peekable<V> for WithLastValue<T, V> {
@@ -119,6 +129,106 @@ impl<T, V: Clone> Peekable<V> for WithLastValue<T, V> {
 	}
 }
 
+/// Wrapper for tracking the warm-up period of methods and indicators
+///
+/// While the wrapped instance is warming up (see [`Method::warmup_len`] and [`IndicatorInstance::warmup_len`]), it returns `None`.
+///
+/// ```
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let s: Vec<_> = vec![1.,2.,3.,4.,5.];
+/// let mut ma = SMA::with_warmup(3, &s[0]).unwrap();
+///
+/// let result = ma.over(&s);
+/// assert_eq!(result.as_slice(), &[None, None, Some(2.), Some(3.), Some(4.)]);
+/// assert!(ma.is_ready());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WithWarmup<T: ?Sized> {
+	steps: usize,
+	warmup_len: usize,
+	instance: T,
+}
+
+impl<T: ?Sized> WithWarmup<T> {
+	/// Returns `true` if the last produced value is not affected by the warm-up period anymore
+	pub const fn is_ready(&self) -> bool {
+		self.steps > self.warmup_len
+	}
+
+	/// Returns count of the leading values, which are considered to be unstable
+	pub const fn warmup_len(&self) -> usize {
+		self.warmup_len
+	}
+
+	/// Returns a reference to the wrapped instance
+	pub const fn instance(&self) -> &T {
+		&self.instance
+	}
+
+	const fn step(&mut self) -> bool {
+		self.steps = self.steps.saturating_add(1);
+		self.is_ready()
+	}
+}
+
+impl<T: Method> Method for WithWarmup<T> {
+	type Params = T::Params;
+	type Input = T::Input;
+	type Output = Option<T::Output>;
+
+	fn new(parameters: Self::Params, initial_value: &Self::Input) -> Result<Self, Error> {
+		let instance = T::new(parameters, initial_value)?;
+
+		Ok(Self {
+			steps: 0,
+			warmup_len: instance.warmup_len(),
+			instance,
+		})
+	}
+
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let next_value = self.instance.next(value);
+		self.step().then_some(next_value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.warmup_len
+	}
+}
+
+impl<I: IndicatorInstance> WithWarmup<I> {
+	/// Initializes the indicator **State** based on the given **Configuration**, wrapped by warm-up tracker
+	///
+	/// See also [`IndicatorConfig::init_with_warmup`]
+	pub fn init<T: OHLCV>(config: I::Config, initial_value: &T) -> Result<Self, Error> {
+		let instance = config.init(initial_value)?;
+
+		Ok(Self {
+			steps: 0,
+			warmup_len: instance.warmup_len(),
+			instance,
+		})
+	}
+
+	/// Evaluates given candle and returns [`IndicatorResult`] if the indicator is already warmed up
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> Option<IndicatorResult> {
+		let result = self.instance.next(candle);
+		self.step().then_some(result)
+	}
+
+	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s,
+	/// masking the values produced during the warm-up period
+	pub fn over<T, S>(&mut self, inputs: S) -> Vec<Option<IndicatorResult>>
+	where
+		T: OHLCV,
+		S: AsRef<[T]>,
+	{
+		inputs.as_ref().iter().map(|x| self.next(x)).collect()
+	}
+}
+
 impl<V: Clone, T: Peekable<V>> Peekable<V> for &T {
 	fn peek(&self) -> V {
 		(*self).peek()
@@ -0,0 +1,338 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::MA;
+use crate::core::{
+	Error, Method, MovingAverage, MovingAverageConstructor, PeriodType, Seed, ValueType,
+};
+
+/// Composite moving average constructor, which chains any number of moving averages one after another
+///
+/// Every stage of the chain is applied to the output of the previous stage, f.e. `ema-5>sma-10`
+/// is [`SMA`](crate::methods::SMA) of length `10` of [`EMA`](crate::methods::EMA) of length `5` of the values.
+///
+/// `MAChain` may be used anywhere the generic `M: MovingAverageConstructor` parameter is accepted.
+///
+/// # String representation
+///
+/// Stages are separated by `>` and written in the order of applying.
+/// Each stage has the string representation of the underlying moving average constructor `M`.
+///
+/// # Period
+///
+/// [`ma_period`](MovingAverageConstructor::ma_period) of the chain is the count of the values, which affect its output:
+/// the sum of the stages periods minus the count of the stages plus one.
+///
+/// [`with_period`](MovingAverageConstructor::with_period) changes the period of the first stage only,
+/// f.e. `ema-5>sma-10` becomes `ema-20>sma-10` with the period `20`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::MovingAverageConstructor;
+/// use yata::helpers::{MAChain, MA};
+/// use yata::methods::{EMA, SMA};
+///
+/// let chain: MAChain = "ema-5>sma-10".parse().unwrap();
+/// assert_eq!(chain.stages(), &[MA::EMA(5), MA::SMA(10)]);
+/// assert_eq!(chain.to_string(), "ema-5>sma-10");
+/// assert_eq!(chain.ma_period(), 14);
+///
+/// let mut instance = chain.init(1.0).unwrap();
+/// let mut ema = EMA::new(5, &1.0).unwrap();
+/// let mut sma = SMA::new(10, &1.0).unwrap();
+///
+/// for value in [2.0, 4.0, 3.0, 5.0, 6.0] {
+///     assert_eq!(instance.next(&value), sma.next(&ema.next(&value)));
+/// }
+/// ```
+///
+/// Using inside indicators:
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Seed, Source};
+/// use yata::helpers::{MAChain, RandomCandles};
+/// use yata::indicators::SMIErgodicIndicator;
+///
+/// let smi = SMIErgodicIndicator::<MAChain> {
+///     period1: 20,
+///     period2: 5,
+///     signal: "hma-5>wma-3".parse().unwrap(),
+///     zone: 0.2,
+///     source: Source::Close,
+///     seed: Seed::Initial,
+/// };
+///
+/// let mut candles = RandomCandles::new();
+/// let mut smi = smi.init(&candles.first()).unwrap();
+///
+/// for candle in candles.take(10) {
+///     smi.next(&candle);
+/// }
+/// ```
+// `Eq` is not derived, because the default `MA` is not `Eq`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<M>"))]
+pub struct MAChain<M: MovingAverageConstructor = MA>(Vec<M>);
+
+impl<M: MovingAverageConstructor> MAChain<M> {
+	/// Creates a chain of the moving averages `stages` in the order of applying
+	///
+	/// `stages` should not be empty. Deserialized chains are checked the same way.
+	pub fn new(stages: Vec<M>) -> Result<Self, Error> {
+		Error::ensure(
+			!stages.is_empty(),
+			"stages",
+			stages.len(),
+			"must not be empty",
+		)?;
+
+		Ok(Self(stages))
+	}
+
+	/// Returns the moving averages of the chain in the order of applying
+	#[must_use]
+	pub fn stages(&self) -> &[M] {
+		&self.0
+	}
+}
+
+impl<M: MovingAverageConstructor> From<M> for MAChain<M> {
+	fn from(ma: M) -> Self {
+		Self(vec![ma])
+	}
+}
+
+impl<M: MovingAverageConstructor> TryFrom<Vec<M>> for MAChain<M> {
+	type Error = Error;
+
+	fn try_from(stages: Vec<M>) -> Result<Self, Self::Error> {
+		Self::new(stages)
+	}
+}
+
+impl<M: MovingAverageConstructor> MovingAverageConstructor for MAChain<M> {
+	type Type = Vec<M::Type>;
+	type Instance = MAChainInstance<M>;
+
+	fn init(&self, value: ValueType) -> Result<Self::Instance, Error> {
+		self.0
+			.iter()
+			.map(|ma| ma.init(value))
+			.collect::<Result<_, _>>()
+			.map(MAChainInstance)
+	}
+
+	fn init_seeded(&self, value: ValueType, seed: Seed) -> Result<Self::Instance, Error> {
+		self.0
+			.iter()
+			.map(|ma| ma.init_seeded(value, seed))
+			.collect::<Result<_, _>>()
+			.map(MAChainInstance)
+	}
+
+	fn ma_period(&self) -> PeriodType {
+		self.0
+			.iter()
+			.map(MovingAverageConstructor::ma_period)
+			.reduce(|period, stage| period.saturating_add(stage.saturating_sub(1)))
+			.unwrap_or_default()
+	}
+
+	fn ma_type(&self) -> Self::Type {
+		self.0
+			.iter()
+			.map(MovingAverageConstructor::ma_type)
+			.collect()
+	}
+
+	fn with_period(&self, period: PeriodType) -> Result<Self, Error> {
+		let mut stages = self.0.clone();
+		if let Some(first) = stages.first_mut() {
+			*first = first.with_period(period)?;
+		}
+
+		Ok(Self(stages))
+	}
+}
+
+impl<M: MovingAverageConstructor> FromStr for MAChain<M> {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let stages = s
+			.split('>')
+			.map(str::parse)
+			.collect::<Result<_, _>>()
+			.or(Err(Error::MovingAverageParse))?;
+
+		Ok(Self(stages))
+	}
+}
+
+impl<M: MovingAverageConstructor> fmt::Display for MAChain<M> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (index, ma) in self.0.iter().enumerate() {
+			if index > 0 {
+				f.write_str(">")?;
+			}
+			write!(f, "{ma}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Instance of the [`MAChain`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M::Instance: Serialize",
+		deserialize = "M::Instance: Deserialize<'de>"
+	))
+)]
+pub struct MAChainInstance<M: MovingAverageConstructor = MA>(Vec<M::Instance>);
+
+impl<M: MovingAverageConstructor> Method for MAChainInstance<M> {
+	type Input = ValueType;
+	type Output = ValueType;
+	type Params = std::convert::Infallible;
+
+	fn new(_: Self::Params, _: &Self::Input) -> Result<Self, Error>
+	where
+		Self: Sized,
+	{
+		Err(Error::Other("`MAChainInstance` cannot be constructed directly. You should use `MAChain::init` to instantiate it.".into()))
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.0
+			.iter_mut()
+			.fold(value, |value, instance| instance.next(&value))
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.iter().map(Method::warmup_len).sum()
+	}
+}
+
+impl<M: MovingAverageConstructor> MovingAverage for MAChainInstance<M> {}
+
+#[cfg(test)]
+mod tests {
+	use super::{MAChain, MA};
+	use crate::core::{
+		Candle, Error, IndicatorConfig, Method, MovingAverageConstructor, Seed, Source,
+	};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{IndicatorRegistry, RSI};
+	use crate::methods::{EMA, SMA};
+
+	#[test]
+	fn test_ma_chain_parse() {
+		let chain: MAChain = "ema-5>alma-9:0.9:5>sma-3".parse().unwrap();
+		assert_eq!(
+			chain.stages(),
+			&[MA::EMA(5), MA::ALMA(9, 0.9, 5.0), MA::SMA(3)]
+		);
+		assert_eq!(chain.to_string(), "ema-5>alma-9:0.9:5>sma-3");
+
+		let chain: MAChain = "ema-5".parse().unwrap();
+		assert_eq!(chain, MA::EMA(5).into());
+
+		for s in ["", "ema-5>", ">sma-3", "ema-5>>sma-3", "ema-5>unknown-3"] {
+			assert!(
+				matches!(s.parse::<MAChain>(), Err(Error::MovingAverageParse)),
+				"{s:?}"
+			);
+		}
+
+		assert!(MAChain::<MA>::new(Vec::new()).is_err());
+	}
+
+	#[test]
+	fn test_ma_chain_period() {
+		let chain: MAChain = "ema-5>sma-10>wma-3".parse().unwrap();
+		assert_eq!(chain.ma_period(), 5 + 10 + 3 - 2);
+
+		let instance = chain.init(1.0).unwrap();
+		let stages: usize = chain
+			.stages()
+			.iter()
+			.map(|stage| stage.init(1.0).unwrap().warmup_len())
+			.sum();
+		assert_eq!(instance.warmup_len(), stages);
+
+		let chain = chain.with_period(20).unwrap();
+		assert_eq!(chain.to_string(), "ema-20>sma-10>wma-3");
+
+		let chain: MAChain = "conv-3:1:2:1>sma-3".parse().unwrap();
+		assert!(chain.with_period(20).is_err());
+	}
+
+	#[test]
+	fn test_ma_chain_init_seeded() {
+		let src: Vec<_> = RandomCandles::default()
+			.take(100)
+			.map(|x| x.close)
+			.collect();
+		let chain: MAChain = "ema-5>sma-3".parse().unwrap();
+
+		let mut instance = chain.init_seeded(src[0], Seed::SMA).unwrap();
+		let mut ema = EMA::new_seeded(5, Seed::SMA, &src[0]).unwrap();
+		let mut sma = SMA::new(3, &src[0]).unwrap();
+
+		for x in &src {
+			assert_eq_float(sma.next(&ema.next(x)), instance.next(x));
+		}
+	}
+
+	#[test]
+	fn test_ma_chain_spec() {
+		let mut registry = IndicatorRegistry::<Candle>::empty();
+		registry.register_fn(RSI::<MAChain>::NAME, || {
+			Box::new(RSI::<MAChain> {
+				ma: MA::EMA(14).into(),
+				zone: 0.3,
+				source: Source::Close,
+				seed: Seed::Initial,
+			})
+		});
+
+		let rsi = registry
+			.parse("RelativeStrengthIndex(ma=ema-5>sma-3)")
+			.unwrap();
+		assert_eq!(rsi.get("ma").unwrap(), "ema-5>sma-3");
+
+		assert!(matches!(
+			registry.parse("RelativeStrengthIndex(ma=ema-5>)"),
+			Err(Error::SpecParse(25, _))
+		));
+
+		let candles: Vec<Candle> = RandomCandles::default().take(10).collect();
+		let mut rsi = rsi.init(&candles[0]).unwrap();
+		for candle in &candles {
+			rsi.next(candle);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_ma_chain_deserialize_empty() {
+		use serde::de::value::{Error as DeError, SeqDeserializer};
+		use serde::Deserialize;
+
+		let empty = SeqDeserializer::<_, DeError>::new(Vec::<u8>::new().into_iter());
+		assert!(MAChain::<MA>::deserialize(empty).is_err());
+	}
+}
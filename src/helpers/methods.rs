@@ -1,15 +1,77 @@
+use std::fmt;
 use std::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverage, MovingAverageConstructor, PeriodType, ValueType};
+use crate::core::{
+	Error, Method, MovingAverage, MovingAverageConstructor, PeriodType, Seed, ValueType,
+};
 use crate::methods::{
-	LinReg, Vidya, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA, TEMA, TMA, TRIMA, WMA, WSMA,
+	Butterworth, Chebyshev, Conv, Decycler, InstantaneousTrendline, LinReg, McGinley,
+	SuperSmoother, Vidya, WindowFunction, ALMA, DEMA, DMA, EMA, FRAMA, HMA, MAMA, RMA, SMA, SMM,
+	SWMA, T3, TEMA, TMA, TRIMA, WMA, WSMA, ZLEMA,
 };
 
+/// Default `offset` of [`MA::ALMA`], when it is omitted in the string representation
+const ALMA_OFFSET: ValueType = 0.85;
+/// Default `sigma` of [`MA::ALMA`], when it is omitted in the string representation
+const ALMA_SIGMA: ValueType = 6.0;
+/// Default `volume_factor` of [`MA::T3`], when it is omitted in the string representation
+const T3_VOLUME_FACTOR: ValueType = 0.7;
+
 /// Default moving average constructor
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # String representation
+///
+/// `MA` is parsed from and formatted to the string `name-length`, f.e. `ema-14`.
+/// Extra parameters of the moving average follow the `length` separated by `:`, f.e. `alma-9:0.85:6`.
+/// Omitted trailing parameters take their commonly used values.
+///
+/// For [`MA::Conv`] the `length` is the count of the `weights`, which follow it.
+///
+/// ```
+/// use yata::core::MovingAverageConstructor;
+/// use yata::helpers::MA;
+///
+/// let alma: MA = "alma-9:0.85:6".parse().unwrap();
+/// assert_eq!(alma, MA::ALMA(9, 0.85, 6.0));
+/// assert_eq!(alma, "alma-9".parse().unwrap());
+/// assert_eq!(alma.to_string(), "alma-9:0.85:6");
+///
+/// let conv: MA = "conv-3:1:2:1".parse().unwrap();
+/// assert_eq!(conv, MA::Conv(vec![1.0, 2.0, 1.0]));
+/// assert_eq!(conv.ma_period(), 3);
+///
+/// assert!("ema-14:0.5".parse::<MA>().is_err());
+/// assert!("conv-3:1:2".parse::<MA>().is_err());
+/// assert!("mama-2".parse::<MA>().is_err());
+/// ```
+///
+/// # Changing the period
+///
+/// [`with_period`](MovingAverageConstructor::with_period) keeps the extra parameters of the moving average.
+/// The period of [`MA::Conv`] is defined by its weights, so it can not be changed.
+///
+/// ```
+/// use yata::core::MovingAverageConstructor;
+/// use yata::helpers::MA;
+///
+/// assert_eq!(MA::ALMA(9, 0.9, 5.0).with_period(20).unwrap(), MA::ALMA(20, 0.9, 5.0));
+/// assert!(MA::Conv(vec![1.0, 2.0, 1.0]).with_period(5).is_err());
+/// ```
+///
+/// # `Copy` and `Eq`
+///
+/// `MA` is neither `Copy` nor `Eq` since `0.7`, because [`MA::Conv`] holds its weights in a `Vec` and the extra parameters are floats.
+/// So indicators, which are generic over the moving average constructor `M` (f.e. [`MACD`](crate::indicators::MACD)),
+/// are not `Copy` with the default `M` = `MA` anymore. Use `.clone()` instead.
+///
+/// # Volume weighted moving average
+///
+/// [`VWMA`](crate::methods::VWMA) is not a variant of `MA`, because its input is a pair of the value and its volume,
+/// while the input of any [`MovingAverage`] is a single [`ValueType`].
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 #[non_exhaustive]
@@ -59,6 +121,61 @@ pub enum MA {
 
 	/// [Variable Index Dynamic Average](crate::methods::Vidya)
 	Vidya(PeriodType),
+
+	/// [Gaussian filter](crate::methods::Conv::gaussian) with `sigma` = `length` / `6`
+	Gaussian(PeriodType),
+
+	/// [Lanczos filter](crate::methods::Conv::lanczos) with `3` lobes
+	Lanczos(PeriodType),
+
+	/// [Windowed-sinc low-pass filter](crate::methods::Conv::low_pass) with the cutoff period of `length` / `2` bars
+	/// (but not less than `2`) and [Blackman window](crate::methods::WindowFunction::Blackman)
+	Sinc(PeriodType),
+
+	/// [Butterworth filter](crate::methods::Butterworth) of order `2` with the cutoff period of `length` bars
+	///
+	/// `length` should be > `2`
+	Butterworth(PeriodType),
+
+	/// [Chebyshev filter](crate::methods::Chebyshev) of order `2` with the cutoff period of `length` bars and `0.5` dB ripple
+	///
+	/// `length` should be > `2`
+	Chebyshev(PeriodType),
+
+	/// [Ehlers' Super Smoother](crate::methods::SuperSmoother)
+	SuperSmoother(PeriodType),
+
+	/// [Ehlers' Decycler](crate::methods::Decycler)
+	Decycler(PeriodType),
+
+	/// [Ehlers' Instantaneous Trendline](crate::methods::InstantaneousTrendline)
+	#[cfg_attr(feature = "serde", serde(rename = "itrend"))]
+	InstantaneousTrendline(PeriodType),
+
+	/// [MESA Adaptive Moving Average](crate::methods::MAMA) with `fast_limit` = `0.5`,
+	/// where `slow_limit` is the smoothing factor of [EMA](crate::methods::EMA) of specified `length`
+	///
+	/// `length` should be > `2`, so `slow_limit` is less than `fast_limit`
+	MAMA(PeriodType),
+
+	/// [Arnaud Legoux Moving Average](crate::methods::ALMA) of specified `length`, `offset` and `sigma`
+	ALMA(PeriodType, ValueType, ValueType),
+
+	/// [Tillson T3 Moving Average](crate::methods::T3) of specified `length` and `volume_factor`
+	T3(PeriodType, ValueType),
+
+	/// [Zero Lag Exponential Moving Average](crate::methods::ZLEMA)
+	ZLEMA(PeriodType),
+
+	/// [`McGinley` Dynamic](crate::methods::McGinley)
+	McGinley(PeriodType),
+
+	/// [Fractal Adaptive Moving Average](crate::methods::FRAMA). Odd `length` is rounded up to the next even number
+	/// or down, when it is the maximum value of [`PeriodType`].
+	FRAMA(PeriodType),
+
+	/// [Convolution Moving Average](crate::methods::Conv) with specified `weights`, which length is the period
+	Conv(Vec<ValueType>),
 }
 
 /// Default moving average instance for constructor
@@ -112,6 +229,52 @@ pub enum MAInstance {
 
 	/// [Variable Index Dynamic Average](crate::methods::Vidya)
 	Vidya(Vidya),
+
+	/// [Gaussian filter](crate::methods::Conv::gaussian)
+	Gaussian(Conv),
+
+	/// [Lanczos filter](crate::methods::Conv::lanczos)
+	Lanczos(Conv),
+
+	/// [Windowed-sinc low-pass filter](crate::methods::Conv::low_pass)
+	Sinc(Conv),
+
+	/// [Butterworth filter](crate::methods::Butterworth)
+	Butterworth(Butterworth),
+
+	/// [Chebyshev filter](crate::methods::Chebyshev)
+	Chebyshev(Chebyshev),
+
+	/// [Ehlers' Super Smoother](crate::methods::SuperSmoother)
+	SuperSmoother(SuperSmoother),
+
+	/// [Ehlers' Decycler](crate::methods::Decycler)
+	Decycler(Decycler),
+
+	/// [Ehlers' Instantaneous Trendline](crate::methods::InstantaneousTrendline)
+	#[cfg_attr(feature = "serde", serde(rename = "itrend"))]
+	InstantaneousTrendline(InstantaneousTrendline),
+
+	/// [MESA Adaptive Moving Average](crate::methods::MAMA)
+	MAMA(MAMA),
+
+	/// [Arnaud Legoux Moving Average](crate::methods::ALMA)
+	ALMA(ALMA),
+
+	/// [Tillson T3 Moving Average](crate::methods::T3)
+	T3(T3),
+
+	/// [Zero Lag Exponential Moving Average](crate::methods::ZLEMA)
+	ZLEMA(ZLEMA),
+
+	/// [`McGinley` Dynamic](crate::methods::McGinley)
+	McGinley(McGinley),
+
+	/// [Fractal Adaptive Moving Average](crate::methods::FRAMA)
+	FRAMA(FRAMA),
+
+	/// [Convolution Moving Average](crate::methods::Conv)
+	Conv(Conv),
 }
 
 impl Method for MAInstance {
@@ -144,17 +307,76 @@ impl Method for MAInstance {
 			Self::TRIMA(i) => i.next(value),
 			Self::LinReg(i) => i.next(value),
 			Self::Vidya(i) => i.next(value),
+			Self::Gaussian(i) | Self::Lanczos(i) | Self::Sinc(i) | Self::Conv(i) => i.next(value),
+			Self::Butterworth(i) => i.next(value),
+			Self::Chebyshev(i) => i.next(value),
+			Self::SuperSmoother(i) => i.next(value),
+			Self::Decycler(i) => i.next(value),
+			Self::InstantaneousTrendline(i) => i.next(value),
+			Self::MAMA(i) => i.next(value),
+			Self::ALMA(i) => i.next(value),
+			Self::T3(i) => i.next(value),
+			Self::ZLEMA(i) => i.next(value),
+			Self::McGinley(i) => i.next(value),
+			Self::FRAMA(i) => i.next(value),
+		}
+	}
+
+	fn warmup_len(&self) -> usize {
+		match self {
+			Self::SMA(i) => i.warmup_len(),
+			Self::WMA(i) => i.warmup_len(),
+			Self::HMA(i) => i.warmup_len(),
+			Self::RMA(i) => i.warmup_len(),
+			Self::EMA(i) => i.warmup_len(),
+			Self::DMA(i) => i.warmup_len(),
+			Self::DEMA(i) => i.warmup_len(),
+			Self::TMA(i) => i.warmup_len(),
+			Self::TEMA(i) => i.warmup_len(),
+			Self::WSMA(i) => i.warmup_len(),
+			Self::SMM(i) => i.warmup_len(),
+			Self::SWMA(i) => i.warmup_len(),
+			Self::TRIMA(i) => i.warmup_len(),
+			Self::LinReg(i) => i.warmup_len(),
+			Self::Vidya(i) => i.warmup_len(),
+			Self::Gaussian(i) | Self::Lanczos(i) | Self::Sinc(i) | Self::Conv(i) => i.warmup_len(),
+			Self::Butterworth(i) => i.warmup_len(),
+			Self::Chebyshev(i) => i.warmup_len(),
+			Self::SuperSmoother(i) => i.warmup_len(),
+			Self::Decycler(i) => i.warmup_len(),
+			Self::InstantaneousTrendline(i) => i.warmup_len(),
+			Self::MAMA(i) => i.warmup_len(),
+			Self::ALMA(i) => i.warmup_len(),
+			Self::T3(i) => i.warmup_len(),
+			Self::ZLEMA(i) => i.warmup_len(),
+			Self::McGinley(i) => i.warmup_len(),
+			Self::FRAMA(i) => i.warmup_len(),
 		}
 	}
 }
 
 impl MovingAverage for MAInstance {}
 
+impl MA {
+	/// Checks the limits of the `length`, which the underlying methods do not take directly
+	fn validate(&self) -> Result<(), Error> {
+		match *self {
+			Self::Butterworth(length) | Self::Chebyshev(length) | Self::MAMA(length) => {
+				Error::ensure(length > 2, "length", length, "must be > 2")
+			}
+			_ => Ok(()),
+		}
+	}
+}
+
 impl MovingAverageConstructor for MA {
 	type Type = u8;
 	type Instance = MAInstance;
 
+	#[allow(clippy::too_many_lines)]
 	fn init(&self, value: ValueType) -> Result<Self::Instance, Error> {
+		self.validate()?;
+
 		match *self {
 			Self::SMA(length) => {
 				let instance = SMA::new(length, &value)?;
@@ -216,6 +438,104 @@ impl MovingAverageConstructor for MA {
 				let instance = Vidya::new(length, &value)?;
 				Ok(Self::Instance::Vidya(instance))
 			}
+			Self::Gaussian(length) => {
+				let instance = Conv::gaussian(length, length as ValueType / 6.0, &value)?;
+				Ok(Self::Instance::Gaussian(instance))
+			}
+			Self::Lanczos(length) => {
+				let instance = Conv::lanczos(length, 3, &value)?;
+				Ok(Self::Instance::Lanczos(instance))
+			}
+			Self::Sinc(length) => {
+				let period = (length as ValueType / 2.0).max(2.0);
+				let instance = Conv::low_pass(length, period, WindowFunction::Blackman, &value)?;
+				Ok(Self::Instance::Sinc(instance))
+			}
+			Self::Butterworth(length) => {
+				let instance = Butterworth::new((length, 2), &value)?;
+				Ok(Self::Instance::Butterworth(instance))
+			}
+			Self::Chebyshev(length) => {
+				let instance = Chebyshev::new((length, 2, 0.5), &value)?;
+				Ok(Self::Instance::Chebyshev(instance))
+			}
+			Self::SuperSmoother(length) => {
+				let instance = SuperSmoother::new(length, &value)?;
+				Ok(Self::Instance::SuperSmoother(instance))
+			}
+			Self::Decycler(length) => {
+				let instance = Decycler::new(length, &value)?;
+				Ok(Self::Instance::Decycler(instance))
+			}
+			Self::InstantaneousTrendline(length) => {
+				let instance = InstantaneousTrendline::new(length, &value)?;
+				Ok(Self::Instance::InstantaneousTrendline(instance))
+			}
+			Self::MAMA(length) => {
+				let slow_limit = 2.0 / (length as ValueType + 1.0);
+				let instance = MAMA::new((0.5, slow_limit), &value)?;
+				Ok(Self::Instance::MAMA(instance))
+			}
+			Self::ALMA(length, offset, sigma) => {
+				let instance = ALMA::new((length, offset, sigma), &value)?;
+				Ok(Self::Instance::ALMA(instance))
+			}
+			Self::T3(length, volume_factor) => {
+				let instance = T3::new((length, volume_factor), &value)?;
+				Ok(Self::Instance::T3(instance))
+			}
+			Self::ZLEMA(length) => {
+				let instance = ZLEMA::new(length, &value)?;
+				Ok(Self::Instance::ZLEMA(instance))
+			}
+			Self::McGinley(length) => {
+				let instance = McGinley::new(length, &value)?;
+				Ok(Self::Instance::McGinley(instance))
+			}
+			Self::FRAMA(length) => {
+				// `PeriodType::MAX` is odd and can not be rounded up
+				let length = length.checked_add(length % 2).unwrap_or(length - 1);
+				let instance = FRAMA::new(length, &value)?;
+				Ok(Self::Instance::FRAMA(instance))
+			}
+			Self::Conv(ref weights) => {
+				let instance = Conv::new(weights.clone(), &value)?;
+				Ok(Self::Instance::Conv(instance))
+			}
+		}
+	}
+
+	fn init_seeded(&self, value: ValueType, seed: Seed) -> Result<Self::Instance, Error> {
+		match *self {
+			Self::RMA(length) => {
+				let instance = RMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::RMA(instance))
+			}
+			Self::EMA(length) => {
+				let instance = EMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::EMA(instance))
+			}
+			Self::DMA(length) => {
+				let instance = DMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::DMA(instance))
+			}
+			Self::DEMA(length) => {
+				let instance = DEMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::DEMA(instance))
+			}
+			Self::TMA(length) => {
+				let instance = TMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::TMA(instance))
+			}
+			Self::TEMA(length) => {
+				let instance = TEMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::TEMA(instance))
+			}
+			Self::WSMA(length) => {
+				let instance = WSMA::new_seeded(length, seed, &value)?;
+				Ok(Self::Instance::WSMA(instance))
+			}
+			_ => self.init(value),
 		}
 	}
 
@@ -236,7 +556,22 @@ impl MovingAverageConstructor for MA {
 			| Self::SWMA(length)
 			| Self::TRIMA(length)
 			| Self::LinReg(length)
-			| Self::Vidya(length) => *length,
+			| Self::Vidya(length)
+			| Self::Gaussian(length)
+			| Self::Lanczos(length)
+			| Self::Sinc(length)
+			| Self::Butterworth(length)
+			| Self::Chebyshev(length)
+			| Self::SuperSmoother(length)
+			| Self::Decycler(length)
+			| Self::InstantaneousTrendline(length)
+			| Self::MAMA(length)
+			| Self::ALMA(length, ..)
+			| Self::T3(length, ..)
+			| Self::ZLEMA(length)
+			| Self::McGinley(length)
+			| Self::FRAMA(length) => *length,
+			Self::Conv(weights) => PeriodType::try_from(weights.len()).unwrap_or(PeriodType::MAX),
 		}
 	}
 
@@ -257,35 +592,169 @@ impl MovingAverageConstructor for MA {
 			Self::TRIMA(_) => 12,
 			Self::LinReg(_) => 13,
 			Self::Vidya(_) => 14,
+			Self::Gaussian(_) => 15,
+			Self::Lanczos(_) => 16,
+			Self::Sinc(_) => 17,
+			Self::Butterworth(_) => 18,
+			Self::Chebyshev(_) => 19,
+			Self::SuperSmoother(_) => 20,
+			Self::Decycler(_) => 21,
+			Self::InstantaneousTrendline(_) => 22,
+			Self::MAMA(_) => 23,
+			Self::ALMA(..) => 24,
+			Self::T3(..) => 25,
+			Self::ZLEMA(_) => 26,
+			Self::McGinley(_) => 27,
+			Self::FRAMA(_) => 28,
+			Self::Conv(_) => 29,
 		}
 	}
+
+	fn with_period(&self, period: PeriodType) -> Result<Self, Error> {
+		let ma = match *self {
+			Self::SMA(_) => Self::SMA(period),
+			Self::WMA(_) => Self::WMA(period),
+			Self::HMA(_) => Self::HMA(period),
+			Self::RMA(_) => Self::RMA(period),
+			Self::EMA(_) => Self::EMA(period),
+			Self::DMA(_) => Self::DMA(period),
+			Self::TMA(_) => Self::TMA(period),
+			Self::DEMA(_) => Self::DEMA(period),
+			Self::TEMA(_) => Self::TEMA(period),
+			Self::WSMA(_) => Self::WSMA(period),
+			Self::SMM(_) => Self::SMM(period),
+			Self::SWMA(_) => Self::SWMA(period),
+			Self::TRIMA(_) => Self::TRIMA(period),
+			Self::LinReg(_) => Self::LinReg(period),
+			Self::Vidya(_) => Self::Vidya(period),
+			Self::Gaussian(_) => Self::Gaussian(period),
+			Self::Lanczos(_) => Self::Lanczos(period),
+			Self::Sinc(_) => Self::Sinc(period),
+			Self::Butterworth(_) => Self::Butterworth(period),
+			Self::Chebyshev(_) => Self::Chebyshev(period),
+			Self::SuperSmoother(_) => Self::SuperSmoother(period),
+			Self::Decycler(_) => Self::Decycler(period),
+			Self::InstantaneousTrendline(_) => Self::InstantaneousTrendline(period),
+			Self::MAMA(_) => Self::MAMA(period),
+			Self::ALMA(_, offset, sigma) => Self::ALMA(period, offset, sigma),
+			Self::T3(_, volume_factor) => Self::T3(period, volume_factor),
+			Self::ZLEMA(_) => Self::ZLEMA(period),
+			Self::McGinley(_) => Self::McGinley(period),
+			Self::FRAMA(_) => Self::FRAMA(period),
+			Self::Conv(_) => {
+				return Err(Error::Other(format!(
+					"Period of the moving average `{self}` is defined by its weights and can not be changed to {period}"
+				)))
+			}
+		};
+
+		ma.validate()?;
+
+		Ok(ma)
+	}
 }
 
 impl FromStr for MA {
 	type Err = Error;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let (method, period) = s.split_once('-').ok_or(Error::MovingAverageParse)?;
-
-		let length: PeriodType = period.parse().or(Err(Error::MovingAverageParse))?;
-
-		match method {
-			"sma" => Ok(Self::SMA(length)),
-			"wma" => Ok(Self::WMA(length)),
-			"hma" => Ok(Self::HMA(length)),
-			"rma" => Ok(Self::RMA(length)),
-			"ema" => Ok(Self::EMA(length)),
-			"dma" => Ok(Self::DMA(length)),
-			"tma" => Ok(Self::TMA(length)),
-			"dema" => Ok(Self::DEMA(length)),
-			"tema" => Ok(Self::TEMA(length)),
-			"wsma" => Ok(Self::WSMA(length)),
-			"smm" => Ok(Self::SMM(length)),
-			"swma" => Ok(Self::SWMA(length)),
-			"trima" => Ok(Self::TRIMA(length)),
-			"linreg" => Ok(Self::LinReg(length)),
-			"vidya" => Ok(Self::Vidya(length)),
-			_ => Err(Error::MovingAverageParse),
+		let (method, params) = s.split_once('-').ok_or(Error::MovingAverageParse)?;
+
+		let mut params = params.split(':');
+		let length: PeriodType = params
+			.next()
+			.unwrap_or_default()
+			.parse()
+			.or(Err(Error::MovingAverageParse))?;
+		let params: Vec<ValueType> = params
+			.map(str::parse)
+			.collect::<Result<_, _>>()
+			.or(Err(Error::MovingAverageParse))?;
+
+		let ma = match (method, params.as_slice()) {
+			("sma", []) => Self::SMA(length),
+			("wma", []) => Self::WMA(length),
+			("hma", []) => Self::HMA(length),
+			("rma", []) => Self::RMA(length),
+			("ema", []) => Self::EMA(length),
+			("dma", []) => Self::DMA(length),
+			("tma", []) => Self::TMA(length),
+			("dema", []) => Self::DEMA(length),
+			("tema", []) => Self::TEMA(length),
+			("wsma", []) => Self::WSMA(length),
+			("smm", []) => Self::SMM(length),
+			("swma", []) => Self::SWMA(length),
+			("trima", []) => Self::TRIMA(length),
+			("linreg", []) => Self::LinReg(length),
+			("vidya", []) => Self::Vidya(length),
+			("gaussian", []) => Self::Gaussian(length),
+			("lanczos", []) => Self::Lanczos(length),
+			("sinc", []) => Self::Sinc(length),
+			("butterworth", []) => Self::Butterworth(length),
+			("chebyshev", []) => Self::Chebyshev(length),
+			("supersmoother", []) => Self::SuperSmoother(length),
+			("decycler", []) => Self::Decycler(length),
+			("itrend", []) => Self::InstantaneousTrendline(length),
+			("mama", []) => Self::MAMA(length),
+			("alma", []) => Self::ALMA(length, ALMA_OFFSET, ALMA_SIGMA),
+			("alma", &[offset]) => Self::ALMA(length, offset, ALMA_SIGMA),
+			("alma", &[offset, sigma]) => Self::ALMA(length, offset, sigma),
+			("t3", []) => Self::T3(length, T3_VOLUME_FACTOR),
+			("t3", &[volume_factor]) => Self::T3(length, volume_factor),
+			("zlema", []) => Self::ZLEMA(length),
+			("mcginley", []) => Self::McGinley(length),
+			("frama", []) => Self::FRAMA(length),
+			("conv", weights) if weights.len() == length as usize => Self::Conv(weights.to_vec()),
+			_ => return Err(Error::MovingAverageParse),
+		};
+
+		ma.validate()?;
+
+		Ok(ma)
+	}
+}
+
+impl fmt::Display for MA {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let method = match self {
+			Self::SMA(_) => "sma",
+			Self::WMA(_) => "wma",
+			Self::HMA(_) => "hma",
+			Self::RMA(_) => "rma",
+			Self::EMA(_) => "ema",
+			Self::DMA(_) => "dma",
+			Self::TMA(_) => "tma",
+			Self::DEMA(_) => "dema",
+			Self::TEMA(_) => "tema",
+			Self::WSMA(_) => "wsma",
+			Self::SMM(_) => "smm",
+			Self::SWMA(_) => "swma",
+			Self::TRIMA(_) => "trima",
+			Self::LinReg(_) => "linreg",
+			Self::Vidya(_) => "vidya",
+			Self::Gaussian(_) => "gaussian",
+			Self::Lanczos(_) => "lanczos",
+			Self::Sinc(_) => "sinc",
+			Self::Butterworth(_) => "butterworth",
+			Self::Chebyshev(_) => "chebyshev",
+			Self::SuperSmoother(_) => "supersmoother",
+			Self::Decycler(_) => "decycler",
+			Self::InstantaneousTrendline(_) => "itrend",
+			Self::MAMA(_) => "mama",
+			Self::ALMA(..) => "alma",
+			Self::T3(..) => "t3",
+			Self::ZLEMA(_) => "zlema",
+			Self::McGinley(_) => "mcginley",
+			Self::FRAMA(_) => "frama",
+			Self::Conv(_) => "conv",
+		};
+
+		write!(f, "{method}-{}", self.ma_period())?;
+		match self {
+			Self::ALMA(_, offset, sigma) => write!(f, ":{offset}:{sigma}"),
+			Self::T3(_, volume_factor) => write!(f, ":{volume_factor}"),
+			Self::Conv(weights) => weights.iter().try_for_each(|weight| write!(f, ":{weight}")),
+			_ => Ok(()),
 		}
 	}
 }
@@ -3,11 +3,15 @@
 //!
 
 mod history;
+mod ma_chain;
 mod methods;
+mod normalize;
 
-use crate::core::{Candle, ValueType};
-pub use history::{Buffered, Peekable, WithHistory, WithLastValue};
+use crate::core::{Candle, TimedCandle, Timestamp, ValueType};
+pub use history::{Buffered, Peekable, WithHistory, WithLastValue, WithWarmup};
+pub use ma_chain::{MAChain, MAChainInstance};
 pub use methods::{MAInstance, MA};
+pub use normalize::{Normalization, Normalized, NormalizedRange};
 
 /// sign is like [`f64::signum`]
 /// except when value == 0.0, then sign returns 0.0
@@ -149,6 +153,16 @@ impl RandomCandles {
 
 		candle
 	}
+
+	/// Converts this iterator into [`RandomTimedCandles`], which timestamps start at `start` and increase by `step`
+	#[must_use]
+	pub const fn timed(self, start: Timestamp, step: Timestamp) -> RandomTimedCandles {
+		RandomTimedCandles {
+			candles: self,
+			timestamp: start,
+			step,
+		}
+	}
 }
 
 impl Iterator for RandomCandles {
@@ -190,3 +204,45 @@ impl Iterator for RandomCandles {
 		self.next()
 	}
 }
+
+/// Random [`TimedCandle`]s iterator for testing purposes
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomTimedCandles;
+///
+/// let candles: Vec<_> = RandomTimedCandles::new(1_000, 60).take(3).collect();
+///
+/// assert_eq!(candles.timestamps(), vec![1_000, 1_060, 1_120]);
+/// ```
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)]
+pub struct RandomTimedCandles {
+	candles: RandomCandles,
+	timestamp: Timestamp,
+	step: Timestamp,
+}
+
+impl RandomTimedCandles {
+	/// Returns new instance of `RandomTimedCandles` for testing purposes.
+	///
+	/// Timestamps start at `start` and increase by `step`.
+	#[must_use]
+	pub fn new(start: Timestamp, step: Timestamp) -> Self {
+		RandomCandles::new().timed(start, step)
+	}
+}
+
+impl Iterator for RandomTimedCandles {
+	type Item = TimedCandle;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let candle = self.candles.next()?;
+		let timestamp = self.timestamp;
+		self.timestamp = self.timestamp.saturating_add(self.step);
+
+		Some(TimedCandle { timestamp, candle })
+	}
+}
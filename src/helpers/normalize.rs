@@ -0,0 +1,364 @@
+use crate::core::{Error, IndicatorConfig, IndicatorInstance, IndicatorResult, PeriodType, OHLCV};
+use crate::core::{Method, ValueType};
+use crate::methods::{Highest, Lowest, PercentRank};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Range of values produced by [`Normalized`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum NormalizedRange {
+	/// Values in \[`-1.0`; `1.0`\]
+	#[default]
+	Signed,
+
+	/// Values in \[`0.0`; `100.0`\]
+	Percent,
+}
+
+/// Method of mapping values into the [`NormalizedRange`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Normalization {
+	/// [`PercentRank`] of the value over the previous `lookback` values
+	#[default]
+	PercentRank,
+
+	/// Position of the value between [`Lowest`] and [`Highest`] of the last `lookback` values
+	///
+	/// When all of the values are equal, the value is mapped into the middle of the range.
+	MinMax,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Scaler {
+	PercentRank(PercentRank),
+	MinMax(Highest, Lowest),
+}
+
+impl Scaler {
+	fn new(
+		normalization: Normalization,
+		lookback: PeriodType,
+		value: ValueType,
+	) -> Result<Self, Error> {
+		let scaler = match normalization {
+			Normalization::PercentRank => Self::PercentRank(PercentRank::new(lookback, &value)?),
+			Normalization::MinMax => Self::MinMax(
+				Highest::new(lookback, &value)?,
+				Lowest::new(lookback, &value)?,
+			),
+		};
+
+		Ok(scaler)
+	}
+
+	/// Returns scaled value in \[`0.0`; `1.0`\]
+	fn next(&mut self, value: ValueType) -> ValueType {
+		match self {
+			Self::PercentRank(percent_rank) => percent_rank.next(&value) * 0.01,
+			Self::MinMax(highest, lowest) => {
+				let (highest, lowest) = (highest.next(&value), lowest.next(&value));
+
+				if highest > lowest {
+					(value - lowest) / (highest - lowest)
+				} else {
+					0.5
+				}
+			}
+		}
+	}
+
+	fn warmup_len(&self) -> usize {
+		match self {
+			Self::PercentRank(percent_rank) => percent_rank.warmup_len(),
+			Self::MinMax(highest, _) => highest.warmup_len(),
+		}
+	}
+}
+
+/// Wrapper for normalizing a single raw value of an indicator over the `lookback` period
+///
+/// Every [`IndicatorResult`] produced by the wrapped indicator keeps all of its values and signals,
+/// except the value at the index `lane`, which is replaced by its normalized value in the [`NormalizedRange`].
+///
+/// Normalization is seeded by the first finite value, which the indicator returns.
+/// Non-finite values (`NaN` or infinities) are passed through as is and are not taken into account by the normalization.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{Normalization, Normalized, NormalizedRange, RandomCandles};
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// // MACD line as a percent rank over the last 50 candles
+/// let mut macd = Normalized::init(
+///     MACD::default(),
+///     &candles[0],
+///     0,
+///     50,
+///     Normalization::PercentRank,
+///     NormalizedRange::Percent,
+/// )
+/// .unwrap();
+///
+/// let results = macd.over(&candles);
+/// assert!(results.iter().all(|x| (0.0..=100.0).contains(&x.value(0))));
+///
+/// // MACD signal line between its lowest and highest values over the last 50 candles
+/// let mut macd = Normalized::init(
+///     MACD::default(),
+///     &candles[0],
+///     1,
+///     50,
+///     Normalization::MinMax,
+///     NormalizedRange::Signed,
+/// )
+/// .unwrap();
+///
+/// let results = macd.over(&candles);
+/// assert!(results.iter().all(|x| (-1.0..=1.0).contains(&x.value(1))));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Normalized<I> {
+	lane: usize,
+	range: NormalizedRange,
+	normalization: Normalization,
+	lookback: PeriodType,
+	scaler: Scaler,
+	is_seeded: bool,
+	instance: I,
+}
+
+impl<I: IndicatorInstance> Normalized<I> {
+	/// Initializes the indicator **State** based on the given **Configuration**, wrapped by normalizer of the raw value at the index `lane`
+	///
+	/// Returns an error if the `config` is invalid, if there is no raw value at the index `lane` or if `lookback` is `0`.
+	pub fn init<C, T>(
+		config: C,
+		initial_value: &T,
+		lane: usize,
+		lookback: PeriodType,
+		normalization: Normalization,
+		range: NormalizedRange,
+	) -> Result<Self, Error>
+	where
+		C: IndicatorConfig<Instance = I>,
+		T: OHLCV,
+	{
+		let values = config.size().0;
+		Error::ensure(
+			lane < values as usize,
+			"lane",
+			lane,
+			format_args!("must be < values count {values}"),
+		)?;
+
+		// the scaler is seeded again by the first value of the indicator, it only validates the parameters here
+		let scaler = Scaler::new(normalization, lookback, 0.0)?;
+
+		Ok(Self {
+			lane,
+			range,
+			normalization,
+			lookback,
+			scaler,
+			is_seeded: false,
+			instance: config.init(initial_value)?,
+		})
+	}
+
+	/// Returns count of the leading results, which are considered to be unstable
+	///
+	/// It is a sum of the wrapped indicator warm-up period and the normalization warm-up period.
+	pub fn warmup_len(&self) -> usize {
+		self.instance.warmup_len() + self.scaler.warmup_len()
+	}
+
+	/// Returns a reference to the wrapped instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Evaluates given candle and returns [`IndicatorResult`] with the normalized value at the index `lane`
+	///
+	/// Non-finite value at the index `lane` is returned as is.
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let mut result = self.instance.next(candle);
+		let value = result.value(self.lane);
+
+		if !value.is_finite() {
+			return result;
+		}
+
+		if !self.is_seeded {
+			self.is_seeded = true;
+
+			// can not fail, because the parameters are validated by `init` and the value is finite
+			if let Ok(scaler) = Scaler::new(self.normalization, self.lookback, value) {
+				self.scaler = scaler;
+			}
+		}
+
+		let scaled = self.scaler.next(value);
+
+		let value = match self.range {
+			NormalizedRange::Signed => scaled.mul_add(2.0, -1.0),
+			NormalizedRange::Percent => scaled * 100.0,
+		};
+		result.set_value(self.lane, value);
+
+		result
+	}
+
+	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s with normalized values
+	pub fn over<T, S>(&mut self, inputs: S) -> Vec<IndicatorResult>
+	where
+		T: OHLCV,
+		S: AsRef<[T]>,
+	{
+		inputs.as_ref().iter().map(|x| self.next(x)).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Normalization, Normalized, NormalizedRange};
+	use crate::core::{
+		Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV,
+	};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::MACD;
+
+	/// Indicator, which returns the close price as is, including non-finite values
+	#[derive(Debug, Clone, Copy)]
+	struct Close;
+
+	#[derive(Debug)]
+	struct CloseInstance(Close);
+
+	impl IndicatorConfig for Close {
+		type Instance = CloseInstance;
+
+		const NAME: &'static str = "Close";
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, _: String) -> Result<(), Error> {
+			Err(Error::UnknownParameter(name.to_string()))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(1, 0)
+		}
+
+		fn init<T: OHLCV>(self, _: &T) -> Result<Self::Instance, Error> {
+			Ok(CloseInstance(self))
+		}
+	}
+
+	impl IndicatorInstance for CloseInstance {
+		type Config = Close;
+
+		fn config(&self) -> &Self::Config {
+			&self.0
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			IndicatorResult::new(&[candle.close()], &[])
+		}
+	}
+
+	#[test]
+	fn test_normalized_seed() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		for (normalization, expected) in [
+			(Normalization::PercentRank, 1.0),
+			(Normalization::MinMax, 0.0),
+		] {
+			let mut macd = Normalized::init(
+				MACD::default(),
+				&candles[0],
+				0,
+				20,
+				normalization,
+				NormalizedRange::Signed,
+			)
+			.unwrap();
+
+			// the first value is compared only with itself
+			assert_eq_float(expected, macd.next(&candles[1]).value(0));
+
+			// other values are kept untouched
+			let mut fixed = MACD::default().init(&candles[0]).unwrap();
+			fixed.next(&candles[1]);
+			for candle in &candles[2..] {
+				let result = macd.next(candle);
+				assert_eq!(result.values()[1..], fixed.next(candle).values()[1..]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_normalized_non_finite() {
+		let candle = |close: ValueType| Candle {
+			close,
+			..Candle::default()
+		};
+
+		for (normalization, expected) in [
+			(Normalization::PercentRank, 100.0),
+			(Normalization::MinMax, 50.0),
+		] {
+			let mut close = Normalized::init(
+				Close,
+				&candle(0.0),
+				0,
+				3,
+				normalization,
+				NormalizedRange::Percent,
+			)
+			.unwrap();
+
+			assert!(close.next(&candle(ValueType::NAN)).value(0).is_nan());
+			assert!(close
+				.next(&candle(ValueType::NEG_INFINITY))
+				.value(0)
+				.is_infinite());
+
+			// the first finite value seeds the normalization
+			assert_eq_float(expected, close.next(&candle(1.0)).value(0));
+			assert!(close.next(&candle(ValueType::NAN)).value(0).is_nan());
+			assert_eq_float(0.0, close.next(&candle(-1.0)).value(0));
+			assert_eq_float(100.0, close.next(&candle(2.0)).value(0));
+		}
+	}
+
+	#[test]
+	fn test_normalized_parameters() {
+		let candle = RandomCandles::new().first();
+		let init = |lane, lookback| {
+			Normalized::init(
+				MACD::default(),
+				&candle,
+				lane,
+				lookback,
+				Normalization::PercentRank,
+				NormalizedRange::Percent,
+			)
+		};
+
+		assert!(init(0, 1).is_ok());
+		assert!(init(3, 10).is_err());
+		assert!(init(0, 0).is_err());
+	}
+}
@@ -0,0 +1,469 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, ParamDescriptor,
+	ParamType,
+};
+use crate::helpers::{MAChain, Peekable};
+use crate::methods::{CycleEstimator, DominantCycle};
+use std::ops::Bound;
+
+/// Adaptive wrapper over any period-based indicator
+///
+/// Measures the [`DominantCycle`] of the `cycle_source` values and feeds it (multiplied by `fraction`) into the
+/// `parameter` of the wrapped `indicator` at every step.
+///
+/// Internally it keeps one instance of the `indicator` for every possible period in
+/// \[`min_period` × `fraction`; `max_period` × `fraction`\], so all of them have the actual state at any moment.
+/// Every step returns the result of the instance with the period closest to the current estimation.
+/// So `init` creates (`max_period` - `min_period`) × `fraction` + `1` instances (`39` with the default parameters)
+/// and every candle is passed to all of them.
+///
+/// A moving average `parameter` is adapted by [`with_period`](MovingAverageConstructor::with_period), so it must be
+/// a [`MA`](crate::helpers::MA) or a [`MAChain`]. Only the first stage of the chain adapts.
+/// Moving averages, which period can not be changed (f.e. [`MA::Conv`](crate::helpers::MA::Conv)), are reported as invalid configuration.
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/Cycle%20Analytics%20for%20Traders.pdf>
+///
+/// # Values and signals
+///
+/// The same as the wrapped `indicator` has.
+///
+/// # Parameters
+///
+/// [`PARAMS`](IndicatorConfig::PARAMS) contains only the parameters of the wrapper itself.
+/// [`set`](IndicatorConfig::set) and [`get`](IndicatorConfig::get) pass any other parameter to the wrapped `indicator`.
+///
+/// # Examples
+///
+/// Ehlers' adaptive RSI, which uses a half of the dominant cycle:
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{Adaptive, RSI};
+///
+/// let mut adaptive_rsi = Adaptive::new(RSI::default());
+/// adaptive_rsi.fraction = 0.5;
+/// assert_eq!(adaptive_rsi.parameter, "ma");
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = adaptive_rsi.over(&candles).unwrap();
+///
+/// assert!(results.iter().all(|x| (0.0..=1.0).contains(&x.value(0))));
+/// ```
+///
+/// # Performance
+///
+/// O(`max_period` - `min_period`) times the performance of the wrapped `indicator` plus the performance of [`DominantCycle`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Adaptive<C: IndicatorConfig> {
+	/// Wrapped indicator config
+	pub indicator: C,
+
+	/// Name of the [`Period`](ParamType::Period) or [`MovingAverage`](ParamType::MovingAverage) parameter
+	/// of the `indicator`, which adapts to the dominant cycle.
+	///
+	/// Default is the first such parameter of the `indicator`.
+	pub parameter: String,
+
+	/// Dominant cycle estimation algorithm. Default is [`Homodyne`](CycleEstimator::Homodyne).
+	pub estimator: CycleEstimator,
+
+	/// Shortest measured cycle period. Default is `10`.
+	///
+	/// Range in \[`3`; `max_period`\)
+	pub min_period: PeriodType,
+
+	/// Longest measured cycle period. Default is `48`.
+	///
+	/// Range in \(`min_period`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub max_period: PeriodType,
+
+	/// Multiplier of the dominant cycle period before it is passed to the `indicator`. Default is `1.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub fraction: ValueType,
+
+	/// Source type of values for the dominant cycle estimation. Default is [`Close`](crate::core::Source::Close)
+	pub cycle_source: Source,
+}
+
+impl<C: IndicatorConfig> Adaptive<C> {
+	/// Creates an adaptive wrapper over the first period-based parameter of the `indicator`
+	pub fn new(indicator: C) -> Self {
+		let parameter = C::PARAMS
+			.iter()
+			.find(|param| matches!(param.kind, ParamType::Period | ParamType::MovingAverage))
+			.map_or_else(String::new, |param| param.name.to_string());
+
+		Self {
+			indicator,
+			parameter,
+			estimator: CycleEstimator::Homodyne,
+			min_period: 10,
+			max_period: 48,
+			fraction: 1.0,
+			cycle_source: Source::Close,
+		}
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn scale(&self, period: ValueType) -> PeriodType {
+		(period * self.fraction).round() as PeriodType
+	}
+
+	/// Returns the range of the `indicator` periods
+	fn periods(&self) -> (PeriodType, PeriodType) {
+		(
+			self.scale(self.min_period as ValueType),
+			self.scale(self.max_period as ValueType),
+		)
+	}
+
+	/// Returns a copy of the `indicator` with the `parameter` set to `period`
+	fn configure(&self, period: PeriodType) -> Result<C, Error> {
+		let kind = C::PARAMS
+			.iter()
+			.find(|param| param.name == self.parameter)
+			.map(|param| param.kind);
+
+		let value = match kind {
+			Some(ParamType::Period) => period.to_string(),
+			Some(ParamType::MovingAverage) => {
+				// a single moving average is a chain of one stage
+				let ma: MAChain = self.indicator.get(&self.parameter)?.parse()?;
+				ma.with_period(period)?.to_string()
+			}
+			_ => {
+				return Err(Error::invalid_parameter(
+					"parameter",
+					&self.parameter,
+					format_args!("must be a period or a moving average of {}", C::NAME),
+				))
+			}
+		};
+
+		let mut indicator = self.indicator.clone();
+		indicator.set(&self.parameter, value)?;
+
+		Ok(indicator)
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for Adaptive<C> {
+	type Instance = AdaptiveInstance<C>;
+
+	const NAME: &'static str = "Adaptive";
+
+	const OUTPUTS: IndicatorOutputs = C::OUTPUTS;
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::cycle_estimator("estimator", "homodyne"),
+		ParamDescriptor::period("min_period", "10", 3),
+		ParamDescriptor::period("max_period", "48", 4),
+		ParamDescriptor::value("fraction", "1", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("cycle_source", "close"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let (first_period, last_period) = self.periods();
+		let instances = (first_period..=last_period)
+			.map(|period| self.configure(period)?.init(candle))
+			.collect::<Result<_, _>>()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.cycle_source);
+		Ok(Self::Instance {
+			dominant_cycle: DominantCycle::new(
+				(cfg.estimator, cfg.min_period, cfg.max_period),
+				&src,
+			)?,
+			instances,
+			first_period,
+			last_period,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.min_period > 2,
+			"min_period",
+			self.min_period,
+			"must be > 2",
+		)?;
+		Error::ensure(
+			self.max_period > self.min_period,
+			"max_period",
+			self.max_period,
+			format_args!("must be > min_period {}", self.min_period),
+		)?;
+		Error::ensure(
+			self.fraction > 0.0 && self.fraction.is_finite(),
+			"fraction",
+			self.fraction,
+			"must be > 0",
+		)?;
+
+		let (first_period, last_period) = self.periods();
+		Error::ensure(
+			first_period > 0,
+			"fraction",
+			self.fraction,
+			format_args!("is too small for min_period {}", self.min_period),
+		)?;
+		Error::ensure(
+			(self.max_period as ValueType * self.fraction) < PeriodType::MAX as ValueType,
+			"fraction",
+			self.fraction,
+			format_args!("is too big for max_period {}", self.max_period),
+		)?;
+
+		self.configure(first_period)?.validate_detailed()?;
+		self.configure(last_period)?.validate_detailed()?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"estimator" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.estimator = value,
+			},
+			"min_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.min_period = value,
+			},
+			"max_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.max_period = value,
+			},
+			"fraction" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.fraction = value,
+			},
+			"cycle_source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.cycle_source = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"estimator" => Ok(self.estimator.to_string()),
+			"min_period" => Ok(self.min_period.to_string()),
+			"max_period" => Ok(self.max_period.to_string()),
+			"fraction" => Ok(self.fraction.to_string()),
+			"cycle_source" => Ok(self.cycle_source.to_string()),
+
+			_ => self.indicator.get(name),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<C: IndicatorConfig + Default> Default for Adaptive<C> {
+	fn default() -> Self {
+		Self::new(C::default())
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "C: Serialize, C::Instance: Serialize",
+		deserialize = "C: Deserialize<'de>, C::Instance: Deserialize<'de>"
+	))
+)]
+pub struct AdaptiveInstance<C: IndicatorConfig> {
+	cfg: Adaptive<C>,
+
+	dominant_cycle: DominantCycle,
+	instances: Vec<C::Instance>,
+	first_period: PeriodType,
+	last_period: PeriodType,
+}
+
+impl<C: IndicatorConfig> AdaptiveInstance<C> {
+	/// Returns the last measured dominant cycle period, which is not multiplied by `fraction`
+	pub fn dominant_cycle(&self) -> ValueType {
+		self.dominant_cycle.peek()
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for AdaptiveInstance<C> {
+	type Config = Adaptive<C>;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let cycle = self
+			.dominant_cycle
+			.next(&candle.source(self.cfg.cycle_source));
+		let period = self
+			.cfg
+			.scale(cycle)
+			.clamp(self.first_period, self.last_period);
+		let index = (period - self.first_period) as usize;
+
+		// every instance has to see every candle to stay ready for the future periods
+		for (i, instance) in self.instances.iter_mut().enumerate() {
+			if i != index {
+				instance.next(candle);
+			}
+		}
+
+		self.instances[index].next(candle)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.instances
+			.iter()
+			.map(IndicatorInstance::warmup_len)
+			.fold(self.dominant_cycle.warmup_len(), usize::max)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Adaptive;
+	use crate::core::{IndicatorConfig, IndicatorInstance, Seed, Source};
+	use crate::helpers::{assert_eq_float, MAChain, RandomCandles};
+	use crate::indicators::{StochasticOscillator, RSI};
+	use crate::methods::CycleEstimator;
+
+	#[test]
+	fn test_adaptive_parameter() {
+		let adaptive = Adaptive::<RSI>::default();
+		assert_eq!(adaptive.parameter, "ma");
+		assert_eq!(adaptive.configure(20).unwrap().get("ma").unwrap(), "ema-20");
+
+		let adaptive = Adaptive::new(RSI::<MAChain> {
+			ma: "alma-14:0.9>sma-3".parse().unwrap(),
+			zone: 0.3,
+			source: Source::Close,
+			seed: Seed::Initial,
+		});
+		assert_eq!(
+			adaptive.configure(20).unwrap().get("ma").unwrap(),
+			"alma-20:0.9:6>sma-3"
+		);
+
+		let adaptive = Adaptive::new(RSI {
+			ma: "conv-3:1:2:1".parse().unwrap(),
+			..RSI::default()
+		});
+		assert!(adaptive.configure(20).is_err());
+		assert!(!adaptive.validate());
+
+		let adaptive = Adaptive::<StochasticOscillator>::default();
+		assert_eq!(adaptive.parameter, "period");
+		assert_eq!(adaptive.configure(20).unwrap().get("period").unwrap(), "20");
+
+		let adaptive = Adaptive::<RSI> {
+			parameter: "zone".to_string(),
+			..Adaptive::default()
+		};
+		assert!(adaptive.configure(20).is_err());
+		assert!(!adaptive.validate());
+	}
+
+	#[test]
+	fn test_adaptive_matches_fixed() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for estimator in [CycleEstimator::Homodyne, CycleEstimator::Autocorrelation] {
+			let adaptive = Adaptive::<StochasticOscillator> {
+				estimator,
+				fraction: 0.5,
+				..Adaptive::default()
+			};
+
+			let mut instance = adaptive.clone().init(&candles[0]).unwrap();
+			let mut fixed: Vec<_> = (5..=24)
+				.map(|period| {
+					let config = StochasticOscillator {
+						period,
+						..StochasticOscillator::default()
+					};
+					config.init(&candles[0]).unwrap()
+				})
+				.collect();
+
+			for candle in &candles {
+				let result = instance.next(candle);
+				let results: Vec<_> = fixed.iter_mut().map(|x| x.next(candle)).collect();
+
+				let period = (instance.dominant_cycle() * 0.5).round().clamp(5.0, 24.0);
+				#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+				let expected = &results[period as usize - 5];
+
+				assert_eq_float(expected.value(0), result.value(0));
+				assert_eq_float(expected.value(1), result.value(1));
+				assert_eq!(expected.signals(), result.signals());
+			}
+		}
+	}
+
+	#[test]
+	fn test_adaptive_set_get() {
+		let mut adaptive = Adaptive::<RSI>::default();
+
+		adaptive
+			.set("estimator", "autocorrelation".to_string())
+			.unwrap();
+		adaptive.set("zone", "0.25".to_string()).unwrap();
+
+		assert_eq!(adaptive.get("estimator").unwrap(), "autocorrelation");
+		assert_eq_float(0.25, adaptive.indicator.zone);
+		assert!(adaptive.set("unknown", "1".to_string()).is_err());
+		assert!(adaptive.get("unknown").is_err());
+	}
+
+	#[test]
+	fn test_adaptive_parameters() {
+		let mut adaptive = Adaptive::<RSI>::default();
+		assert!(adaptive.validate());
+
+		adaptive.min_period = 2;
+		assert!(!adaptive.validate());
+
+		adaptive.min_period = 10;
+		adaptive.max_period = 10;
+		assert!(!adaptive.validate());
+
+		adaptive.max_period = 48;
+		adaptive.fraction = 0.0;
+		assert!(!adaptive.validate());
+
+		// RSI period must be > 2
+		adaptive.fraction = 0.2;
+		assert!(!adaptive.validate());
+	}
+}
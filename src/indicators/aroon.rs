@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Cross, HighestIndex, LowestIndex};
+use std::ops::Bound;
 
 // https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/aroon-indicator
 // Aroon-Up = [(Period Specified – Periods Since the Highest High within Period Specified) / Period Specified]
@@ -58,10 +62,31 @@ impl IndicatorConfig for Aroon {
 
 	const NAME: &'static str = "Aroon";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("aroon_up", 0.0, 1.0),
+			OutputDescriptor::oscillator("aroon_down", 0.0, 1.0),
+		],
+		signals: &[
+			OutputDescriptor::signal("cross"),
+			OutputDescriptor::signal("edge"),
+			OutputDescriptor::signal("trend"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "14", 2),
+		ParamDescriptor::value(
+			"signal_zone",
+			"0.3",
+			Bound::Included(0.0),
+			Bound::Included(1.0),
+		),
+		ParamDescriptor::period("over_zone_period", "7", 1),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 
@@ -76,12 +101,37 @@ impl IndicatorConfig for Aroon {
 	}
 
 	fn validate(&self) -> bool {
-		self.signal_zone >= 0.0
-			&& self.signal_zone <= 1.0
-			&& self.period > 1
-			&& self.period < PeriodType::MAX
-			&& self.over_zone_period > 0
-			&& self.over_zone_period < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			(0.0..=1.0).contains(&self.signal_zone),
+			"signal_zone",
+			self.signal_zone,
+			"must be in [0; 1]",
+		)?;
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+		Error::ensure(
+			self.period < PeriodType::MAX,
+			"period",
+			self.period,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			self.over_zone_period > 0,
+			"over_zone_period",
+			self.over_zone_period,
+			"must be > 0",
+		)?;
+		Error::ensure(
+			self.over_zone_period < PeriodType::MAX,
+			"over_zone_period",
+			self.over_zone_period,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -107,6 +157,16 @@ impl IndicatorConfig for Aroon {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"signal_zone" => Ok(self.signal_zone.to_string()),
+			"over_zone_period" => Ok(self.over_zone_period.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -172,4 +232,10 @@ impl IndicatorInstance for AroonInstance {
 			&[trend_signal, edge_signal.into(), trend_value.into()],
 		)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.highest_index
+			.warmup_len()
+			.max(self.lowest_index.warmup_len())
+	}
 }
@@ -2,9 +2,15 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Error, Method, MovingAverageConstructor, PeriodType, Seed, ValueType, Window, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
+use std::ops::Bound;
 
 /// Average Directional Index
 ///
@@ -52,6 +58,12 @@ pub struct AverageDirectionalIndex<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`0.0`; `1.0`\]
 	pub zone: ValueType,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M> {
@@ -59,10 +71,28 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M>
 
 	const NAME: &'static str = "AverageDirectionalIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("adx", 0.0, 1.0),
+			OutputDescriptor::oscillator("plus_di", 0.0, 1.0),
+			OutputDescriptor::oscillator("minus_di", 0.0, 1.0),
+		],
+		signals: &[
+			OutputDescriptor::signal("trend"),
+			OutputDescriptor::signal("direction"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("method1", "rma-14", 2),
+		ParamDescriptor::moving_average("method2", "rma-14", 2),
+		ParamDescriptor::period("period1", "1", 1),
+		ParamDescriptor::value("zone", "0.2", Bound::Included(0.0), Bound::Included(1.0)),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let tr = candle.tr(candle);
@@ -70,24 +100,64 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M>
 		Ok(Self::Instance {
 			window: Window::new(cfg.period1, HLC::from(candle)),
 			prev_close: candle.close(),
-			tr_ma: cfg.method1.init(tr)?,
-			plus_di: cfg.method1.init(0.0)?,
-			minus_di: cfg.method1.init(0.0)?,
-			ma2: cfg.method2.init(0.0)?,
+			tr_ma: cfg.method1.init_seeded(tr, cfg.seed)?,
+			plus_di: cfg.method1.init_seeded(0.0, cfg.seed)?,
+			minus_di: cfg.method1.init_seeded(0.0, cfg.seed)?,
+			ma2: cfg.method2.init_seeded(0.0, cfg.seed)?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.method1.ma_period() >= 1
-			&& self.method1.ma_period() < PeriodType::MAX
-			&& self.method2.ma_period() >= 1
-			&& self.method2.ma_period() < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
-			&& self.period1 >= 1
-			&& self.period1 < self.method1.ma_period()
-			&& self.period1 < self.method2.ma_period()
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.method1.ma_period() >= 1,
+			"method1 period",
+			self.method1.ma_period(),
+			"must be >= 1",
+		)?;
+		Error::ensure(
+			self.method1.ma_period() < PeriodType::MAX,
+			"method1 period",
+			self.method1.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			self.method2.ma_period() >= 1,
+			"method2 period",
+			self.method2.ma_period(),
+			"must be >= 1",
+		)?;
+		Error::ensure(
+			self.method2.ma_period() < PeriodType::MAX,
+			"method2 period",
+			self.method2.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			(0.0..=1.0).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 1]",
+		)?;
+		Error::ensure(self.period1 >= 1, "period1", self.period1, "must be >= 1")?;
+		Error::ensure(
+			self.period1 < self.method1.ma_period(),
+			"period1",
+			self.period1,
+			format_args!("must be < method1 period {}", self.method1.ma_period()),
+		)?;
+		Error::ensure(
+			self.period1 < self.method2.ma_period(),
+			"period1",
+			self.period1,
+			format_args!("must be < method2 period {}", self.method2.ma_period()),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -108,6 +178,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M>
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -117,6 +191,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M>
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"method1" => Ok(self.method1.to_string()),
+			"method2" => Ok(self.method2.to_string()),
+			"period1" => Ok(self.period1.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
@@ -129,6 +215,7 @@ impl Default for AverageDirectionalIndex {
 			method2: MA::RMA(14),
 			period1: 1,
 			zone: 0.2,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -201,4 +288,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for AverageDirectionalIndexI
 
 		IndicatorResult::new(&values, &[signal1.into(), signal2.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize + self.plus_di.warmup_len() + self.ma2.warmup_len()
+	}
 }
@@ -1,10 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor, ParamType,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, ReversalSignal};
+use std::ops::Bound;
 
 /// Awesome Oscillator
 ///
@@ -49,6 +53,12 @@ pub struct AwesomeOscillator<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\].
 	pub conseq_peaks: u8,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
@@ -56,17 +66,38 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
 
 	const NAME: &'static str = "AwesomeOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::unbounded("main")],
+		signals: &[
+			OutputDescriptor::signal("twin_peaks"),
+			OutputDescriptor::signal("zero_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma1", "sma-34", 3),
+		ParamDescriptor::moving_average("ma2", "sma-5", 2),
+		ParamDescriptor::source("source", "hl2"),
+		ParamDescriptor::period("left", "1", 1),
+		ParamDescriptor::period("right", "1", 1),
+		ParamDescriptor::new(
+			"conseq_peaks",
+			ParamType::Integer,
+			"2",
+			(Bound::Included(1.0), Bound::Included(255.0)),
+		),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma1: cfg.ma1.init(src)?,
-			ma2: cfg.ma2.init(src)?,
+			ma1: cfg.ma1.init_seeded(src, cfg.seed)?,
+			ma2: cfg.ma2.init_seeded(src, cfg.seed)?,
 			cross_over: Cross::default(),
 			reverse: Method::new((cfg.left, cfg.right), &0.0)?,
 			low_peaks: 0,
@@ -76,15 +107,56 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.ma_period() > 2
-			&& self.ma1.is_similar_to(&self.ma2)
-			&& self.ma1.ma_period() < PeriodType::MAX
-			&& self.ma1.ma_period() > self.ma2.ma_period()
-			&& self.ma2.ma_period() > 1
-			&& self.left > 0
-			&& self.right > 0
-			&& self.conseq_peaks > 0
-			&& self.left.saturating_add(self.right) < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.ma_period() > 2,
+			"ma1 period",
+			self.ma1.ma_period(),
+			"must be > 2",
+		)?;
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma2),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma2 {}", self.ma2),
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() < PeriodType::MAX,
+			"ma1 period",
+			self.ma1.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() > self.ma2.ma_period(),
+			"ma1 period",
+			self.ma1.ma_period(),
+			format_args!("must be > ma2 period {}", self.ma2.ma_period()),
+		)?;
+		Error::ensure(
+			self.ma2.ma_period() > 1,
+			"ma2 period",
+			self.ma2.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(self.left > 0, "left", self.left, "must be > 0")?;
+		Error::ensure(self.right > 0, "right", self.right, "must be > 0")?;
+		Error::ensure(
+			self.conseq_peaks > 0,
+			"conseq_peaks",
+			self.conseq_peaks,
+			"must be > 0",
+		)?;
+		Error::ensure(
+			self.left.saturating_add(self.right) < PeriodType::MAX,
+			"left",
+			self.left,
+			format_args!("+ right {} must be < {}", self.right, PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -109,6 +181,14 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.right = value,
 			},
+			"conseq_peaks" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.conseq_peaks = value,
+			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -118,6 +198,20 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma1" => Ok(self.ma1.to_string()),
+			"ma2" => Ok(self.ma2.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"left" => Ok(self.left.to_string()),
+			"right" => Ok(self.right.to_string()),
+			"conseq_peaks" => Ok(self.conseq_peaks.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
@@ -132,6 +226,7 @@ impl Default for AwesomeOscillator<MA> {
 			left: 1,
 			right: 1,
 			conseq_peaks: 2,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -182,4 +277,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for AwesomeOscillatorInstanc
 
 		IndicatorResult::new(&values, &signals)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ma1.warmup_len().max(self.ma2.warmup_len())
+	}
 }
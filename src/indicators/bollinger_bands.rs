@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{StDev, SMA};
+use std::ops::Bound;
 
 /// Bollinger Bands
 ///
@@ -47,10 +51,23 @@ impl IndicatorConfig for BollingerBands {
 
 	const NAME: &'static str = "BollingerBands";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("upper"),
+			OutputDescriptor::price("middle"),
+			OutputDescriptor::price("lower"),
+		],
+		signals: &[OutputDescriptor::signal("position")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("avg_size", "20", 3),
+		ParamDescriptor::value("sigma", "2", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = T::source(candle, cfg.source);
@@ -62,7 +79,20 @@ impl IndicatorConfig for BollingerBands {
 	}
 
 	fn validate(&self) -> bool {
-		self.sigma > 0.0 && self.avg_size > 2 && self.avg_size < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.sigma > 0.0, "sigma", self.sigma, "must be > 0.0")?;
+		Error::ensure(self.avg_size > 2, "avg_size", self.avg_size, "must be > 2")?;
+		Error::ensure(
+			self.avg_size < PeriodType::MAX,
+			"avg_size",
+			self.avg_size,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -88,6 +118,16 @@ impl IndicatorConfig for BollingerBands {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"avg_size" => Ok(self.avg_size.to_string()),
+			"sigma" => Ok(self.sigma.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -140,4 +180,8 @@ impl IndicatorInstance for BollingerBandsInstance {
 		let signals = [Action::from(relative.mul_add(2.0, -1.0))];
 		IndicatorResult::new(&values, &signals)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ma.warmup_len().max(self.st_dev.warmup_len())
+	}
 }
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Cross, ADI};
 
 /// Chaikin Money Flow
@@ -36,10 +39,15 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 
 	const NAME: &'static str = "ChaikinMoneyFlow";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::oscillator("main", -1.0, 1.0)],
+		signals: &[OutputDescriptor::signal("zero_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[ParamDescriptor::period("size", "20", 2)];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -52,7 +60,19 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 	}
 
 	fn validate(&self) -> bool {
-		self.size > 1 && self.size < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.size > 1, "size", self.size, "must be > 1")?;
+		Error::ensure(
+			self.size < PeriodType::MAX,
+			"size",
+			self.size,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -69,6 +89,14 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"size" => Ok(self.size.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -106,4 +134,8 @@ impl IndicatorInstance for ChaikinMoneyFlowInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.adi.warmup_len()
+	}
 }
@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, ADI};
 use crate::prelude::Peekable;
@@ -43,6 +46,12 @@ pub struct ChaikinOscillator<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\]
 	pub window: PeriodType, // from 0 to ...
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for ChaikinOscillator<M> {
@@ -50,17 +59,27 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChaikinOscillator<M> {
 
 	const NAME: &'static str = "ChaikinOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::volume("main")],
+		signals: &[OutputDescriptor::signal("zero_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma1", "ema-3", 1),
+		ParamDescriptor::moving_average("ma2", "ema-10", 2),
+		ParamDescriptor::period("window", "0", 0),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let adi = ADI::new(cfg.window, candle)?;
 
 		Ok(Self::Instance {
-			ma1: cfg.ma1.init(adi.peek())?,
-			ma2: cfg.ma2.init(adi.peek())?,
+			ma1: cfg.ma1.init_seeded(adi.peek(), cfg.seed)?,
+			ma2: cfg.ma2.init_seeded(adi.peek(), cfg.seed)?,
 			adi,
 			cross_over: Cross::default(),
 			cfg,
@@ -68,10 +87,36 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChaikinOscillator<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.is_similar_to(&self.ma2)
-			&& self.ma1.ma_period() > 0
-			&& self.ma1.ma_period() < self.ma2.ma_period()
-			&& self.ma2.ma_period() < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma2),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma2 {}", self.ma2),
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() > 0,
+			"ma1 period",
+			self.ma1.ma_period(),
+			"must be > 0",
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() < self.ma2.ma_period(),
+			"ma1 period",
+			self.ma1.ma_period(),
+			format_args!("must be < ma2 period {}", self.ma2.ma_period()),
+		)?;
+		Error::ensure(
+			self.ma2.ma_period() < PeriodType::MAX,
+			"ma2 period",
+			self.ma2.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -84,6 +129,14 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChaikinOscillator<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.ma2 = value,
 			},
+			"window" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.window = value,
+			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -93,6 +146,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChaikinOscillator<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma1" => Ok(self.ma1.to_string()),
+			"ma2" => Ok(self.ma2.to_string()),
+			"window" => Ok(self.window.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -104,6 +168,7 @@ impl Default for ChaikinOscillator<MA> {
 			ma1: MA::EMA(3),
 			ma2: MA::EMA(10),
 			window: 0,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -140,4 +205,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for ChaikinOscillatorInstanc
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.adi.warmup_len() + self.ma1.warmup_len().max(self.ma2.warmup_len())
+	}
 }
@@ -3,11 +3,15 @@ use serde::{Deserialize, Serialize};
 // use std::str::FromStr;
 
 use crate::core::{
-	Action, Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV,
+	Action, Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, ValueType, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
 };
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{signi, MA};
 use crate::methods::{CrossAbove, Highest, Lowest};
+use std::ops::Bound;
 
 /// Chande Kroll Stop
 ///
@@ -57,6 +61,12 @@ pub struct ChandeKrollStop<M: MovingAverageConstructor = MA> {
 	pub q: PeriodType,
 	/// Price source. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
@@ -64,16 +74,34 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 
 	const NAME: &'static str = "ChandeKrollStop";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("stop_long"),
+			OutputDescriptor::price("source"),
+			OutputDescriptor::price("stop_short"),
+		],
+		signals: &[
+			OutputDescriptor::signal("position"),
+			OutputDescriptor::signal("stops_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "sma-10", 1),
+		ParamDescriptor::value("x", "1", Bound::Included(0.0), Bound::Unbounded),
+		ParamDescriptor::period("q", "9", 1),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let tr = candle.high() - candle.low();
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma: cfg.ma.init(candle.tr(candle))?,
+			ma: cfg.ma.init_seeded(candle.tr(candle), cfg.seed)?,
 
 			highest1: Highest::new(cfg.ma.ma_period(), &candle.high())?,
 			lowest1: Lowest::new(cfg.ma.ma_period(), &candle.low())?,
@@ -96,7 +124,20 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.x >= 0.0 && self.ma.ma_period() > 0 && self.q > 0
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.x >= 0.0, "x", self.x, "must be >= 0.0")?;
+		Error::ensure(
+			self.ma.ma_period() > 0,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 0",
+		)?;
+		Error::ensure(self.q > 0, "q", self.q, "must be > 0")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -117,6 +158,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -126,6 +171,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"x" => Ok(self.x.to_string()),
+			"q" => Ok(self.q.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
@@ -138,6 +195,7 @@ impl Default for ChandeKrollStop<MA> {
 			x: 1.0,
 			q: 9,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -200,4 +258,9 @@ impl<M: MovingAverageConstructor> IndicatorInstance for ChandeKrollStopInstance<
 			&[Action::from(value), Action::from(s2)],
 		)
 	}
+
+	fn warmup_len(&self) -> usize {
+		// true range needs previous candle
+		(1 + self.ma.warmup_len()).max(self.highest1.warmup_len()) + self.highest2.warmup_len()
+	}
 }
@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Change, CrossAbove, CrossUnder};
+use std::ops::Bound;
 
 /// Chande Momentum Oscillator
 ///
@@ -42,10 +46,19 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 
 	const NAME: &'static str = "ChandeMomentumOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::oscillator("main", -1.0, 1.0)],
+		signals: &[OutputDescriptor::signal("zone")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "9", 2),
+		ParamDescriptor::value("zone", "0.5", Bound::Included(0.0), Bound::Included(1.0)),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 
@@ -61,7 +74,19 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0. && self.zone <= 1.0 && self.period > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			(0.0..=1.0).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 1]",
+		)?;
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -87,6 +112,16 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -153,4 +188,8 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.change.warmup_len() + self.window.len() as usize - 1
+	}
 }
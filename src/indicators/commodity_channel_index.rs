@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::CCI;
+use std::ops::Bound;
 
 const SCALE: ValueType = 1.0 / 1.5;
 /// Commodity Channel Index
@@ -45,10 +49,19 @@ impl IndicatorConfig for CommodityChannelIndex {
 
 	const NAME: &'static str = "CommodityChannelIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::unbounded("main")],
+		signals: &[OutputDescriptor::signal("zone")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "18", 2),
+		ParamDescriptor::value("zone", "1", Bound::Included(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let value = candle.source(cfg.source);
@@ -63,7 +76,20 @@ impl IndicatorConfig for CommodityChannelIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0.0 && self.period > 1 && self.period < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.zone >= 0.0, "zone", self.zone, "must be >= 0.0")?;
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+		Error::ensure(
+			self.period < PeriodType::MAX,
+			"period",
+			self.period,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -89,6 +115,16 @@ impl IndicatorConfig for CommodityChannelIndex {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -151,4 +187,8 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 
 		IndicatorResult::new(&[cci], &[Action::from(signal)])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.cci.warmup_len()
+	}
 }
@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, RateOfChange, ReversalSignal};
 
@@ -66,6 +69,12 @@ pub struct CoppockCurve<M: MovingAverageConstructor = MA> {
 
 	/// Source type. Default is [`Close`](crate::core::Source::Close).
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
@@ -73,18 +82,39 @@ impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 
 	const NAME: &'static str = "CoppockCurve";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("signal"),
+		],
+		signals: &[
+			OutputDescriptor::signal("zero_cross"),
+			OutputDescriptor::signal("reverse"),
+			OutputDescriptor::signal("signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma1", "wma-10", 2),
+		ParamDescriptor::moving_average("s3_ma", "ema-5", 2),
+		ParamDescriptor::period("period2", "14", 2),
+		ParamDescriptor::period("period3", "11", 1),
+		ParamDescriptor::period("s2_left", "4", 1),
+		ParamDescriptor::period("s2_right", "2", 1),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = &candle.source(cfg.source);
 		Ok(Self::Instance {
 			roc1: RateOfChange::new(cfg.period2, src)?,
 			roc2: RateOfChange::new(cfg.period3, src)?,
-			ma1: cfg.ma1.init(0.)?,   // method(cfg.method1, cfg.period1, 0.)?,
-			ma2: cfg.s3_ma.init(0.)?, //method(cfg.method2, cfg.s3_period, 0.)?,
+			ma1: cfg.ma1.init_seeded(0., cfg.seed)?, // method(cfg.method1, cfg.period1, 0.)?,
+			ma2: cfg.s3_ma.init_seeded(0., cfg.seed)?, //method(cfg.method2, cfg.s3_period, 0.)?,
 			cross_over1: Cross::default(),
 			pivot: ReversalSignal::new(cfg.s2_left, cfg.s2_right, &0.)?,
 			cross_over2: Cross::default(),
@@ -94,14 +124,45 @@ impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.ma_period() > 1
-			&& self.period2 > self.period3
-			&& self.period2 < PeriodType::MAX
-			&& self.period3 > 0
-			&& self.s3_ma.ma_period() > 1
-			&& self.s2_left > 0
-			&& self.s2_right > 0
-			&& self.s2_left.saturating_add(self.s2_right) < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.ma_period() > 1,
+			"ma1 period",
+			self.ma1.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.period2 > self.period3,
+			"period2",
+			self.period2,
+			format_args!("must be > period3 {}", self.period3),
+		)?;
+		Error::ensure(
+			self.period2 < PeriodType::MAX,
+			"period2",
+			self.period2,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(self.period3 > 0, "period3", self.period3, "must be > 0")?;
+		Error::ensure(
+			self.s3_ma.ma_period() > 1,
+			"s3_ma period",
+			self.s3_ma.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(self.s2_left > 0, "s2_left", self.s2_left, "must be > 0")?;
+		Error::ensure(self.s2_right > 0, "s2_right", self.s2_right, "must be > 0")?;
+		Error::ensure(
+			self.s2_left.saturating_add(self.s2_right) < PeriodType::MAX,
+			"s2_left",
+			self.s2_left,
+			format_args!("+ s2_right {} must be < {}", self.s2_right, PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -136,6 +197,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 			},
 			// "zone"		=> self.zone = value.parse().unwrap(),
 			// "source"	=> self.source = value.parse().unwrap(),
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
 			}
@@ -144,6 +209,21 @@ impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma1" => Ok(self.ma1.to_string()),
+			"s3_ma" => Ok(self.s3_ma.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"period3" => Ok(self.period3.to_string()),
+			"s2_left" => Ok(self.s2_left.to_string()),
+			"s2_right" => Ok(self.s2_right.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -159,6 +239,7 @@ impl Default for CoppockCurve<MA> {
 			s2_left: 4,
 			s2_right: 2,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -197,4 +278,10 @@ impl<M: MovingAverageConstructor> IndicatorInstance for CoppockCurveInstance<M>
 
 		IndicatorResult::new(&[value1, value2], &[signal1, signal2, signal3])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.roc1.warmup_len().max(self.roc2.warmup_len())
+			+ self.ma1.warmup_len()
+			+ self.ma2.warmup_len()
+	}
 }
@@ -2,9 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{
-	Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, Window, OHLCV,
+	Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, ValueType, Window, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
 };
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
 
 // The Formula for the Detrended Price Oscillator (DPO) is
@@ -38,6 +41,12 @@ pub struct DetrendedPriceOscillator<M: MovingAverageConstructor = MA> {
 	pub ma: M,
 	/// Source type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M> {
@@ -45,23 +54,49 @@ impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M
 
 	const NAME: &'static str = "DetrendedPriceOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::unbounded("main")],
+		signals: &[],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "sma-21", 2),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			sma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
+			sma: cfg.ma.init_seeded(src, cfg.seed)?, // method(cfg.method, cfg.period, src)?,
 			window: Window::new(cfg.ma.ma_period() / 2 + 1, src),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 1 && self.ma.ma_period() < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma.ma_period() > 1,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.ma.ma_period() < PeriodType::MAX,
+			"ma period",
+			self.ma.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -74,6 +109,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -83,6 +122,16 @@ impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 0)
 	}
@@ -93,6 +142,7 @@ impl Default for DetrendedPriceOscillator<MA> {
 		Self {
 			ma: MA::SMA(21),
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -123,4 +173,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for DetrendedPriceOscillator
 
 		IndicatorResult::new(&[dpo], &[])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.sma.warmup_len().max(self.window.len() as usize)
+	}
 }
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Highest, Lowest};
 
 /// Donchian Channel
@@ -50,10 +53,19 @@ impl IndicatorConfig for DonchianChannel {
 
 	const NAME: &'static str = "DonchianChannel";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("lower"),
+			OutputDescriptor::price("middle"),
+			OutputDescriptor::price("upper"),
+		],
+		signals: &[OutputDescriptor::signal("breakout")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[ParamDescriptor::period("period", "20", 2)];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 
@@ -65,7 +77,13 @@ impl IndicatorConfig for DonchianChannel {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -82,6 +100,14 @@ impl IndicatorConfig for DonchianChannel {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -122,4 +148,8 @@ impl IndicatorInstance for DonchianChannelInstance {
 
 		IndicatorResult::new(&[lowest, middle, highest], &[signal1.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.highest.warmup_len().max(self.lowest.warmup_len())
+	}
 }
@@ -2,8 +2,11 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, Window, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::Cross;
 
@@ -38,6 +41,12 @@ pub struct EaseOfMovement<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\].
 	pub period2: PeriodType,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
@@ -45,14 +54,23 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
 
 	const NAME: &'static str = "EaseOfMovement";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::unbounded("main")],
+		signals: &[OutputDescriptor::signal("zero_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "sma-13", 2),
+		ParamDescriptor::period("period2", "1", 1),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
-			m1: cfg.ma.init(0.)?, //method(cfg.method, cfg.period1, 0.)?,
+			m1: cfg.ma.init_seeded(0., cfg.seed)?, //method(cfg.method, cfg.period1, 0.)?,
 			w: Window::new(cfg.period2, HLC::from(candle)),
 			cross: Cross::new((), &(0.0, 0.0))?,
 
@@ -61,7 +79,25 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 1 && self.ma.ma_period() < PeriodType::MAX && self.period2 >= 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma.ma_period() > 1,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.ma.ma_period() < PeriodType::MAX,
+			"ma period",
+			self.ma.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(self.period2 >= 1, "period2", self.period2, "must be >= 1")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -74,6 +110,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.period2 = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -83,6 +123,16 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -93,6 +143,7 @@ impl Default for EaseOfMovement<MA> {
 		Self {
 			ma: MA::SMA(13),
 			period2: 1,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -143,4 +194,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EaseOfMovementInstance<M
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.w.len() as usize + self.m1.warmup_len()
+	}
 }
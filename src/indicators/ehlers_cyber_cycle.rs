@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{Cross, CyberCycle};
+
+/// Ehlers' Cyber Cycle
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/TheInverseFisherTransform.pdf>
+///
+/// # 2 values
+///
+/// * `Cycle` value
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// * `Trigger` value, which is the previous `Cycle` value
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When `Cycle` crosses `Trigger` upwards, returns full buy signal.
+///   When `Cycle` crosses `Trigger` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::EhlersCyberCycle;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = EhlersCyberCycle::default().over(&candles).unwrap();
+///
+/// assert!(results.windows(2).all(|x| x[0].value(0) == x[1].value(1)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersCyberCycle {
+	/// Smoothing length. Default is `28`, which is about Ehlers' `alpha` = `0.07`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Source type of values. Default is [`HL2`](crate::core::Source::HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for EhlersCyberCycle {
+	type Instance = EhlersCyberCycleInstance;
+
+	const NAME: &'static str = "EhlersCyberCycle";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("trigger"),
+		],
+		signals: &[OutputDescriptor::signal("cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("length", "28", 1),
+		ParamDescriptor::source("source", "hl2"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			cyber_cycle: CyberCycle::new(cfg.length, &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.length > 0, "length", self.length, "must be > 0")?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"length" => Ok(self.length.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for EhlersCyberCycle {
+	fn default() -> Self {
+		Self {
+			length: 28,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersCyberCycleInstance {
+	cfg: EhlersCyberCycle,
+
+	cyber_cycle: CyberCycle,
+	cross: Cross,
+}
+
+impl IndicatorInstance for EhlersCyberCycleInstance {
+	type Config = EhlersCyberCycle;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let cycle = self.cyber_cycle.next(&candle.source(self.cfg.source));
+		let trigger = self.cyber_cycle.trigger();
+		let signal = self.cross.next(&(cycle, trigger));
+
+		IndicatorResult::new(&[cycle, trigger], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.cyber_cycle.warmup_len() + 1
+	}
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{Cross, Decycler};
+
+/// Ehlers' Decycler
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/Decyclers.pdf>
+///
+/// # 1 value
+///
+/// * `Decycler` value, which is the trend without the cycles shorter than `period` bars
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When `source` value crosses `Decycler` upwards, returns full buy signal.
+///   When `source` value crosses `Decycler` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::EhlersDecycler;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = EhlersDecycler::default().over(&candles).unwrap();
+///
+/// assert_eq!(results.len(), 100);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersDecycler {
+	/// Cutoff period of the removed cycles. Default is `125`.
+	///
+	/// Range in \[`5`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for EhlersDecycler {
+	type Instance = EhlersDecyclerInstance;
+
+	const NAME: &'static str = "EhlersDecycler";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::price("main")],
+		signals: &[OutputDescriptor::signal("cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "125", 5),
+		ParamDescriptor::source("source", "close"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			decycler: Decycler::new(cfg.period, &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 4, "period", self.period, "must be > 4")?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for EhlersDecycler {
+	fn default() -> Self {
+		Self {
+			period: 125,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersDecyclerInstance {
+	cfg: EhlersDecycler,
+
+	decycler: Decycler,
+	cross: Cross,
+}
+
+impl IndicatorInstance for EhlersDecyclerInstance {
+	type Config = EhlersDecycler;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let value = self.decycler.next(&source);
+		let signal = self.cross.next(&(source, value));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.decycler.warmup_len()
+	}
+}
@@ -0,0 +1,167 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{Cross, InstantaneousTrendline};
+
+/// Ehlers' Instantaneous Trendline
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/TheInverseFisherTransform.pdf>
+///
+/// # 2 values
+///
+/// * `Trendline` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `Trigger` value, which is the `Trendline` extrapolated by 2 bars
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When `Trigger` crosses `Trendline` upwards, returns full buy signal.
+///   When `Trigger` crosses `Trendline` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::EhlersInstantaneousTrendline;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = EhlersInstantaneousTrendline::default().over(&candles).unwrap();
+///
+/// assert_eq!(results.len(), 100);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersInstantaneousTrendline {
+	/// Smoothing length. Default is `28`, which is about Ehlers' `alpha` = `0.07`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub length: PeriodType,
+
+	/// Source type of values. Default is [`HL2`](crate::core::Source::HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for EhlersInstantaneousTrendline {
+	type Instance = EhlersInstantaneousTrendlineInstance;
+
+	const NAME: &'static str = "EhlersInstantaneousTrendline";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("main"),
+			OutputDescriptor::price("trigger"),
+		],
+		signals: &[OutputDescriptor::signal("cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("length", "28", 1),
+		ParamDescriptor::source("source", "hl2"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			trendline: InstantaneousTrendline::new(cfg.length, &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.length > 0, "length", self.length, "must be > 0")?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"length" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.length = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"length" => Ok(self.length.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for EhlersInstantaneousTrendline {
+	fn default() -> Self {
+		Self {
+			length: 28,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersInstantaneousTrendlineInstance {
+	cfg: EhlersInstantaneousTrendline,
+
+	trendline: InstantaneousTrendline,
+	cross: Cross,
+}
+
+impl IndicatorInstance for EhlersInstantaneousTrendlineInstance {
+	type Config = EhlersInstantaneousTrendline;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let trendline = self.trendline.next(&candle.source(self.cfg.source));
+		let trigger = self.trendline.trigger();
+		let signal = self.cross.next(&(trigger, trendline));
+
+		IndicatorResult::new(&[trendline, trigger], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.trendline.warmup_len()
+	}
+}
@@ -0,0 +1,182 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{Cross, RoofingFilter};
+
+/// Ehlers' Roofing Filter
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/PredictiveIndicators.pdf>
+///
+/// # 1 value
+///
+/// * `Roofing Filter` value, which contains only the cycles from `low_period` to `high_period` bars
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When `Roofing Filter` value crosses zero line upwards, returns full buy signal.
+///   When `Roofing Filter` value crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::EhlersRoofingFilter;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = EhlersRoofingFilter::default().over(&candles).unwrap();
+///
+/// assert_eq!(results.len(), 100);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersRoofingFilter {
+	/// Longest period of the kept cycles. Default is `48`.
+	///
+	/// Range in \(`low_period`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub high_period: PeriodType,
+
+	/// Shortest period of the kept cycles. Default is `10`.
+	///
+	/// Range in \[`2`; `high_period`\)
+	pub low_period: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for EhlersRoofingFilter {
+	type Instance = EhlersRoofingFilterInstance;
+
+	const NAME: &'static str = "EhlersRoofingFilter";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::unbounded("main")],
+		signals: &[OutputDescriptor::signal("zero_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("high_period", "48", 3),
+		ParamDescriptor::period("low_period", "10", 2),
+		ParamDescriptor::source("source", "close"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			roofing: RoofingFilter::new((cfg.high_period, cfg.low_period), &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.low_period > 1,
+			"low_period",
+			self.low_period,
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.high_period > self.low_period,
+			"high_period",
+			self.high_period,
+			format_args!("must be > low_period {}", self.low_period),
+		)?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"high_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.high_period = value,
+			},
+			"low_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.low_period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"high_period" => Ok(self.high_period.to_string()),
+			"low_period" => Ok(self.low_period.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for EhlersRoofingFilter {
+	fn default() -> Self {
+		Self {
+			high_period: 48,
+			low_period: 10,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EhlersRoofingFilterInstance {
+	cfg: EhlersRoofingFilter,
+
+	roofing: RoofingFilter,
+	cross: Cross,
+}
+
+impl IndicatorInstance for EhlersRoofingFilterInstance {
+	type Config = EhlersRoofingFilter;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self.roofing.next(&candle.source(self.cfg.source));
+		let signal = self.cross.next(&(value, 0.0));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.roofing.warmup_len()
+	}
+}
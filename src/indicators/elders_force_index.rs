@@ -1,9 +1,12 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Candle, MovingAverageConstructor};
+use crate::core::{Candle, MovingAverageConstructor, Seed};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::Cross;
 
@@ -40,6 +43,12 @@ pub struct EldersForceIndex<M: MovingAverageConstructor = MA> {
 	pub period2: PeriodType,
 	/// Price source type of values. Default is [`Close`](crate::core::Source::Close).
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
@@ -47,14 +56,24 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
 
 	const NAME: &'static str = "EldersForceIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::volume("main")],
+		signals: &[OutputDescriptor::signal("zero_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "ema-13", 2),
+		ParamDescriptor::period("period2", "1", 1),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma: cfg.ma.init(0.)?, // method(cfg.method, cfg.period1, 0.)?,
+			ma: cfg.ma.init_seeded(0., cfg.seed)?, // method(cfg.method, cfg.period1, 0.)?,
 			window: Window::new(cfg.period2, Candle::from(candle)),
 			vol_sum: candle.volume() * cfg.period2 as ValueType,
 			cross_over: Cross::default(),
@@ -63,7 +82,19 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 1 && self.period2 >= 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma.ma_period() > 1,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(self.period2 >= 1, "period2", self.period2, "must be >= 1")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -80,6 +111,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -89,6 +124,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -100,6 +146,7 @@ impl Default for EldersForceIndex<MA> {
 			ma: MA::EMA(13),
 			period2: 1,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -135,4 +182,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EldersForceIndexInstance
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize + self.ma.warmup_len()
+	}
 }
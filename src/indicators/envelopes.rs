@@ -1,9 +1,15 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, Seed, Source, ValueType, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
+use std::ops::Bound;
 
 /// Envelopes
 ///
@@ -45,6 +51,12 @@ pub struct Envelopes<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 	/// Source2 value type for actual price. Default is [`Close`](crate::core::Source::Close).
 	pub source2: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
@@ -52,16 +64,31 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 
 	const NAME: &'static str = "Envelopes";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("upper"),
+			OutputDescriptor::price("lower"),
+			OutputDescriptor::price("source"),
+		],
+		signals: &[OutputDescriptor::signal("bounds_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "sma-20", 2),
+		ParamDescriptor::value("k", "0.1", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::source("source2", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
+			ma: cfg.ma.init_seeded(src, cfg.seed)?, // method(cfg.method, cfg.period, src)?,
 			k_high: 1.0 + cfg.k,
 			k_low: 1.0 - cfg.k,
 			cfg,
@@ -69,7 +96,19 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.k > 0.0 && self.ma.ma_period() > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.k > 0.0, "k", self.k, "must be > 0.0")?;
+		Error::ensure(
+			self.ma.ma_period() > 1,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 1",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -90,6 +129,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source2 = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -99,6 +142,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"k" => Ok(self.k.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"source2" => Ok(self.source2.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -111,6 +166,7 @@ impl Default for Envelopes<MA> {
 			k: 0.1,
 			source: Source::Close,
 			source2: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -151,4 +207,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
 
 		IndicatorResult::new(&[value1, value2, src2], &[Action::from(signal)])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ma.warmup_len()
+	}
 }
@@ -5,7 +5,10 @@
 //! The idea is to find signals where price of timeseries crosses this config's `price` for the last `period` frames.
 
 // Some core structures and traits
-use crate::core::{Action, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{
+	Action, Error, IndicatorOutputs, IndicatorResult, OutputDescriptor, ParamDescriptor,
+	PeriodType, Source, ValueType, OHLCV,
+};
 use crate::prelude::*;
 
 // Cross method for searching crossover between price and our value
@@ -15,6 +18,7 @@ use crate::methods::Cross;
 // If you don't, you can just skip these lines
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::ops::Bound;
 
 /// # Example config for the indicator **Configuration**
 ///
@@ -37,10 +41,23 @@ impl IndicatorConfig for Example {
 
 	const NAME: &'static str = "Example";
 
+	/// Names, kinds and ranges of our raw value and two signals
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::price("close")],
+		signals: &[
+			OutputDescriptor::signal("cross"),
+			OutputDescriptor::signal("some_other"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::value("price", "2", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::period("period", "3", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -53,7 +70,13 @@ impl IndicatorConfig for Example {
 
 	/// Validates config values to be consistent
 	fn validate(&self) -> bool {
-		self.price > 0.0
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.price > 0.0, "price", self.price, "must be > 0.0")?;
+
+		Ok(())
 	}
 
 	/// Sets attributes of config by given name and value by `String`
@@ -63,6 +86,14 @@ impl IndicatorConfig for Example {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.price = value,
 			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -72,6 +103,16 @@ impl IndicatorConfig for Example {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"price" => Ok(self.price.to_string()),
+			"period" => Ok(self.period.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	/// Our indicator will return single raw value and two signals
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
@@ -1,10 +1,16 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, ValueType, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, Highest, Lowest};
+use std::ops::Bound;
 
 // FT = 1/2 * ln((1+x)/(1-x)) = arctanh(x)
 // x - transformation of price to a level between -1 and 1 for N periods
@@ -50,6 +56,12 @@ pub struct FisherTransform<M: MovingAverageConstructor = MA> {
 	pub signal: M,
 	/// Source type of values. Default is [`TP`](crate::core::Source::TP)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
@@ -57,16 +69,33 @@ impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
 
 	const NAME: &'static str = "FisherTransform";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("signal"),
+		],
+		signals: &[
+			OutputDescriptor::signal("zone"),
+			OutputDescriptor::signal("signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "9", 2),
+		ParamDescriptor::value("zone", "1.5", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::moving_average("signal", "sma-2", 2),
+		ParamDescriptor::source("source", "tp"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = &candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			ma1: cfg.signal.init(0.)?, // method(cfg.method, cfg.period2, 0.)?,
+			ma1: cfg.signal.init_seeded(0., cfg.seed)?, // method(cfg.method, cfg.period2, 0.)?,
 			highest: Highest::new(cfg.period1, src)?,
 			lowest: Lowest::new(cfg.period1, src)?,
 			cross: Cross::default(),
@@ -78,7 +107,20 @@ impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.signal.ma_period() > 1 && self.zone > 0.
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period1 > 1, "period1", self.period1, "must be > 1")?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(self.zone > 0.0, "zone", self.zone, "must be > 0.0")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -99,6 +141,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -108,6 +154,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
@@ -120,6 +178,7 @@ impl Default for FisherTransform<MA> {
 			signal: MA::SMA(2),
 			zone: 1.5,
 			source: Source::TP,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -199,10 +258,15 @@ impl<M: MovingAverageConstructor> IndicatorInstance for FisherTransformInstance<
 
 		let s2 = signal_line / self.cfg.zone
 			* ((signal_line < 0.0 && self.last_reverse > 0 && crossed_ma > 0)
-				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8 as ValueType;
+				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8
+				as ValueType;
 
 		self.prev_value = cumulative;
 
 		IndicatorResult::new(&[cumulative, signal_line], &[s1.into(), s2.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.highest.warmup_len().max(self.lowest.warmup_len()) + self.ma1.warmup_len()
+	}
 }
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{ReversalSignal, HMA};
 
 /// Hull Moving Average indicator
@@ -49,10 +52,20 @@ impl IndicatorConfig for HullMovingAverage {
 
 	const NAME: &'static str = "HullMovingAverage";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::price("main")],
+		signals: &[OutputDescriptor::signal("reverse")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "9", 3),
+		ParamDescriptor::period("left", "3", 1),
+		ParamDescriptor::period("right", "2", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
@@ -65,10 +78,21 @@ impl IndicatorConfig for HullMovingAverage {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 2
-			&& self.left >= 1
-			&& self.right >= 1
-			&& self.left.saturating_add(self.right) < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 2, "period", self.period, "must be > 2")?;
+		Error::ensure(self.left >= 1, "left", self.left, "must be >= 1")?;
+		Error::ensure(self.right >= 1, "right", self.right, "must be >= 1")?;
+		Error::ensure(
+			self.left.saturating_add(self.right) < PeriodType::MAX,
+			"left",
+			self.left,
+			format_args!("+ right {} must be < {}", self.right, PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -98,6 +122,17 @@ impl IndicatorConfig for HullMovingAverage {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"left" => Ok(self.left.to_string()),
+			"right" => Ok(self.right.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -136,4 +171,8 @@ impl IndicatorInstance for HullMovingAverageInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.hma.warmup_len()
+	}
 }
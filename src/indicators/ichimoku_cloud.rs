@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Cross, Highest, Lowest};
 
 /// Ichimoku cloud
@@ -11,15 +14,18 @@ use crate::methods::{Cross, Highest, Lowest};
 ///
 /// * <https://en.wikipedia.org/wiki/Ichimoku_Kink%C5%8D_Hy%C5%8D>
 ///
-/// # 4 values
+/// # 5 values
 ///
 /// * `Tenkan Sen`
 /// * `Kijun Sen`
 /// * `Senkou Span A`
 /// * `Senkou Span B`
+/// * `Chikou Span`
 ///
 /// Range of all the values is the same as the range of the `source` values.
 ///
+/// `Chikou Span` is the current `source` value, which is meant to be plotted `m` periods back.
+///
 /// # 2 signals
 ///
 /// * When `Tenkan Sen` crosses `Kijun Sen` upwards and `source` value is greater than both `Senkou Span A and B` and when `Senkou Span A` is greater than `Senkou Span B`,
@@ -63,10 +69,30 @@ impl IndicatorConfig for IchimokuCloud {
 
 	const NAME: &'static str = "IchimokuCloud";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("tenkan_sen"),
+			OutputDescriptor::price("kijun_sen"),
+			OutputDescriptor::price("senkou_span_a"),
+			OutputDescriptor::price("senkou_span_b"),
+			OutputDescriptor::price("chikou_span"),
+		],
+		signals: &[
+			OutputDescriptor::signal("tenkan_kijun_cross"),
+			OutputDescriptor::signal("source_kijun_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("l1", "9", 1),
+		ParamDescriptor::period("l2", "26", 2),
+		ParamDescriptor::period("l3", "52", 3),
+		ParamDescriptor::period("m", "26", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -85,7 +111,24 @@ impl IndicatorConfig for IchimokuCloud {
 	}
 
 	fn validate(&self) -> bool {
-		self.l1 < self.l2 && self.l2 < self.l3
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.l1 < self.l2,
+			"l1",
+			self.l1,
+			format_args!("must be < l2 {}", self.l2),
+		)?;
+		Error::ensure(
+			self.l2 < self.l3,
+			"l2",
+			self.l2,
+			format_args!("must be < l3 {}", self.l3),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -119,8 +162,20 @@ impl IndicatorConfig for IchimokuCloud {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"l1" => Ok(self.l1.to_string()),
+			"l2" => Ok(self.l2.to_string()),
+			"l3" => Ok(self.l3.to_string()),
+			"m" => Ok(self.m.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
-		(4, 2)
+		(5, 2)
 	}
 }
 
@@ -205,8 +260,18 @@ impl IndicatorInstance for IchimokuCloudInstance {
 				as i8;
 
 		IndicatorResult::new(
-			&[tenkan_sen, kijun_sen, senkou_span_a, senkou_span_b],
+			&[tenkan_sen, kijun_sen, senkou_span_a, senkou_span_b, src],
 			&[Action::from(s1), Action::from(s2)],
 		)
 	}
+
+	fn warmup_len(&self) -> usize {
+		let highest = self
+			.highest1
+			.warmup_len()
+			.max(self.highest2.warmup_len())
+			.max(self.highest3.warmup_len());
+
+		self.window1.len() as usize + highest
+	}
 }
@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Change, Cross, LinearVolatility, StDev};
+use std::ops::Bound;
 
 /// Kaufman Adaptive Moving Average (KAMA)
 /// # Links
@@ -68,10 +72,23 @@ impl IndicatorConfig for Kaufman {
 
 	const NAME: &'static str = "Kaufman";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::price("main")],
+		signals: &[OutputDescriptor::signal("source_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "10", 1),
+		ParamDescriptor::period("period2", "2", 1),
+		ParamDescriptor::period("period3", "30", 2),
+		ParamDescriptor::period("filter_period", "10", 0),
+		ParamDescriptor::flag("square_smooth", "true"),
+		ParamDescriptor::value("k", "0.3", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = &candle.source(cfg.source);
@@ -91,10 +108,26 @@ impl IndicatorConfig for Kaufman {
 	}
 
 	fn validate(&self) -> bool {
-		self.period3 > self.period2
-			&& self.period2 > 0
-			&& self.period1 > 0
-			&& (self.k > 0.0 || self.filter_period < 2)
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.period3 > self.period2,
+			"period3",
+			self.period3,
+			format_args!("must be > period2 {}", self.period2),
+		)?;
+		Error::ensure(self.period2 > 0, "period2", self.period2, "must be > 0")?;
+		Error::ensure(self.period1 > 0, "period1", self.period1, "must be > 0")?;
+		Error::ensure(
+			self.k > 0.0 || self.filter_period < 2,
+			"k",
+			self.k,
+			format_args!("must be > 0 when filter_period {} >= 2", self.filter_period),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -136,6 +169,20 @@ impl IndicatorConfig for Kaufman {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"period3" => Ok(self.period3.to_string()),
+			"filter_period" => Ok(self.filter_period.to_string()),
+			"square_smooth" => Ok(self.square_smooth.to_string()),
+			"k" => Ok(self.k.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 1)
 	}
@@ -221,4 +268,8 @@ impl IndicatorInstance for KaufmanInstance {
 
 		IndicatorResult::new(&[value], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.change.warmup_len().max(self.volatility.warmup_len())
+	}
 }
@@ -1,10 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, Seed, Source, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{CrossAbove, CrossUnder, SMA};
+use std::ops::Bound;
 
 /// Keltner Channel
 ///
@@ -14,11 +18,11 @@ use crate::methods::{CrossAbove, CrossUnder, SMA};
 ///
 /// # 3 values
 ///
+/// * `source` value
 /// * `upper bound`
 ///
 /// Range of values is the same as the range of the `source` values.
 ///
-/// * `source` value
 /// * `lower bound`
 ///
 /// Range of values is the same as the range of the `source` values.
@@ -45,6 +49,12 @@ pub struct KeltnerChannel<M: MovingAverageConstructor = MA> {
 
 	/// Middle moving average source value type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
@@ -52,16 +62,30 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 
 	const NAME: &'static str = "KeltnerChannel";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("source"),
+			OutputDescriptor::price("upper"),
+			OutputDescriptor::price("lower"),
+		],
+		signals: &[OutputDescriptor::signal("breakout")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "ema-20", 2),
+		ParamDescriptor::value("sigma", "1", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 		Ok(Self::Instance {
 			prev_close: candle.close(),
-			ma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
+			ma: cfg.ma.init_seeded(src, cfg.seed)?, // method(cfg.method, cfg.period, src)?,
 			sma: SMA::new(cfg.ma.ma_period(), &(candle.high() - candle.low()))?,
 			cross_above: CrossAbove::default(),
 			cross_under: CrossUnder::default(),
@@ -70,7 +94,19 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 1 && self.sigma > 0.0
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma.ma_period() > 1,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(self.sigma > 0.0, "sigma", self.sigma, "must be > 0.0")?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -87,6 +123,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -96,6 +136,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"sigma" => Ok(self.sigma.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -107,6 +158,7 @@ impl Default for KeltnerChannel<MA> {
 			ma: MA::EMA(20),
 			sigma: 1.0,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -146,4 +198,9 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KeltnerChannelInstance<M
 
 		IndicatorResult::new(&[source, upper, lower], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		// true range needs previous candle
+		self.ma.warmup_len().max(1 + self.sma.warmup_len())
+	}
 }
@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, Seed, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::{sign, MA};
 use crate::methods::Cross;
 
@@ -53,6 +56,12 @@ pub struct KlingerVolumeOscillator<M: MovingAverageConstructor = MA> {
 	///
 	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub signal: M,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M> {
@@ -60,16 +69,32 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 
 	const NAME: &'static str = "KlingerVolumeOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::volume("main"),
+			OutputDescriptor::volume("signal"),
+		],
+		signals: &[
+			OutputDescriptor::signal("zero_cross"),
+			OutputDescriptor::signal("signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma1", "ema-34", 2),
+		ParamDescriptor::moving_average("ma2", "ema-55", 3),
+		ParamDescriptor::moving_average("signal", "ema-13", 2),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
-			ma1: cfg.ma1.init(0.)?,
-			ma2: cfg.ma2.init(0.)?,
-			ma3: cfg.signal.init(0.)?,
+			ma1: cfg.ma1.init_seeded(0., cfg.seed)?,
+			ma2: cfg.ma2.init_seeded(0., cfg.seed)?,
+			ma3: cfg.signal.init_seeded(0., cfg.seed)?,
 			cross1: Cross::default(),
 			cross2: Cross::default(),
 			last_tp: candle.tp(),
@@ -78,10 +103,36 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.is_similar_to(&self.ma2)
-			&& self.ma1.ma_period() > 1
-			&& self.signal.ma_period() > 1
-			&& self.ma1.ma_period() < self.ma2.ma_period()
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma2),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma2 {}", self.ma2),
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() > 1,
+			"ma1 period",
+			self.ma1.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() < self.ma2.ma_period(),
+			"ma1 period",
+			self.ma1.ma_period(),
+			format_args!("must be < ma2 period {}", self.ma2.ma_period()),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -98,6 +149,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.signal = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -107,6 +162,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma1" => Ok(self.ma1.to_string()),
+			"ma2" => Ok(self.ma2.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
@@ -118,6 +184,7 @@ impl Default for KlingerVolumeOscillator {
 			ma1: MA::EMA(34),
 			ma2: MA::EMA(55),
 			signal: MA::EMA(13),
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -169,4 +236,9 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorI
 
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
+
+	fn warmup_len(&self) -> usize {
+		// typical price change needs previous candle
+		1 + self.ma1.warmup_len().max(self.ma2.warmup_len()) + self.ma3.warmup_len()
+	}
 }
@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, RateOfChange};
 
@@ -53,6 +56,12 @@ pub struct KnowSureThing<M: MovingAverageConstructor = MA> {
 
 	/// Signal line moving average type. Default is [`SMA(9)`](crate::methods::SMA).
 	pub signal: M,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for KnowSureThing<M> {
@@ -60,10 +69,29 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KnowSureThing<M> {
 
 	const NAME: &'static str = "KnowSureThing";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("signal"),
+		],
+		signals: &[OutputDescriptor::signal("signal_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "10", 1),
+		ParamDescriptor::period("period2", "15", 2),
+		ParamDescriptor::period("period3", "20", 3),
+		ParamDescriptor::period("period4", "30", 4),
+		ParamDescriptor::moving_average("ma1", "sma-10", 1),
+		ParamDescriptor::moving_average("ma2", "sma-10", 1),
+		ParamDescriptor::moving_average("ma3", "sma-10", 1),
+		ParamDescriptor::moving_average("ma4", "sma-15", 1),
+		ParamDescriptor::moving_average("signal", "sma-9", 1),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let close = &candle.close();
@@ -73,23 +101,59 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KnowSureThing<M> {
 			roc2v: RateOfChange::new(cfg.period2, close)?,
 			roc3v: RateOfChange::new(cfg.period3, close)?,
 			roc4v: RateOfChange::new(cfg.period4, close)?,
-			ma1: cfg.ma1.init(0.)?,
-			ma2: cfg.ma2.init(0.)?,
-			ma3: cfg.ma3.init(0.)?,
-			ma4: cfg.ma4.init(0.)?,
-			ma5: cfg.signal.init(0.)?,
+			ma1: cfg.ma1.init_seeded(0., cfg.seed)?,
+			ma2: cfg.ma2.init_seeded(0., cfg.seed)?,
+			ma3: cfg.ma3.init_seeded(0., cfg.seed)?,
+			ma4: cfg.ma4.init_seeded(0., cfg.seed)?,
+			ma5: cfg.signal.init_seeded(0., cfg.seed)?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.is_similar_to(&self.ma2)
-			&& self.ma1.is_similar_to(&self.ma3)
-			&& self.ma1.is_similar_to(&self.ma4)
-			&& self.period1 < self.period2
-			&& self.period2 < self.period3
-			&& self.period3 < self.period4
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma2),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma2 {}", self.ma2),
+		)?;
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma3),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma3 {}", self.ma3),
+		)?;
+		Error::ensure(
+			self.ma1.is_similar_to(&self.ma4),
+			"ma1",
+			&self.ma1,
+			format_args!("must be of the same type as ma4 {}", self.ma4),
+		)?;
+		Error::ensure(
+			self.period1 < self.period2,
+			"period1",
+			self.period1,
+			format_args!("must be < period2 {}", self.period2),
+		)?;
+		Error::ensure(
+			self.period2 < self.period3,
+			"period2",
+			self.period2,
+			format_args!("must be < period3 {}", self.period3),
+		)?;
+		Error::ensure(
+			self.period3 < self.period4,
+			"period3",
+			self.period3,
+			format_args!("must be < period4 {}", self.period4),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -130,6 +194,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KnowSureThing<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.signal = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -139,6 +207,23 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KnowSureThing<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"period3" => Ok(self.period3.to_string()),
+			"period4" => Ok(self.period4.to_string()),
+			"ma1" => Ok(self.ma1.to_string()),
+			"ma2" => Ok(self.ma2.to_string()),
+			"ma3" => Ok(self.ma3.to_string()),
+			"ma4" => Ok(self.ma4.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -156,6 +241,7 @@ impl Default for KnowSureThing {
 			ma3: MA::SMA(10),
 			ma4: MA::SMA(15),
 			signal: MA::SMA(9),
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -204,4 +290,13 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KnowSureThingInstance<M>
 
 		IndicatorResult::new(&[kst, sl], &[signal])
 	}
+
+	fn warmup_len(&self) -> usize {
+		let kst = (self.roc1v.warmup_len() + self.ma1.warmup_len())
+			.max(self.roc2v.warmup_len() + self.ma2.warmup_len())
+			.max(self.roc3v.warmup_len() + self.ma3.warmup_len())
+			.max(self.roc4v.warmup_len() + self.ma4.warmup_len());
+
+		kst + self.ma5.warmup_len()
+	}
 }
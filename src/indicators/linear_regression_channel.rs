@@ -0,0 +1,196 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{CrossAbove, CrossUnder, LinRegStats};
+use std::ops::Bound;
+
+/// Linear Regression Channel
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502266-linear-regression-channel/>
+///
+/// # 3 values
+///
+/// * `upper bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `middle` value of the [linear regression](crate::methods::LinRegStats) line at the current bar
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower bound`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// Bounds are `sigma` standard errors of the estimate away from the `middle` value.
+///
+/// # 1 signal
+///
+/// When `source` value crosses the `upper bound` upwards, then returns full buy signal.
+/// When `source` value crosses the `lower bound` downwards, then returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::LinearRegressionChannel;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = LinearRegressionChannel::default().over(&candles).unwrap();
+///
+/// assert!(results.iter().all(|x| x.value(0) >= x.value(1) && x.value(1) >= x.value(2)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRegressionChannel {
+	/// Linear regression period. Default is `100`
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Standard error multiplier for bounds. Default is `2.0`
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub sigma: ValueType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for LinearRegressionChannel {
+	type Instance = LinearRegressionChannelInstance;
+
+	const NAME: &'static str = "LinearRegressionChannel";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("upper"),
+			OutputDescriptor::price("middle"),
+			OutputDescriptor::price("lower"),
+		],
+		signals: &[OutputDescriptor::signal("breakout")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "100", 3),
+		ParamDescriptor::value("sigma", "2", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::source("source", "close"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			lin_reg: LinRegStats::new(cfg.period, &src)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 2, "period", self.period, "must be > 2")?;
+		Error::ensure(self.sigma > 0.0, "sigma", self.sigma, "must be > 0.0")?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.sigma = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"sigma" => Ok(self.sigma.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for LinearRegressionChannel {
+	fn default() -> Self {
+		Self {
+			period: 100,
+			sigma: 2.0,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRegressionChannelInstance {
+	cfg: LinearRegressionChannel,
+
+	lin_reg: LinRegStats,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for LinearRegressionChannelInstance {
+	type Config = LinearRegressionChannel;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let middle = self.lin_reg.next(&source);
+		let std_error = self.lin_reg.std_error();
+
+		let upper = std_error.mul_add(self.cfg.sigma, middle);
+		let lower = std_error.mul_add(-self.cfg.sigma, middle);
+
+		let signal =
+			self.cross_above.next(&(source, upper)) - self.cross_under.next(&(source, lower));
+
+		IndicatorResult::new(&[upper, middle, lower], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.lin_reg.warmup_len()
+	}
+}
@@ -1,8 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, Seed, Source, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::Cross;
 
@@ -58,6 +61,12 @@ pub struct MACD<M: MovingAverageConstructor = MA> {
 
 	/// Source value type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
@@ -65,27 +74,65 @@ impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
 
 	const NAME: &'static str = "MACD";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("signal"),
+		],
+		signals: &[
+			OutputDescriptor::signal("signal_cross"),
+			OutputDescriptor::signal("zero_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma1", "ema-12", 2),
+		ParamDescriptor::moving_average("ma2", "ema-26", 3),
+		ParamDescriptor::moving_average("signal", "ema-9", 2),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if self.validate() {
-			let cfg = self;
-			let src = candle.source(cfg.source);
-			Ok(Self::Instance {
-				ma1: cfg.ma1.init(src)?,
-				ma2: cfg.ma2.init(src)?,
-				ma3: cfg.signal.init(0.)?,
-				cross1: Cross::default(),
-				cross2: Cross::default(),
-				cfg,
-			})
-		} else {
-			Err(Error::WrongConfig)
-		}
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			ma1: cfg.ma1.init_seeded(src, cfg.seed)?,
+			ma2: cfg.ma2.init_seeded(src, cfg.seed)?,
+			ma3: cfg.signal.init_seeded(0., cfg.seed)?,
+			cross1: Cross::default(),
+			cross2: Cross::default(),
+			cfg,
+		})
 	}
 
 	fn validate(&self) -> bool {
-		self.ma1.ma_period() < self.ma2.ma_period()
-			&& self.ma1.ma_period() > 1
-			&& self.signal.ma_period() > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma1.ma_period() < self.ma2.ma_period(),
+			"ma1 period",
+			self.ma1.ma_period(),
+			format_args!("must be < ma2 period {}", self.ma2.ma_period()),
+		)?;
+		Error::ensure(
+			self.ma1.ma_period() > 1,
+			"ma1 period",
+			self.ma1.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -106,6 +153,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
 			}
@@ -114,6 +165,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma1" => Ok(self.ma1.to_string()),
+			"ma2" => Ok(self.ma2.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
@@ -126,6 +189,7 @@ impl Default for MACD {
 			ma2: MA::EMA(26),
 			signal: MA::EMA(9),
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -167,4 +231,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for MACDInstance<M> {
 
 		IndicatorResult::new(&[macd, sigline], &[signal1, signal2])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ma1.warmup_len().max(self.ma2.warmup_len()) + self.ma3.warmup_len()
+	}
 }
@@ -0,0 +1,201 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, Source, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
+use crate::methods::{Cross, MAMA};
+use std::ops::Bound;
+
+/// Ehlers' MESA Adaptive Moving Average
+///
+/// ## Links
+///
+/// * <https://www.mesasoftware.com/papers/MAMA.pdf>
+///
+/// # 2 values
+///
+/// * `MAMA` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `FAMA` (Following Adaptive Moving Average) value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When `MAMA` crosses `FAMA` upwards, returns full buy signal.
+///   When `MAMA` crosses `FAMA` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MESAAdaptiveMovingAverage;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = MESAAdaptiveMovingAverage::default().over(&candles).unwrap();
+///
+/// assert_eq!(results.len(), 100);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MESAAdaptiveMovingAverage {
+	/// Maximum smoothing factor. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `1.0`\]
+	pub fast_limit: ValueType,
+
+	/// Minimum smoothing factor. Default is `0.05`.
+	///
+	/// Range in \(`0.0`; `fast_limit`\]
+	pub slow_limit: ValueType,
+
+	/// Source type of values. Default is [`HL2`](crate::core::Source::HL2)
+	pub source: Source,
+}
+
+impl IndicatorConfig for MESAAdaptiveMovingAverage {
+	type Instance = MESAAdaptiveMovingAverageInstance;
+
+	const NAME: &'static str = "MESAAdaptiveMovingAverage";
+
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("mama"),
+			OutputDescriptor::price("fama"),
+		],
+		signals: &[OutputDescriptor::signal("cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::value(
+			"fast_limit",
+			"0.5",
+			Bound::Excluded(0.0),
+			Bound::Included(1.0),
+		),
+		ParamDescriptor::value(
+			"slow_limit",
+			"0.05",
+			Bound::Excluded(0.0),
+			Bound::Included(1.0),
+		),
+		ParamDescriptor::source("source", "hl2"),
+	];
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		self.validate_detailed()?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		Ok(Self::Instance {
+			mama: MAMA::new((cfg.fast_limit, cfg.slow_limit), &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.fast_limit > 0.0 && self.fast_limit <= 1.0,
+			"fast_limit",
+			self.fast_limit,
+			"must be in range (0.0; 1.0]",
+		)?;
+		Error::ensure(
+			self.slow_limit > 0.0 && self.slow_limit <= self.fast_limit,
+			"slow_limit",
+			self.slow_limit,
+			format_args!("must be in range (0.0; fast_limit {}]", self.fast_limit),
+		)?;
+
+		Ok(())
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"fast_limit" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.fast_limit = value,
+			},
+			"slow_limit" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.slow_limit = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"fast_limit" => Ok(self.fast_limit.to_string()),
+			"slow_limit" => Ok(self.slow_limit.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for MESAAdaptiveMovingAverage {
+	fn default() -> Self {
+		Self {
+			fast_limit: 0.5,
+			slow_limit: 0.05,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MESAAdaptiveMovingAverageInstance {
+	cfg: MESAAdaptiveMovingAverage,
+
+	mama: MAMA,
+	cross: Cross,
+}
+
+impl IndicatorInstance for MESAAdaptiveMovingAverageInstance {
+	type Config = MESAAdaptiveMovingAverage;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let mama = self.mama.next(&candle.source(self.cfg.source));
+		let fama = self.mama.fama();
+		let signal = self.cross.next(&(mama, fama));
+
+		IndicatorResult::new(&[mama, fama], &[signal])
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.mama.warmup_len()
+	}
+}
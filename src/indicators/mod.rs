@@ -49,6 +49,9 @@ impl OHLCV for HLC {
 	}
 }
 
+mod adaptive;
+pub use adaptive::{Adaptive, AdaptiveInstance};
+
 mod aroon;
 pub use aroon::{Aroon, AroonInstance};
 
@@ -88,6 +91,20 @@ pub use donchian_channel::{DonchianChannel, DonchianChannelInstance};
 mod ease_of_movement;
 pub use ease_of_movement::{EaseOfMovement, EaseOfMovementInstance};
 
+mod ehlers_cyber_cycle;
+pub use ehlers_cyber_cycle::{EhlersCyberCycle, EhlersCyberCycleInstance};
+
+mod ehlers_decycler;
+pub use ehlers_decycler::{EhlersDecycler, EhlersDecyclerInstance};
+
+mod ehlers_instantaneous_trendline;
+pub use ehlers_instantaneous_trendline::{
+	EhlersInstantaneousTrendline, EhlersInstantaneousTrendlineInstance,
+};
+
+mod ehlers_roofing_filter;
+pub use ehlers_roofing_filter::{EhlersRoofingFilter, EhlersRoofingFilterInstance};
+
 mod elders_force_index;
 pub use elders_force_index::{EldersForceIndex, EldersForceIndexInstance};
 
@@ -115,8 +132,16 @@ pub use klinger_volume_oscillator::{KlingerVolumeOscillator, KlingerVolumeOscill
 mod know_sure_thing;
 pub use know_sure_thing::{KnowSureThing, KnowSureThingInstance};
 
+mod linear_regression_channel;
+pub use linear_regression_channel::{LinearRegressionChannel, LinearRegressionChannelInstance};
+
 mod macd;
-pub use macd::{MovingAverageConvergenceDivergence, MACD, MACDInstance};
+pub use macd::{MACDInstance, MovingAverageConvergenceDivergence, MACD};
+
+mod mesa_adaptive_moving_average;
+pub use mesa_adaptive_moving_average::{
+	MESAAdaptiveMovingAverage, MESAAdaptiveMovingAverageInstance,
+};
 
 mod momentum_index;
 pub use momentum_index::{MomentumIndex, MomentumIndexInstance};
@@ -146,7 +171,7 @@ mod stochastic_oscillator;
 pub use stochastic_oscillator::{StochasticOscillator, StochasticOscillatorInstance};
 
 mod trix;
-pub use trix::{Trix, TRIXInstance};
+pub use trix::{TRIXInstance, Trix};
 
 mod trend_strength_index;
 pub use trend_strength_index::{TrendStrengthIndex, TrendStrengthIndexInstance};
@@ -156,3 +181,8 @@ pub use true_strength_index::{TrueStrengthIndex, TrueStrengthIndexInstance};
 
 mod woodies_cci;
 pub use woodies_cci::{WoodiesCCI, WoodiesCCIInstance};
+
+mod registry;
+pub use registry::{IndicatorFactory, IndicatorRegistry};
+
+mod spec;
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::Momentum;
 
 /// Momentum Index
@@ -44,10 +47,22 @@ impl IndicatorConfig for MomentumIndex {
 
 	const NAME: &'static str = "MomentumIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("slow"),
+			OutputDescriptor::unbounded("fast"),
+		],
+		signals: &[OutputDescriptor::signal("direction")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "10", 2),
+		ParamDescriptor::period("period2", "1", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = &candle.source(cfg.source);
@@ -60,7 +75,19 @@ impl IndicatorConfig for MomentumIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 0 && self.period1 > self.period2
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period2 > 0, "period2", self.period2, "must be > 0")?;
+		Error::ensure(
+			self.period1 > self.period2,
+			"period1",
+			self.period1,
+			format_args!("must be > period2 {}", self.period2),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -86,6 +113,16 @@ impl IndicatorConfig for MomentumIndex {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -136,4 +173,8 @@ impl IndicatorInstance for MomentumIndexInstance {
 
 		IndicatorResult::new(&[v, s], &[Action::from(signal)])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.momentum1.warmup_len().max(self.momentum2.warmup_len())
+	}
 }
@@ -3,8 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::Candle;
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::Cross;
+use std::ops::Bound;
 
 /// Money Flow Index
 ///
@@ -54,10 +58,25 @@ impl IndicatorConfig for MoneyFlowIndex {
 
 	const NAME: &'static str = "MoneyFlowIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("upper", 0.5, 1.0),
+			OutputDescriptor::oscillator("main", 0.0, 1.0),
+			OutputDescriptor::oscillator("lower", 0.0, 0.5),
+		],
+		signals: &[
+			OutputDescriptor::signal("enters_zone"),
+			OutputDescriptor::signal("leaves_zone"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "14", 2),
+		ParamDescriptor::value("zone", "0.2", Bound::Included(0.0), Bound::Included(0.5)),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let static_candle = Candle::from(candle);
 		let cfg = self;
@@ -74,7 +93,18 @@ impl IndicatorConfig for MoneyFlowIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0. && self.zone <= 0.5
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			(0.0..=0.5).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 0.5]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -96,6 +126,15 @@ impl IndicatorConfig for MoneyFlowIndex {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 2)
 	}
@@ -176,4 +215,8 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 			&[enters_zone.into(), leaves_zone.into()],
 		)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
 }
@@ -2,10 +2,14 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use std::cmp::Ordering;
 
 use super::HLC;
+use std::ops::Bound;
 
 /// Parabolic Stop And Reverse
 ///
@@ -39,10 +43,21 @@ impl IndicatorConfig for ParabolicSAR {
 
 	const NAME: &'static str = "ParabolicSAR";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("main"),
+			OutputDescriptor::oscillator("trend", -1.0, 1.0),
+		],
+		signals: &[OutputDescriptor::signal("trend_change")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::value("af_step", "0.02", Bound::Excluded(0.0), Bound::Unbounded),
+		ParamDescriptor::value("af_max", "0.2", Bound::Excluded(0.0), Bound::Unbounded),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -58,7 +73,18 @@ impl IndicatorConfig for ParabolicSAR {
 	}
 
 	fn validate(&self) -> bool {
-		self.af_step < self.af_max
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.af_step < self.af_max,
+			"af_step",
+			self.af_step,
+			format_args!("must be < af_max {}", self.af_max),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -80,6 +106,15 @@ impl IndicatorConfig for ParabolicSAR {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"af_step" => Ok(self.af_step.to_string()),
+			"af_max" => Ok(self.af_max.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -176,4 +211,8 @@ impl IndicatorInstance for ParabolicSARInstance {
 
 		IndicatorResult::new(&[sar, trend as ValueType], &[Action::from(signal)])
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
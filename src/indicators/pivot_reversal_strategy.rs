@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{LowerReversalSignal, UpperReversalSignal};
 
 use super::HLC;
@@ -44,10 +47,18 @@ impl IndicatorConfig for PivotReversalStrategy {
 
 	const NAME: &'static str = "PivotReversalStrategy";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[],
+		signals: &[OutputDescriptor::signal("pivot")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("left", "4", 1),
+		ParamDescriptor::period("right", "2", 1),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -61,7 +72,20 @@ impl IndicatorConfig for PivotReversalStrategy {
 	}
 
 	fn validate(&self) -> bool {
-		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.left >= 1, "left", self.left, "must be >= 1")?;
+		Error::ensure(self.right >= 1, "right", self.right, "must be >= 1")?;
+		Error::ensure(
+			self.left.saturating_add(self.right) < PeriodType::MAX,
+			"left",
+			self.left,
+			format_args!("+ right {} must be < {}", self.right, PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -83,6 +107,15 @@ impl IndicatorConfig for PivotReversalStrategy {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"left" => Ok(self.left.to_string()),
+			"right" => Ok(self.right.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(0, 1)
 	}
@@ -144,4 +177,8 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 
 		IndicatorResult::new(&[], &[r.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ph.warmup_len().max(self.pl.warmup_len())
+	}
 }
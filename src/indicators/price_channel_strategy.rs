@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Highest, Lowest};
+use std::ops::Bound;
 
 /// Price Channel Strategy
 ///
@@ -48,10 +52,21 @@ impl IndicatorConfig for PriceChannelStrategy {
 
 	const NAME: &'static str = "PriceChannelStrategy";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::price("upper"),
+			OutputDescriptor::price("lower"),
+		],
+		signals: &[OutputDescriptor::signal("breakout")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "20", 2),
+		ParamDescriptor::value("sigma", "1", Bound::Excluded(0.0), Bound::Included(1.0)),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		Ok(Self::Instance {
@@ -62,7 +77,19 @@ impl IndicatorConfig for PriceChannelStrategy {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.sigma > 0. && self.sigma <= 1.0
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+		Error::ensure(
+			self.sigma > 0.0 && self.sigma <= 1.0,
+			"sigma",
+			self.sigma,
+			"must be in (0; 1]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -84,6 +111,15 @@ impl IndicatorConfig for PriceChannelStrategy {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"sigma" => Ok(self.sigma.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -132,4 +168,8 @@ impl IndicatorInstance for PriceChannelStrategyInstance {
 
 		IndicatorResult::new(&[upper, lower], &[signal.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.highest.warmup_len().max(self.lowest.warmup_len())
+	}
 }
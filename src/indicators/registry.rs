@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::core::{Error, IndicatorConfig, IndicatorConfigDyn, OHLCV};
+
+use super::{
+	Aroon, AverageDirectionalIndex, AwesomeOscillator, BollingerBands, ChaikinMoneyFlow,
+	ChaikinOscillator, ChandeKrollStop, ChandeMomentumOscillator, CommodityChannelIndex,
+	CoppockCurve, DetrendedPriceOscillator, DonchianChannel, EaseOfMovement, EhlersCyberCycle,
+	EhlersDecycler, EhlersInstantaneousTrendline, EhlersRoofingFilter, EldersForceIndex, Envelopes,
+	FisherTransform, HullMovingAverage, IchimokuCloud, Kaufman, KeltnerChannel,
+	KlingerVolumeOscillator, KnowSureThing, LinearRegressionChannel, MESAAdaptiveMovingAverage,
+	MomentumIndex, MoneyFlowIndex, ParabolicSAR, PivotReversalStrategy, PriceChannelStrategy,
+	RelativeStrengthIndex, RelativeVigorIndex, SMIErgodicIndicator, StochasticOscillator,
+	TrendStrengthIndex, Trix, TrueStrengthIndex, WoodiesCCI, MACD,
+};
+
+/// Factory function, which creates a boxed indicator config
+pub type IndicatorFactory<T> = Box<dyn Fn() -> Box<dyn IndicatorConfigDyn<T>> + Send + Sync>;
+
+/// Registry of indicators, which allows to construct any indicator config by its name at runtime
+///
+/// Names are case insensitive. Every indicator may also be registered under any count of aliases.
+///
+/// [`IndicatorRegistry::new`] contains all the indicators of this crate.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{IndicatorRegistry, MACD};
+///
+/// let mut registry = IndicatorRegistry::new();
+///
+/// let mut rsi = registry.create("RSI").unwrap();
+/// assert_eq!(rsi.name(), "RelativeStrengthIndex");
+///
+/// rsi.set("ma", "sma-10".to_string()).unwrap();
+/// let candles: Vec<_> = RandomCandles::new().take(20).collect();
+/// let results = rsi.over(&candles).unwrap();
+/// assert_eq!(results.len(), 20);
+///
+/// // User-defined indicators may be registered at runtime
+/// registry.register_fn("FastMACD", || {
+///     let mut macd = MACD::default();
+///     macd.ma1 = "ema-6".parse().unwrap();
+///     Box::new(macd)
+/// });
+/// assert_eq!(registry.create("fastmacd").unwrap().get("ma1").unwrap(), "ema-6");
+/// ```
+pub struct IndicatorRegistry<T: OHLCV> {
+	factories: BTreeMap<String, (&'static str, IndicatorFactory<T>)>,
+	aliases: BTreeMap<String, String>,
+}
+
+impl<T: OHLCV + 'static> IndicatorRegistry<T> {
+	/// Creates a registry with all the indicators of this crate and their common aliases
+	#[must_use]
+	pub fn new() -> Self {
+		let mut registry = Self::empty();
+
+		registry.register::<Aroon>();
+		registry.register::<AverageDirectionalIndex>();
+		registry.register::<AwesomeOscillator>();
+		registry.register::<BollingerBands>();
+		registry.register::<ChaikinMoneyFlow>();
+		registry.register::<ChaikinOscillator>();
+		registry.register::<ChandeKrollStop>();
+		registry.register::<ChandeMomentumOscillator>();
+		registry.register::<CommodityChannelIndex>();
+		registry.register::<CoppockCurve>();
+		registry.register::<DetrendedPriceOscillator>();
+		registry.register::<DonchianChannel>();
+		registry.register::<EaseOfMovement>();
+		registry.register::<EhlersCyberCycle>();
+		registry.register::<EhlersDecycler>();
+		registry.register::<EhlersInstantaneousTrendline>();
+		registry.register::<EhlersRoofingFilter>();
+		registry.register::<EldersForceIndex>();
+		registry.register::<Envelopes>();
+		registry.register::<FisherTransform>();
+		registry.register::<HullMovingAverage>();
+		registry.register::<IchimokuCloud>();
+		registry.register::<Kaufman>();
+		registry.register::<KeltnerChannel>();
+		registry.register::<KlingerVolumeOscillator>();
+		registry.register::<KnowSureThing>();
+		registry.register::<LinearRegressionChannel>();
+		registry.register::<MACD>();
+		registry.register::<MESAAdaptiveMovingAverage>();
+		registry.register::<MomentumIndex>();
+		registry.register::<MoneyFlowIndex>();
+		registry.register::<ParabolicSAR>();
+		registry.register::<PivotReversalStrategy>();
+		registry.register::<PriceChannelStrategy>();
+		registry.register::<RelativeStrengthIndex>();
+		registry.register::<RelativeVigorIndex>();
+		registry.register::<SMIErgodicIndicator>();
+		registry.register::<StochasticOscillator>();
+		registry.register::<TrendStrengthIndex>();
+		registry.register::<Trix>();
+		registry.register::<TrueStrengthIndex>();
+		registry.register::<WoodiesCCI>();
+
+		let aliases = [
+			("ADX", "AverageDirectionalIndex"),
+			("AO", "AwesomeOscillator"),
+			("BB", "BollingerBands"),
+			("CMF", "ChaikinMoneyFlow"),
+			("CMO", "ChandeMomentumOscillator"),
+			("CCI", "CommodityChannelIndex"),
+			("DPO", "DetrendedPriceOscillator"),
+			("EOM", "EaseOfMovement"),
+			("CyberCycle", "EhlersCyberCycle"),
+			("Decycler", "EhlersDecycler"),
+			("ITrend", "EhlersInstantaneousTrendline"),
+			("InstantaneousTrendline", "EhlersInstantaneousTrendline"),
+			("RoofingFilter", "EhlersRoofingFilter"),
+			("EFI", "EldersForceIndex"),
+			("HMA", "HullMovingAverage"),
+			("KAMA", "Kaufman"),
+			("KVO", "KlingerVolumeOscillator"),
+			("KST", "KnowSureThing"),
+			("LRC", "LinearRegressionChannel"),
+			("MovingAverageConvergenceDivergence", "MACD"),
+			("MAMA", "MESAAdaptiveMovingAverage"),
+			("MFI", "MoneyFlowIndex"),
+			("ParabolicStopAndReverse", "ParabolicSAR"),
+			("RSI", "RelativeStrengthIndex"),
+			("RVI", "RelativeVigorIndex"),
+			("SMI", "SMIErgodicIndicator"),
+			("Stochastic", "StochasticOscillator"),
+		];
+
+		for (alias, name) in aliases {
+			registry
+				.aliases
+				.insert(alias.to_ascii_lowercase(), name.to_ascii_lowercase());
+		}
+
+		registry
+	}
+
+	/// Creates a registry without any indicators
+	#[must_use]
+	pub fn empty() -> Self {
+		Self {
+			factories: BTreeMap::new(),
+			aliases: BTreeMap::new(),
+		}
+	}
+
+	/// Registers an indicator config under its [`NAME`](IndicatorConfig::NAME), using its `Default` value as a factory
+	///
+	/// Replaces any previously registered indicator with the same name.
+	pub fn register<C>(&mut self)
+	where
+		C: IndicatorConfig + Default + 'static,
+		C::Instance: 'static,
+	{
+		self.register_fn(C::NAME, || Box::new(C::default()));
+	}
+
+	/// Registers an indicator factory under the given `name`
+	///
+	/// Replaces any previously registered indicator or alias with the same name.
+	pub fn register_fn<F>(&mut self, name: &'static str, factory: F)
+	where
+		F: Fn() -> Box<dyn IndicatorConfigDyn<T>> + Send + Sync + 'static,
+	{
+		let key = name.to_ascii_lowercase();
+
+		self.aliases.remove(&key);
+		self.factories.insert(key, (name, Box::new(factory)));
+	}
+
+	/// Registers an `alias` for the already registered indicator `name`
+	///
+	/// Returns [`Error::UnknownIndicator`] if there is no indicator registered with the `name`.
+	pub fn alias(&mut self, alias: &str, name: &str) -> Result<(), Error> {
+		let target = self.resolve(name)?.to_string();
+
+		self.aliases.insert(alias.to_ascii_lowercase(), target);
+
+		Ok(())
+	}
+
+	/// Creates a default indicator config by its name or alias
+	///
+	/// Returns [`Error::UnknownIndicator`] if there is no such indicator in the registry.
+	pub fn create(&self, name: &str) -> Result<Box<dyn IndicatorConfigDyn<T>>, Error> {
+		let key = self.resolve(name)?;
+
+		Ok((self.factories[key].1)())
+	}
+
+	/// Checks if there is an indicator with the given name or alias in the registry
+	#[must_use]
+	pub fn contains(&self, name: &str) -> bool {
+		self.resolve(name).is_ok()
+	}
+
+	/// Returns an iterator over all the registered indicator names in alphabetical order, not including aliases
+	pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+		self.factories.values().map(|(name, _)| *name)
+	}
+
+	/// Returns an iterator over all the registered `(alias, indicator name)` pairs
+	///
+	/// Aliases are returned in lowercase.
+	pub fn aliases(&self) -> impl Iterator<Item = (&str, &'static str)> + '_ {
+		self.aliases
+			.iter()
+			.map(|(alias, key)| (alias.as_str(), self.factories[key].0))
+	}
+
+	fn resolve(&self, name: &str) -> Result<&str, Error> {
+		let key = name.to_ascii_lowercase();
+
+		if let Some((key, _)) = self.factories.get_key_value(&key) {
+			return Ok(key);
+		}
+
+		self.aliases
+			.get(&key)
+			.map(String::as_str)
+			.ok_or_else(|| Error::UnknownIndicator(name.to_string()))
+	}
+}
+
+impl<T: OHLCV + 'static> Default for IndicatorRegistry<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: OHLCV> fmt::Debug for IndicatorRegistry<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("IndicatorRegistry")
+			.field(
+				"names",
+				&self.factories.values().map(|x| x.0).collect::<Vec<_>>(),
+			)
+			.field("aliases", &self.aliases)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorRegistry;
+	use crate::core::{Candle, Error, OutputDescriptor, OutputRange, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::Kaufman;
+
+	#[test]
+	fn test_registry_builtins() {
+		let registry = IndicatorRegistry::<Candle>::new();
+
+		assert_eq!(registry.names().count(), 42);
+
+		for name in registry.names() {
+			let config = registry.create(name).unwrap();
+			assert_eq!(config.name(), name);
+			assert!(config.validate());
+		}
+
+		for (alias, name) in registry.aliases() {
+			assert_eq!(registry.create(alias).unwrap().name(), name);
+		}
+	}
+
+	#[test]
+	fn test_registry_outputs() {
+		let registry = IndicatorRegistry::<Candle>::new();
+		// `RandomCandles` may have negative volumes, which never happen on the real markets
+		let candles: Vec<_> = RandomCandles::new()
+			.take(300)
+			.map(|candle| Candle {
+				volume: candle.volume.abs(),
+				..candle
+			})
+			.collect();
+
+		let check = |name: &str, descriptor: &OutputDescriptor, value: ValueType| {
+			if let OutputRange::Bounded { min, max } = descriptor.range {
+				assert!(
+					(min..=max).contains(&value),
+					"{name}.{} = {value} is out of [{min}; {max}]",
+					descriptor.name
+				);
+			}
+		};
+
+		for name in registry.names() {
+			let config = registry.create(name).unwrap();
+			let outputs = config.outputs();
+			assert_eq!(outputs.size(), config.size(), "{name}");
+
+			for result in config.over(&candles).unwrap() {
+				assert_eq!(result.size(), config.size(), "{name}");
+
+				for (descriptor, &value) in outputs.values.iter().zip(result.values()) {
+					check(name, descriptor, value);
+				}
+
+				for (descriptor, signal) in outputs.signals.iter().zip(result.signals()) {
+					if let Some(ratio) = signal.ratio() {
+						check(name, descriptor, ratio);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_registry_aliases() {
+		let mut registry = IndicatorRegistry::<Candle>::new();
+
+		assert_eq!(registry.create("kama").unwrap().name(), "Kaufman");
+		assert_eq!(registry.create("Kaufman").unwrap().name(), "Kaufman");
+		assert_eq!(
+			registry.create("rsi").unwrap().name(),
+			registry.create("RelativeStrengthIndex").unwrap().name()
+		);
+
+		assert!(matches!(
+			registry.alias("foo", "bar"),
+			Err(Error::UnknownIndicator(_))
+		));
+		assert!(matches!(
+			registry.create("bar"),
+			Err(Error::UnknownIndicator(_))
+		));
+
+		registry.alias("AMA", "KAMA").unwrap();
+		assert_eq!(registry.create("ama").unwrap().name(), "Kaufman");
+	}
+
+	#[test]
+	fn test_registry_custom() {
+		let mut registry = IndicatorRegistry::<Candle>::empty();
+		assert_eq!(registry.names().count(), 0);
+
+		registry.register_fn("SlowKAMA", || {
+			Box::new(Kaufman {
+				period3: 60,
+				..Kaufman::default()
+			})
+		});
+
+		assert!(registry.contains("slowkama"));
+		assert_eq!(registry.names().collect::<Vec<_>>(), ["SlowKAMA"]);
+		assert_eq!(
+			registry.create("SlowKAMA").unwrap().get("period3").unwrap(),
+			"60"
+		);
+	}
+}
@@ -1,11 +1,16 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, Seed, Source, ValueType, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::Cross;
-use std::mem::replace;
+use std::ops::Bound;
 
 /// Relative Strength Index
 ///
@@ -49,6 +54,12 @@ pub struct RelativeStrengthIndex<M: MovingAverageConstructor = MA> {
 
 	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
@@ -56,18 +67,37 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 
 	const NAME: &'static str = "RelativeStrengthIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::oscillator("main", 0.0, 1.0)],
+		signals: &[
+			OutputDescriptor::signal("enters_zone"),
+			OutputDescriptor::signal("leaves_zone"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::moving_average("ma", "ema-14", 3),
+		ParamDescriptor::value("zone", "0.3", Bound::Excluded(0.0), Bound::Included(0.5)),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
+		// TA-Lib and TradingView do not count the very first candle, which has no price change
+		let previous_input = match cfg.seed {
+			Seed::Initial => Some(src),
+			Seed::SMA => None,
+		};
+
 		Ok(Self::Instance {
-			previous_input: src,
-			posma: cfg.ma.init(0.)?,
-			negma: cfg.ma.init(0.)?,
+			previous_input,
+			posma: cfg.ma.init_seeded(0., cfg.seed)?,
+			negma: cfg.ma.init_seeded(0., cfg.seed)?,
 			cross_upper: Cross::new((), &(0.5, 1.0 - cfg.zone))?,
 			cross_lower: Cross::new((), &(0.5, cfg.zone))?,
 			cfg,
@@ -75,7 +105,24 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 2 && self.zone > 0. && self.zone <= 0.5
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.ma.ma_period() > 2,
+			"ma period",
+			self.ma.ma_period(),
+			"must be > 2",
+		)?;
+		Error::ensure(
+			self.zone > 0.0 && self.zone <= 0.5,
+			"zone",
+			self.zone,
+			"must be in (0; 0.5]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -92,6 +139,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -101,6 +152,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"ma" => Ok(self.ma.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
@@ -112,6 +174,7 @@ impl Default for RelativeStrengthIndex {
 			ma: MA::EMA(14),
 			zone: 0.3,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -121,7 +184,7 @@ impl Default for RelativeStrengthIndex {
 pub struct RelativeStrengthIndexInstance<M: MovingAverageConstructor = MA> {
 	cfg: RelativeStrengthIndex<M>,
 
-	previous_input: ValueType,
+	previous_input: Option<ValueType>,
 	posma: M::Instance,
 	negma: M::Instance,
 	cross_upper: Cross,
@@ -141,7 +204,11 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeStrengthIndexIns
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
-		let change = src - replace(&mut self.previous_input, src);
+		let Some(previous_input) = self.previous_input.replace(src) else {
+			return IndicatorResult::new(&[0.5], &[Action::None, Action::None]);
+		};
+
+		let change = src - previous_input;
 
 		let pos: ValueType = self.posma.next(&change.max(0.));
 		let neg: ValueType = self.negma.next(&change.min(0.)) * -1.;
@@ -161,4 +228,9 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeStrengthIndexIns
 
 		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		// price change needs previous candle
+		1 + self.posma.warmup_len()
+	}
 }
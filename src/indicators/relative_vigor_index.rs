@@ -1,10 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, SMA, SWMA};
+use std::ops::Bound;
 
 /// Relative Vigor Index
 ///
@@ -59,6 +63,12 @@ pub struct RelativeVigorIndex<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`0.0`; `0.5`\)
 	pub zone: ValueType,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for RelativeVigorIndex<M> {
@@ -66,10 +76,27 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeVigorIndex<M> {
 
 	const NAME: &'static str = "RelativeVigorIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("main", -0.5, 0.5),
+			OutputDescriptor::oscillator("signal", -0.5, 0.5),
+		],
+		signals: &[
+			OutputDescriptor::signal("signal_cross"),
+			OutputDescriptor::signal("zone_signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "10", 2),
+		ParamDescriptor::period("period2", "4", 2),
+		ParamDescriptor::moving_average("signal", "swma-4", 2),
+		ParamDescriptor::value("zone", "0.25", Bound::Included(0.0), Bound::Excluded(0.5)),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let d_close = &0.0; // candle.close() - candle.open();
@@ -82,18 +109,33 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeVigorIndex<M> {
 			sma1: SMA::new(cfg.period1, d_close)?,
 			swma2: SWMA::new(cfg.period2, d_hl)?,
 			sma2: SMA::new(cfg.period1, d_hl)?,
-			ma: cfg.signal.init(rvi)?,
+			ma: cfg.signal.init_seeded(rvi, cfg.seed)?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 >= 2
-			&& self.zone >= 0.
-			&& self.zone < 0.5
-			&& self.period2 > 1
-			&& self.signal.ma_period() > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period1 >= 2, "period1", self.period1, "must be >= 2")?;
+		Error::ensure(
+			self.zone >= 0.0 && self.zone < 0.5,
+			"zone",
+			self.zone,
+			"must be in [0; 0.5)",
+		)?;
+		Error::ensure(self.period2 > 1, "period2", self.period2, "must be > 1")?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -114,6 +156,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeVigorIndex<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -123,6 +169,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeVigorIndex<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
@@ -135,6 +193,7 @@ impl Default for RelativeVigorIndex {
 			period2: 4,
 			signal: MA::SWMA(4),
 			zone: 0.25,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -192,4 +251,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeVigorIndexInstan
 
 		IndicatorResult::new(&[rvi, sig], &[s1.into(), s2.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.swma1.warmup_len() + self.sma1.warmup_len() + self.ma.warmup_len()
+	}
 }
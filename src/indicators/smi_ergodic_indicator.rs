@@ -1,10 +1,16 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Error, Method, MovingAverageConstructor, PeriodType, Seed, Source, ValueType, OHLCV,
+};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, TSI};
+use std::ops::Bound;
 
 /// SMI Ergodic Indicator
 ///
@@ -61,6 +67,12 @@ pub struct SMIErgodicIndicator<M: MovingAverageConstructor = MA> {
 
 	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for SMIErgodicIndicator<M> {
@@ -68,30 +80,76 @@ impl<M: MovingAverageConstructor> IndicatorConfig for SMIErgodicIndicator<M> {
 
 	const NAME: &'static str = "SMIErgodicIndicator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("main", -1.0, 1.0),
+			OutputDescriptor::oscillator("signal", -1.0, 1.0),
+			OutputDescriptor::oscillator("oscillator", -2.0, 2.0),
+		],
+		signals: &[OutputDescriptor::signal("signal_cross")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "20", 2),
+		ParamDescriptor::period("period2", "5", 2),
+		ParamDescriptor::moving_average("signal", "ema-5", 2),
+		ParamDescriptor::value("zone", "0.2", Bound::Included(0.0), Bound::Included(1.0)),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
 			tsi: TSI::new(cfg.period2, cfg.period1, &src)?,
-			ma: cfg.signal.init(0.)?, // method(cfg.method, cfg.period3, 0.)?,
+			ma: cfg.signal.init_seeded(0., cfg.seed)?, // method(cfg.method, cfg.period3, 0.)?,
 			cross: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 1
-			&& self.period2 <= self.period1
-			&& self.period1 < PeriodType::MAX
-			&& self.signal.ma_period() > 1
-			&& self.signal.ma_period() < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period2 > 1, "period2", self.period2, "must be > 1")?;
+		Error::ensure(
+			self.period2 <= self.period1,
+			"period2",
+			self.period2,
+			format_args!("must be <= period1 {}", self.period1),
+		)?;
+		Error::ensure(
+			self.period1 < PeriodType::MAX,
+			"period1",
+			self.period1,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+		Error::ensure(
+			self.signal.ma_period() < PeriodType::MAX,
+			"signal period",
+			self.signal.ma_period(),
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			(0.0..=1.0).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 1]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -116,6 +174,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for SMIErgodicIndicator<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -125,6 +187,19 @@ impl<M: MovingAverageConstructor> IndicatorConfig for SMIErgodicIndicator<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
@@ -140,6 +215,7 @@ impl Default for SMIErgodicIndicator {
 			// method: RegularMethods::EMA,
 			zone: 0.2,
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -173,4 +249,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for SMIErgodicIndicatorInsta
 
 		IndicatorResult::new(&[tsi, sig, tsi - sig], &[s1.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.tsi.warmup_len() + self.ma.warmup_len()
+	}
 }
@@ -0,0 +1,298 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::{Error, IndicatorConfigDyn, OHLCV};
+
+use super::IndicatorRegistry;
+
+impl<T: OHLCV + 'static> IndicatorRegistry<T> {
+	/// Parses textual indicator specification and returns validated indicator config
+	///
+	/// Specification consists of an indicator name or alias, followed by optional list of arguments in parentheses.
+	/// Arguments may be positional (in the order of [`params`](IndicatorConfigDyn::params)) or named.
+	/// Named arguments may not be followed by positional ones. Parameters, which are not listed, keep their default values.
+	///
+	/// Any error is reported as [`Error::SpecParse`] with a byte position in the `spec`.
+	/// Invalid configuration is reported at the value of the violating parameter, or at the indicator name,
+	/// when that parameter keeps its default value.
+	///
+	/// The canonical form of any indicator config is produced by its `Display` implementation.
+	///
+	/// ```
+	/// use yata::prelude::dd::*;
+	/// use yata::core::{Candle, Error};
+	/// use yata::indicators::IndicatorRegistry;
+	///
+	/// let registry = IndicatorRegistry::<Candle>::new();
+	///
+	/// let macd = registry.parse("macd(ma1=ema-12, ma2=ema-26, signal=ema-9, source=close)").unwrap();
+	/// assert_eq!(macd.to_string(), "MACD(ma1=ema-12, ma2=ema-26, signal=ema-9, source=close, seed=initial)");
+	///
+	/// let bb = registry.parse("bb(20, 2.5)").unwrap();
+	/// assert_eq!(bb.get("sigma").unwrap(), "2.5");
+	///
+	/// assert!(matches!(registry.parse("bb(20, x)"), Err(Error::SpecParse(7, _))));
+	/// assert!(matches!(registry.parse("macd(ema-30)"), Err(Error::SpecParse(5, _))));
+	/// ```
+	pub fn parse(&self, spec: &str) -> Result<Box<dyn IndicatorConfigDyn<T>>, Error> {
+		let name_start = skip_whitespace(spec, 0);
+		let name_end = spec[name_start..]
+			.find(|c: char| !is_identifier(c))
+			.map_or(spec.len(), |x| x + name_start);
+
+		if name_start == name_end {
+			return Err(spec_error(name_start, "expected indicator name"));
+		}
+
+		let name = &spec[name_start..name_end];
+		let mut config = self
+			.create(name)
+			.map_err(|_| spec_error(name_start, format!("unknown indicator {name:?}")))?;
+
+		let mut positions = Vec::new();
+		let open = skip_whitespace(spec, name_end);
+		if open < spec.len() {
+			if !spec[open..].starts_with('(') {
+				return Err(spec_error(open, "expected '('"));
+			}
+
+			let close = spec[open..]
+				.find(')')
+				.map(|x| x + open)
+				.ok_or_else(|| spec_error(spec.len(), "expected ')'"))?;
+
+			let rest = skip_whitespace(spec, close + 1);
+			if rest < spec.len() {
+				return Err(spec_error(rest, "unexpected characters after ')'"));
+			}
+
+			positions = set_arguments(config.as_mut(), spec, open + 1, close)?;
+		}
+
+		config.validate_detailed().map_err(|error| {
+			let position = match &error {
+				// parameter is named by the first word of the error, f.e. `ma1 period`
+				Error::InvalidParameter { name, .. } => positions
+					.iter()
+					.find(|(param, _)| name.split_whitespace().next() == Some(*param))
+					.map_or(name_start, |&(_, position)| position),
+				_ => name_start,
+			};
+
+			spec_error(
+				position,
+				format!("invalid indicator configuration: {error}"),
+			)
+		})?;
+
+		Ok(config)
+	}
+}
+
+/// Sets the arguments and returns positions of the values of all the set parameters
+fn set_arguments<T: OHLCV>(
+	config: &mut dyn IndicatorConfigDyn<T>,
+	spec: &str,
+	start: usize,
+	end: usize,
+) -> Result<Vec<(&'static str, usize)>, Error> {
+	let mut positions = Vec::new();
+	if spec[start..end].trim().is_empty() {
+		return Ok(positions);
+	}
+
+	let params = config.params();
+	let mut is_set = vec![false; params.len()];
+	let mut named = false;
+	let mut arg_start = start;
+
+	for (index, arg) in spec[start..end].split(',').enumerate() {
+		let arg_end = arg_start + arg.len();
+		let position = skip_whitespace(spec, arg_start);
+
+		if arg.trim().is_empty() {
+			return Err(spec_error(position, "expected argument"));
+		}
+
+		let (param_index, value_start) = if let Some(eq) = arg.find('=') {
+			let key = arg[..eq].trim();
+			named = true;
+
+			let param_index = params
+				.iter()
+				.position(|x| x.name == key)
+				.ok_or_else(|| spec_error(position, format!("unknown parameter {key:?}")))?;
+
+			(param_index, arg_start + eq + 1)
+		} else {
+			if named {
+				return Err(spec_error(
+					position,
+					"positional argument after named argument",
+				));
+			}
+
+			if index >= params.len() {
+				return Err(spec_error(position, "too many positional arguments"));
+			}
+
+			(index, arg_start)
+		};
+
+		let param = params[param_index];
+		if std::mem::replace(&mut is_set[param_index], true) {
+			return Err(spec_error(
+				position,
+				format!("parameter {:?} is set twice", param.name),
+			));
+		}
+
+		let value_position = skip_whitespace(spec, value_start);
+		let value = spec[value_start..arg_end].trim();
+
+		if value.is_empty() {
+			return Err(spec_error(value_position, "expected value"));
+		}
+
+		config.set(param.name, value.to_string()).map_err(|_| {
+			spec_error(
+				value_position,
+				format!("invalid value {value:?} for parameter {:?}", param.name),
+			)
+		})?;
+
+		positions.push((param.name, value_position));
+		arg_start = arg_end + 1;
+	}
+
+	Ok(positions)
+}
+
+fn skip_whitespace(spec: &str, position: usize) -> usize {
+	spec[position..]
+		.find(|c: char| !c.is_whitespace())
+		.map_or(spec.len(), |x| x + position)
+}
+
+const fn is_identifier(c: char) -> bool {
+	c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn spec_error(position: usize, reason: impl Into<String>) -> Error {
+	Error::SpecParse(position, reason.into())
+}
+
+/// Formats indicator config in the canonical textual form with all the parameters
+///
+/// See also [`IndicatorRegistry::parse`]
+impl<T: OHLCV> fmt::Display for dyn IndicatorConfigDyn<T> + '_ {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}(", self.name())?;
+
+		for (index, param) in self.params().iter().enumerate() {
+			if index > 0 {
+				f.write_str(", ")?;
+			}
+
+			let value = self.get(param.name).map_err(|_| fmt::Error)?;
+			write!(f, "{}={}", param.name, value)?;
+		}
+
+		f.write_str(")")
+	}
+}
+
+/// Parses textual indicator specification using the [default registry](IndicatorRegistry::new)
+///
+/// The default registry is built on every call, which allocates all of its factories and aliases.
+/// Create a registry once and use [`IndicatorRegistry::parse`] to parse many specifications.
+///
+/// See also [`IndicatorRegistry::parse`]
+impl<T: OHLCV + 'static> FromStr for Box<dyn IndicatorConfigDyn<T>> {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		IndicatorRegistry::new().parse(s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::core::{Candle, Error, IndicatorConfigDyn};
+	use crate::indicators::IndicatorRegistry;
+
+	fn parse(spec: &str) -> Result<Box<dyn IndicatorConfigDyn<Candle>>, Error> {
+		IndicatorRegistry::new().parse(spec)
+	}
+
+	fn error_position(spec: &str) -> usize {
+		match parse(spec) {
+			Err(Error::SpecParse(position, _)) => position,
+			_ => panic!("{spec:?} must not be parsed"),
+		}
+	}
+
+	#[test]
+	fn test_spec_round_trip() {
+		let registry = IndicatorRegistry::<Candle>::new();
+
+		for name in registry.names() {
+			let config = registry.create(name).unwrap();
+			let spec = config.to_string();
+			let parsed = registry.parse(&spec).unwrap();
+
+			assert_eq!(parsed.to_string(), spec);
+		}
+	}
+
+	#[test]
+	fn test_spec_arguments() {
+		let rsi = parse(" RSI ( ema-10 ,zone = 0.25, source=hl2 ) ").unwrap();
+		assert_eq!(rsi.name(), "RelativeStrengthIndex");
+		assert_eq!(rsi.get("ma").unwrap(), "ema-10");
+		assert_eq!(rsi.get("zone").unwrap(), "0.25");
+		assert_eq!(rsi.get("source").unwrap(), "hl2");
+
+		assert_eq!(
+			parse("kama").unwrap().to_string(),
+			parse("Kaufman()").unwrap().to_string()
+		);
+
+		let spec: Box<dyn IndicatorConfigDyn<Candle>> = "bb(30)".parse().unwrap();
+		assert_eq!(spec.get("avg_size").unwrap(), "30");
+	}
+
+	#[test]
+	fn test_spec_errors() {
+		assert_eq!(error_position(""), 0);
+		assert_eq!(error_position("  (20)"), 2);
+		assert_eq!(error_position("foo(20)"), 0);
+		assert_eq!(error_position("bb[20]"), 2);
+		assert_eq!(error_position("bb(20"), 5);
+		assert_eq!(error_position("bb(20) x"), 7);
+		assert_eq!(error_position("bb(20,,2)"), 6);
+		assert_eq!(error_position("bb(20, sigma=)"), 13);
+		assert_eq!(error_position("bb(20, sigma=x)"), 13);
+		assert_eq!(error_position("bb(20, foo=2)"), 7);
+		assert_eq!(error_position("bb(sigma=2, 20)"), 12);
+		assert_eq!(error_position("bb(20, avg_size=20)"), 7);
+		assert_eq!(error_position("bb(20, 2, close, 4)"), 17);
+		assert_eq!(error_position(" bb(1)"), 4);
+	}
+
+	#[test]
+	fn test_spec_invalid_config() {
+		match parse("macd(ema-30)") {
+			Err(Error::SpecParse(5, reason)) => assert_eq!(
+				reason,
+				"invalid indicator configuration: ma1 period 30 must be < ma2 period 26"
+			),
+			_ => panic!("invalid MACD config must not be parsed"),
+		}
+
+		assert_eq!(error_position("macd(ma2=ema-10, ma1=ema-12)"), 21);
+		assert_eq!(error_position("rsi(zone = 0.7)"), 11);
+		// `ma1` keeps its default value, so the indicator name is reported
+		assert_eq!(error_position(" macd(ma2=ema-10)"), 1);
+	}
+}
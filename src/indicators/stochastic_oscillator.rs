@@ -1,10 +1,14 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Seed, ValueType, OHLCV};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::MA;
 use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
+use std::ops::Bound;
 
 /// Stochastic Oscillator
 ///
@@ -69,6 +73,12 @@ pub struct StochasticOscillator<M: MovingAverageConstructor = MA> {
 	///
 	/// Range in \[`0.0`; `0.5`\].
 	pub zone: ValueType,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
@@ -76,10 +86,28 @@ impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
 
 	const NAME: &'static str = "StochasticOscillator";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("main", 0.0, 1.0),
+			OutputDescriptor::oscillator("signal", 0.0, 1.0),
+		],
+		signals: &[
+			OutputDescriptor::signal("main_zone"),
+			OutputDescriptor::signal("signal_zone"),
+			OutputDescriptor::signal("signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "14", 2),
+		ParamDescriptor::moving_average("ma", "sma-14", 1),
+		ParamDescriptor::moving_average("signal", "sma-3", 1),
+		ParamDescriptor::value("zone", "0.2", Bound::Included(0.0), Bound::Included(0.5)),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		// we need to check division by zero, so we can really just check if `high` is equal to `low` without using any kind of round error checks
@@ -94,8 +122,8 @@ impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
 			upper_zone: 1. - cfg.zone,
 			highest: Highest::new(cfg.period, &candle.high())?,
 			lowest: Lowest::new(cfg.period, &candle.low())?,
-			ma1: cfg.ma.init(k_rows)?, //method(cfg.method_k, cfg.smooth_k, k_rows)?,
-			ma2: cfg.signal.init(k_rows)?, //method(cfg.method_d, cfg.smooth_d, k_rows)?,
+			ma1: cfg.ma.init_seeded(k_rows, cfg.seed)?, //method(cfg.method_k, cfg.smooth_k, k_rows)?,
+			ma2: cfg.signal.init_seeded(k_rows, cfg.seed)?, //method(cfg.method_d, cfg.smooth_d, k_rows)?,
 			cross_over: Cross::default(),
 			cross_above1: CrossAbove::default(),
 			cross_under1: CrossUnder::default(),
@@ -106,7 +134,19 @@ impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.zone >= 0.0 && self.zone <= 0.5
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+		Error::ensure(
+			(0.0..=0.5).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 0.5]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -127,6 +167,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
 			}
@@ -135,6 +179,18 @@ impl<M: MovingAverageConstructor> IndicatorConfig for StochasticOscillator<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"ma" => Ok(self.ma.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -147,6 +203,7 @@ impl Default for StochasticOscillator {
 			ma: MA::SMA(14),
 			signal: MA::SMA(3),
 			zone: 0.2,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -202,4 +259,10 @@ impl<M: MovingAverageConstructor> IndicatorInstance for StochasticOscillatorInst
 
 		IndicatorResult::new(&[f1, f2], &[s1, s2, s3])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.highest.warmup_len().max(self.lowest.warmup_len())
+			+ self.ma1.warmup_len()
+			+ self.ma2.warmup_len()
+	}
 }
@@ -3,8 +3,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{CrossAbove, CrossUnder, ReversalSignal, WMA};
+use std::ops::Bound;
 
 /// Trend Strength Index
 ///
@@ -52,48 +56,81 @@ impl IndicatorConfig for TrendStrengthIndex {
 
 	const NAME: &'static str = "TrendStrengthIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[OutputDescriptor::oscillator("main", -1.0, 1.0)],
+		signals: &[
+			OutputDescriptor::signal("zone_cross"),
+			OutputDescriptor::signal("reverse"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period", "14", 2),
+		ParamDescriptor::value("zone", "0.75", Bound::Included(0.0), Bound::Excluded(1.0)),
+		ParamDescriptor::period("reverse_offset", "2", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if self.validate() {
-			let cfg = self;
-
-			let inverted_period = (cfg.period as ValueType).recip();
-			let src = candle.source(cfg.source);
-
-			let period = cfg.period as usize;
-			let sx = (period + 1) * period / 2;
-			let sx2 = (sx * (2 * period + 1)) as ValueType / 3.0;
-
-			let inv_sx = ((period + 1) * sx) as ValueType * 0.5;
-			let k = sx2 - inv_sx;
-			let sy = src * cfg.period as ValueType;
-			let sy2 = src * src * cfg.period as ValueType;
-
-			Ok(Self::Instance {
-				window: Window::new(cfg.period, src),
-				period: period as ValueType,
-				inverted_period,
-				sx: sx as ValueType,
-				sy2,
-				k,
-				wma: WMA::new(cfg.period, &src)?,
-				cross_under: CrossUnder::new((), &(0.0, cfg.zone))?,
-				cross_above: CrossAbove::new((), &(0.0, -cfg.zone))?,
-				reverse: ReversalSignal::new(1, 2, &0.0)?,
-				sy,
-
-				cfg,
-			})
-		} else {
-			Err(Error::WrongConfig)
-		}
+		self.validate_detailed()?;
+
+		let cfg = self;
+
+		let inverted_period = (cfg.period as ValueType).recip();
+		let src = candle.source(cfg.source);
+
+		let period = cfg.period as usize;
+		let sx = (period + 1) * period / 2;
+		let sx2 = (sx * (2 * period + 1)) as ValueType / 3.0;
+
+		let inv_sx = ((period + 1) * sx) as ValueType * 0.5;
+		let k = sx2 - inv_sx;
+		let sy = src * cfg.period as ValueType;
+		let sy2 = src * src * cfg.period as ValueType;
+
+		Ok(Self::Instance {
+			window: Window::new(cfg.period, src),
+			period: period as ValueType,
+			inverted_period,
+			sx: sx as ValueType,
+			sy2,
+			k,
+			wma: WMA::new(cfg.period, &src)?,
+			cross_under: CrossUnder::new((), &(0.0, cfg.zone))?,
+			cross_above: CrossAbove::new((), &(0.0, -cfg.zone))?,
+			reverse: ReversalSignal::new(1, 2, &0.0)?,
+			sy,
+
+			cfg,
+		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1
-			&& self.zone >= 0.0
-			&& self.zone < 1.0
-			&& self.reverse_offset > 0
-			&& self.reverse_offset <= self.period
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period > 1, "period", self.period, "must be > 1")?;
+		Error::ensure(
+			self.zone >= 0.0 && self.zone < 1.0,
+			"zone",
+			self.zone,
+			"must be in [0; 1)",
+		)?;
+		Error::ensure(
+			self.reverse_offset > 0,
+			"reverse_offset",
+			self.reverse_offset,
+			"must be > 0",
+		)?;
+		Error::ensure(
+			self.reverse_offset <= self.period,
+			"reverse_offset",
+			self.reverse_offset,
+			format_args!("must be <= period {}", self.period),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -122,6 +159,17 @@ impl IndicatorConfig for TrendStrengthIndex {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period" => Ok(self.period.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"reverse_offset" => Ok(self.reverse_offset.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(1, 2)
 	}
@@ -174,10 +222,11 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 		let sma = self.inverted_period * self.sy;
 		let p = (self.wma.next(&src) - sma) * self.sx;
 
-		// sy2 is always greater than sma * sy, so q is always positive
+		// sy2 is always greater than sma * sy, so q is always positive unless the window is flat
 		let q = self.k * sma.mul_add(-self.sy, self.sy2);
 
-		let value = p / q.sqrt();
+		// there is no trend in the flat window
+		let value = if q > 0.0 { p / q.sqrt() } else { 0.0 };
 
 		let cross_signal = self.cross_under.next(&(value, self.cfg.zone))
 			- self.cross_above.next(&(value, -self.cfg.zone));
@@ -189,4 +238,8 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 
 		IndicatorResult::new(&[value], &[cross_signal, reverse_signal.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.wma.warmup_len()
+	}
 }
@@ -1,6 +1,6 @@
 use crate::core::{
-	Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Method, MovingAverageConstructor,
-	PeriodType, Source, OHLCV,
+	Error, IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, Method,
+	MovingAverageConstructor, OutputDescriptor, ParamDescriptor, PeriodType, Seed, Source, OHLCV,
 };
 use crate::helpers::MA;
 use crate::methods::{Change, Cross, ReversalSignal, TMA};
@@ -54,6 +54,12 @@ pub struct Trix<M: MovingAverageConstructor = MA> {
 
 	/// Source type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Initialization policy of moving averages. Default is [`Initial`](crate::core::Seed::Initial).
+	///
+	/// Use [`SMA`](crate::core::Seed::SMA) to reconcile values with TA-Lib and Pine Script.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub seed: Seed,
 }
 
 impl<M: MovingAverageConstructor> IndicatorConfig for Trix<M> {
@@ -61,27 +67,56 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Trix<M> {
 
 	const NAME: &'static str = "Trix";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("main"),
+			OutputDescriptor::unbounded("signal"),
+		],
+		signals: &[
+			OutputDescriptor::signal("reverse"),
+			OutputDescriptor::signal("signal_cross"),
+			OutputDescriptor::signal("zero_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "18", 3),
+		ParamDescriptor::moving_average("signal", "ema-6", 2),
+		ParamDescriptor::source("source", "close"),
+		ParamDescriptor::seed("seed"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if self.validate() {
-			let src = candle.source(self.source);
-
-			Ok(Self::Instance {
-				tma: TMA::new(self.period1, &src)?,
-				sig: self.signal.init(src)?,
-				change: Change::new(1, &src)?,
-				cross1: Cross::new((), &(src, src))?,
-				cross2: Cross::new((), &(src, src))?,
-				reverse: ReversalSignal::new(1, 1, &0.0)?,
-
-				cfg: self,
-			})
-		} else {
-			Err(Error::WrongConfig)
-		}
+		self.validate_detailed()?;
+
+		let src = candle.source(self.source);
+
+		Ok(Self::Instance {
+			tma: TMA::new_seeded(self.period1, self.seed, &src)?,
+			sig: self.signal.init_seeded(src, self.seed)?,
+			change: Change::new(1, &src)?,
+			cross1: Cross::new((), &(src, src))?,
+			cross2: Cross::new((), &(src, src))?,
+			reverse: ReversalSignal::new(1, 1, &0.0)?,
+
+			cfg: self,
+		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 2 && self.signal.ma_period() > 1
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period1 > 2, "period1", self.period1, "must be > 2")?;
+		Error::ensure(
+			self.signal.ma_period() > 1,
+			"signal period",
+			self.signal.ma_period(),
+			"must be > 1",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -98,6 +133,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Trix<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"seed" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.seed = value,
+			},
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
 			}
@@ -106,6 +145,17 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Trix<M> {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"signal" => Ok(self.signal.to_string()),
+			"source" => Ok(self.source.to_string()),
+			"seed" => Ok(self.seed.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -117,6 +167,7 @@ impl Default for Trix {
 			period1: 18,
 			signal: MA::EMA(6),
 			source: Source::Close,
+			seed: Seed::Initial,
 		}
 	}
 }
@@ -156,4 +207,8 @@ impl<M: MovingAverageConstructor> IndicatorInstance for TRIXInstance<M> {
 
 		IndicatorResult::new(&[value, sigline], &[signal1, signal2, signal3])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.tma.warmup_len() + self.change.warmup_len() + self.sig.warmup_len()
+	}
 }
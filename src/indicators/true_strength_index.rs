@@ -2,8 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::methods::{Cross, CrossAbove, CrossUnder, EMA, TSI};
+use std::ops::Bound;
 
 /// True Strength Index
 ///
@@ -70,10 +74,28 @@ impl IndicatorConfig for TrueStrengthIndex {
 
 	const NAME: &'static str = "TrueStrengthIndex";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::oscillator("main", -1.0, 1.0),
+			OutputDescriptor::oscillator("signal", -1.0, 1.0),
+		],
+		signals: &[
+			OutputDescriptor::signal("zone"),
+			OutputDescriptor::signal("zero_cross"),
+			OutputDescriptor::signal("signal_cross"),
+		],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "25", 2),
+		ParamDescriptor::period("period2", "13", 2),
+		ParamDescriptor::period("period3", "13", 2),
+		ParamDescriptor::value("zone", "0.25", Bound::Included(0.0), Bound::Included(1.0)),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
@@ -90,13 +112,38 @@ impl IndicatorConfig for TrueStrengthIndex {
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 1
-			&& self.period2 <= self.period1
-			&& self.period1 < PeriodType::MAX
-			&& self.period3 > 1
-			&& self.period3 < PeriodType::MAX
-			&& self.zone >= 0.
-			&& self.zone <= 1.
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(self.period2 > 1, "period2", self.period2, "must be > 1")?;
+		Error::ensure(
+			self.period2 <= self.period1,
+			"period2",
+			self.period2,
+			format_args!("must be <= period1 {}", self.period1),
+		)?;
+		Error::ensure(
+			self.period1 < PeriodType::MAX,
+			"period1",
+			self.period1,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(self.period3 > 1, "period3", self.period3, "must be > 1")?;
+		Error::ensure(
+			self.period3 < PeriodType::MAX,
+			"period3",
+			self.period3,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			(0.0..=1.0).contains(&self.zone),
+			"zone",
+			self.zone,
+			"must be in [0; 1]",
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -130,6 +177,18 @@ impl IndicatorConfig for TrueStrengthIndex {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"period3" => Ok(self.period3.to_string()),
+			"zone" => Ok(self.zone.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 3)
 	}
@@ -181,4 +240,8 @@ impl IndicatorInstance for TrueStrengthIndexInstance {
 
 		IndicatorResult::new(&[tsi, sig], &[s1, s2, s3])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.tsi.warmup_len() + self.ema.warmup_len()
+	}
 }
@@ -2,7 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorOutputs, IndicatorResult, OutputDescriptor,
+	ParamDescriptor,
+};
 use crate::helpers::signi;
 use crate::methods::{Cross, CCI};
 
@@ -50,10 +53,23 @@ impl IndicatorConfig for WoodiesCCI {
 
 	const NAME: &'static str = "WoodiesCCI";
 
+	const OUTPUTS: IndicatorOutputs = IndicatorOutputs {
+		values: &[
+			OutputDescriptor::unbounded("turbo"),
+			OutputDescriptor::unbounded("trend"),
+		],
+		signals: &[OutputDescriptor::signal("trend")],
+	};
+
+	const PARAMS: &'static [ParamDescriptor] = &[
+		ParamDescriptor::period("period1", "6", 1),
+		ParamDescriptor::period("period2", "14", 2),
+		ParamDescriptor::period("s1_lag", "6", 1),
+		ParamDescriptor::source("source", "close"),
+	];
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
-		if !self.validate() {
-			return Err(Error::WrongConfig);
-		}
+		self.validate_detailed()?;
 
 		let cfg = self;
 		let src = &candle.source(cfg.source);
@@ -68,10 +84,31 @@ impl IndicatorConfig for WoodiesCCI {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 < self.period2
-			&& self.s1_lag > 0
-			&& self.period2 < PeriodType::MAX
-			&& self.s1_lag < PeriodType::MAX
+		self.validate_detailed().is_ok()
+	}
+
+	fn validate_detailed(&self) -> Result<(), Error> {
+		Error::ensure(
+			self.period1 < self.period2,
+			"period1",
+			self.period1,
+			format_args!("must be < period2 {}", self.period2),
+		)?;
+		Error::ensure(self.s1_lag > 0, "s1_lag", self.s1_lag, "must be > 0")?;
+		Error::ensure(
+			self.period2 < PeriodType::MAX,
+			"period2",
+			self.period2,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+		Error::ensure(
+			self.s1_lag < PeriodType::MAX,
+			"s1_lag",
+			self.s1_lag,
+			format_args!("must be < {}", PeriodType::MAX),
+		)?;
+
+		Ok(())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -101,6 +138,17 @@ impl IndicatorConfig for WoodiesCCI {
 		Ok(())
 	}
 
+	fn get(&self, name: &str) -> Result<String, Error> {
+		match name {
+			"period1" => Ok(self.period1.to_string()),
+			"period2" => Ok(self.period2.to_string()),
+			"s1_lag" => Ok(self.s1_lag.to_string()),
+			"source" => Ok(self.source.to_string()),
+
+			_ => Err(Error::UnknownParameter(name.to_string())),
+		}
+	}
+
 	fn size(&self) -> (u8, u8) {
 		(2, 1)
 	}
@@ -154,4 +202,8 @@ impl IndicatorInstance for WoodiesCCIInstance {
 
 		IndicatorResult::new(&[turbo, trend], &[s1.into()])
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.turbo.warmup_len().max(self.trend.warmup_len())
+	}
 }
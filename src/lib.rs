@@ -68,7 +68,7 @@ limitations under the License.
 //!
 //! ## Timeseries conversion
 //!
-//! - [Timeframe Collapsing](crate::methods::CollapseTimeframe);
+//! - [Timeframe Collapsing](crate::methods::CollapseTimeframe) / [by the wall-clock time](crate::methods::CollapseTimeframeByTime);
 //! - [Heikin Ashi](crate::methods::HeikinAshi);
 //! - [Renko](crate::methods::Renko);
 //!
@@ -187,7 +187,8 @@ compile_error!("Features `period_type_u64` and `period_type_u32` can't be used o
 /// Contains main traits you need to start using this library
 pub mod prelude {
 	pub use super::core::{
-		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, OHLCV,
+		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, Timestamped, OHLCV,
+		OHLCVT,
 	};
 
 	pub use super::helpers::{Buffered, Peekable};
@@ -117,6 +117,10 @@ impl Method for ADI {
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for ADI {
@@ -0,0 +1,190 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::Conv;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Arnaud Legoux Moving Average](https://www.tradingview.com/support/solutions/43000594683-arnaud-legoux-moving-average/) of specified `length` for timeseries of type [`ValueType`]
+///
+/// Weights the values by the Gaussian curve, which peak is shifted towards the newest values by `offset`.
+/// The closer `offset` is to `1.0`, the more responsive and the less smooth the average is.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `offset`: [`ValueType`], `sigma`: [`ValueType`]\)
+///
+/// `length` should be > `0`
+///
+/// `offset` should be in \[`0.0`; `1.0`\]. `0.85` is commonly used.
+///
+/// `sigma` should be > `0.0`. It is the count of the standard deviations of the curve over the `length`, so the higher
+/// is the `sigma`, the narrower is the curve. `6.0` is commonly used.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ALMA;
+///
+/// let mut alma = ALMA::new((9, 0.85, 6.0), &1.0).unwrap();
+///
+/// assert!((alma.next(&1.0) - 1.0).abs() < 1e-9);
+/// // the newest values have the highest weights, while SMA of the same length gives ~1.11 here
+/// assert!(alma.next(&2.0) > 1.2);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Conv::gaussian`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[doc(alias = "ArnaudLegoux")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ALMA(Conv);
+
+impl Method for ALMA {
+	type Params = (PeriodType, ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, offset, sigma): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+		Error::ensure(
+			(0.0..=1.0).contains(&offset),
+			"offset",
+			offset,
+			"must be in [0.0; 1.0]",
+		)?;
+		Error::ensure(
+			sigma > 0.0 && sigma.is_finite(),
+			"sigma",
+			sigma,
+			"must be a finite number > 0.0",
+		)?;
+
+		#[allow(clippy::useless_conversion)] // when `ValueType` is `f32`
+		let (offset, sigma) = (f64::from(offset), f64::from(sigma));
+		let center = offset * (length as f64 - 1.0);
+		let deviation = length as f64 / sigma;
+
+		#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+		let weights = (0..length)
+			.map(|i| {
+				let x = (i as f64 - center) / deviation;
+				(-0.5 * x * x).exp() as ValueType
+			})
+			.collect();
+
+		Ok(Self(Conv::new(weights, value)?))
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl MovingAverage for ALMA {}
+
+impl Peekable<<Self as Method>::Output> for ALMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, ALMA as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const_float, test_warmup};
+
+	#[test]
+	fn test_alma_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 0.85, 6.0), &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+		}
+	}
+
+	#[test]
+	fn test_alma1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new((1, 0.85, 6.0), &candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(&x.close));
+		});
+	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_alma() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for (offset, sigma) in [(0.85, 6.0), (0.5, 3.0), (0.0, 1.0), (1.0, 10.0)] {
+			(1..100).for_each(|length| {
+				let mut ma = TestingMethod::new((length, offset, sigma), &src[0]).unwrap();
+				let length = length as usize;
+
+				let m = offset * (length - 1) as ValueType;
+				let s = length as ValueType / sigma;
+				let weights: Vec<ValueType> = (0..length)
+					.map(|i| (-((i as ValueType - m).powi(2)) / (2.0 * s * s)).exp())
+					.collect();
+				let norm: ValueType = weights.iter().sum();
+
+				src.iter().enumerate().for_each(|(i, &x)| {
+					let value = weights
+						.iter()
+						.enumerate()
+						.map(|(j, &w)| w * src[(i + 1 + j).saturating_sub(length)])
+						.sum::<ValueType>()
+						/ norm;
+
+					assert_eq_float(value, ma.next(&x));
+				});
+			});
+		}
+	}
+
+	#[test]
+	fn test_alma_warmup() {
+		for length in [1, 5, 9, 20] {
+			test_warmup::<TestingMethod>((length, 0.85, 6.0));
+		}
+	}
+
+	#[test]
+	fn test_alma_parameters() {
+		assert!(TestingMethod::new((0, 0.85, 6.0), &1.0).is_err());
+		assert!(TestingMethod::new((9, -0.1, 6.0), &1.0).is_err());
+		assert!(TestingMethod::new((9, 1.1, 6.0), &1.0).is_err());
+		assert!(TestingMethod::new((9, 0.85, 0.0), &1.0).is_err());
+		assert!(TestingMethod::new((9, 0.85, ValueType::INFINITY), &1.0).is_err());
+	}
+}
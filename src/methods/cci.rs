@@ -42,7 +42,7 @@ impl Method for CCI {
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self(MeanAbsDev::new(length, value)?)),
 		}
 	}
@@ -58,6 +58,10 @@ impl Method for CCI {
 			0.
 		}
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+use std::marker::PhantomData;
+use std::mem::replace;
 use std::ops::Add;
 
-use crate::core::{Candle, Error, Method, OHLCV};
+use crate::core::{Candle, Error, Method, TimedCandle, Timestamp, OHLCV, OHLCVT};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -51,6 +53,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// See also Sequence's [`collapse_timeframe`](crate::core::Sequence::collapse_timeframe) function.
 ///
+/// If your candles have timestamps, you may want to use [`CollapseTimeframeByTime`] instead.
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`Candle`]: crate::core::Candle
 #[derive(Debug, Clone)]
@@ -73,9 +77,7 @@ where
 	type Output = Option<T>;
 
 	fn new(period: Self::Params, _candle: &Self::Input) -> Result<Self, Error> {
-		if period == 0 {
-			return Err(Error::WrongMethodParameters);
-		}
+		Error::ensure(period > 0, "period", period, "must be > 0")?;
 
 		Ok(Self {
 			current: None,
@@ -102,11 +104,250 @@ where
 	}
 }
 
+/// Parameters of wall-clock based timeframe collapsing for [`CollapseTimeframeByTime`].
+///
+/// Every bucket starts at `origin + k * interval` in the local time, where local time is `timestamp + tz_offset`.
+/// All the values must be in the same units as candles' [`Timestamp`]s.
+///
+/// # Examples
+///
+/// ```
+/// use yata::methods::TimeBuckets;
+///
+/// // 15 minutes buckets for timestamps in seconds
+/// let buckets = TimeBuckets::new(15 * 60);
+/// assert_eq!(buckets.bucket_start(1_000), 900);
+///
+/// // daily buckets for timestamps in seconds in UTC+3 timezone
+/// let buckets = TimeBuckets {
+///     tz_offset: 3 * 3600,
+///     ..TimeBuckets::new(86_400)
+/// };
+/// // 1970-01-02 22:00:00 UTC is 1970-01-03 01:00:00 in UTC+3, so the bucket starts at 1970-01-02 21:00:00 UTC
+/// assert_eq!(buckets.bucket_start(86_400 + 22 * 3600), 86_400 + 21 * 3600);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeBuckets {
+	/// Size of every bucket.
+	///
+	/// Must be > `0`.
+	pub interval: Timestamp,
+
+	/// Start of any bucket in the local time. Default is `0`.
+	///
+	/// F.e. you can shift hourly buckets to start at `hh:30` by setting `origin` to 30 minutes.
+	pub origin: Timestamp,
+
+	/// Timezone offset, which is added to every timestamp to get the local time. Default is `0`.
+	pub tz_offset: Timestamp,
+
+	/// Whether to emit partial buckets or not. Default is `false`.
+	///
+	/// The bucket is partial, when the series starts in the middle of it, or when it is the last bucket of the series, which is never closed.
+	pub partial: bool,
+}
+
+impl TimeBuckets {
+	/// Creates new `TimeBuckets` of size `interval` with default `origin`, `tz_offset` and `partial` values.
+	#[must_use]
+	pub const fn new(interval: Timestamp) -> Self {
+		Self {
+			interval,
+			origin: 0,
+			tz_offset: 0,
+			partial: false,
+		}
+	}
+
+	/// Returns `true` if parameters are OK
+	#[must_use]
+	pub const fn validate(&self) -> bool {
+		self.interval > 0
+	}
+
+	/// Returns start timestamp of the bucket, which `timestamp` belongs to.
+	///
+	/// Works for the whole range of [`Timestamp`]. If the bucket starts before [`Timestamp::MIN`], returns [`Timestamp::MIN`].
+	///
+	/// # Panics
+	///
+	/// Panics if `interval` is equal to `0`.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn bucket_start(&self, timestamp: Timestamp) -> Timestamp {
+		// calculate in the wider type, so timestamps close to the bounds do not overflow
+		let shift = self.tz_offset as i128 - self.origin as i128;
+		let local = timestamp as i128 + shift;
+		let start = local - local.rem_euclid(self.interval as i128) - shift;
+
+		if start < Timestamp::MIN as i128 {
+			Timestamp::MIN
+		} else {
+			start as Timestamp
+		}
+	}
+}
+
+/// Converting between different timeframes by the wall-clock time.
+///
+/// Unlike [`CollapseTimeframe`], which groups fixed count of input bars, this method groups candles
+/// by time buckets, so missing bars or shortened sessions do not shift the buckets.
+///
+/// # Parameters
+///
+/// Has a single parameter [`TimeBuckets`]
+///
+/// `interval` must be > `0`
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCVT`]
+///
+/// Input candles must be ordered by time.
+///
+/// # Output type
+///
+/// Output type is [`Option`]<[`TimedCandle`]>
+///
+/// The bucket is returned, when the first candle of the next bucket comes in.
+/// Timestamp of the returned candle is the bucket's start timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::TimedCandle;
+/// use yata::methods::{CollapseTimeframeByTime, TimeBuckets};
+///
+/// let timeframe: [TimedCandle; 4] = [
+/// //   time open  high  low  close volume
+///     (60, 10.0, 15.0, 5.0, 12.0, 1000.0).into(),
+///     (120, 12.1, 17.0, 6.0, 13.0, 2000.0).into(),
+///     // minute 180 is missing
+///     (240, 13.0, 14.0, 12.0, 13.5, 500.0).into(),
+///     (300, 13.5, 14.5, 13.0, 14.0, 700.0).into(),
+/// ];
+///
+/// let buckets = TimeBuckets {
+///     origin: 60,
+///     ..TimeBuckets::new(180)
+/// };
+/// let mut collapser = CollapseTimeframeByTime::new(buckets, &timeframe[0]).unwrap();
+///
+/// assert_eq!(collapser.next(&timeframe[0]), None);
+/// assert_eq!(collapser.next(&timeframe[1]), None);
+///
+/// let collapsed = collapser.next(&timeframe[2]).unwrap();
+/// assert_eq!(collapsed.timestamp(), 60);
+/// assert_eq!(collapsed.open(), 10.0);
+/// assert_eq!(collapsed.high(), 17.0);
+/// assert_eq!(collapsed.low(), 5.0);
+/// assert_eq!(collapsed.close(), 13.0);
+/// assert_eq!(collapsed.volume(), 3000.0);
+///
+/// assert_eq!(collapser.next(&timeframe[3]), None);
+///
+/// // the last bucket is not closed yet
+/// let current = collapser.flush().unwrap();
+/// assert_eq!(current.timestamp(), 240);
+/// assert_eq!(current.volume(), 1200.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// See also Sequence's [`collapse_timeframe_by_time`](crate::core::Sequence::collapse_timeframe_by_time) function.
+///
+/// [`OHLCVT`]: crate::core::OHLCVT
+/// [`TimedCandle`]: crate::core::TimedCandle
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CollapseTimeframeByTime<T = TimedCandle>
+where
+	T: OHLCVT,
+{
+	buckets: TimeBuckets,
+	current: Option<TimedCandle>,
+	is_started: bool,
+	is_partial: bool,
+	phantom: PhantomData<T>,
+}
+
+impl<T: OHLCVT> CollapseTimeframeByTime<T> {
+	/// Returns a reference to the current (not closed yet) bucket
+	#[must_use]
+	pub const fn current(&self) -> Option<&TimedCandle> {
+		self.current.as_ref()
+	}
+
+	/// Takes the current (not closed yet) bucket out of the method.
+	///
+	/// Useful when the series is over and you need the last bucket even if it is not complete.
+	///
+	/// When [`partial`](TimeBuckets::partial) buckets are disabled, the very first bucket, which is known to be partial,
+	/// is dropped and `None` is returned, the same way as [`next`](Method::next) does.
+	pub fn flush(&mut self) -> Option<TimedCandle> {
+		let is_partial = replace(&mut self.is_partial, false);
+
+		self.current
+			.take()
+			.filter(|_| !is_partial || self.buckets.partial)
+	}
+}
+
+impl<T: OHLCVT> Method for CollapseTimeframeByTime<T> {
+	type Params = TimeBuckets;
+	type Input = T;
+	type Output = Option<TimedCandle>;
+
+	fn new(buckets: Self::Params, _candle: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			buckets.validate(),
+			"interval",
+			buckets.interval,
+			"must be > 0",
+		)?;
+
+		Ok(Self {
+			buckets,
+			current: None,
+			is_started: false,
+			is_partial: false,
+			phantom: PhantomData,
+		})
+	}
+
+	fn next(&mut self, candle: &Self::Input) -> Self::Output {
+		let timestamp = candle.timestamp();
+		let start = self.buckets.bucket_start(timestamp);
+
+		match self.current.as_mut() {
+			Some(current) if current.timestamp == start => {
+				current.candle = current.candle + Candle::from(candle);
+				None
+			}
+			_ => {
+				// only the very first bucket may be known to be partial, when the series starts in the middle of it
+				let is_partial =
+					replace(&mut self.is_partial, !self.is_started && timestamp != start);
+				self.is_started = true;
+
+				self.current
+					.replace(TimedCandle::new(start, candle))
+					.filter(|_| !is_partial || self.buckets.partial)
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Candle, CollapseTimeframe as TestingMethod, Method, OHLCV};
-	use crate::core::ValueType;
-	use crate::helpers::{assert_eq_float, RandomCandles};
+	use super::{CollapseTimeframeByTime, TimeBuckets};
+	use crate::core::{Sequence, TimedCandle, Timestamp, Timestamped, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles, RandomTimedCandles};
 
 	#[test]
 	fn test_timeframe_collapse() {
@@ -166,9 +407,157 @@ mod tests {
 	}
 
 	#[test]
-	#[should_panic(expected = "WrongMethodParameters")]
+	#[should_panic(expected = "InvalidParameter")]
 	fn test_timeframe_collapse_fail() {
 		let candles = RandomCandles::new().take(1).collect::<Vec<_>>();
 		TestingMethod::new(0, &candles[0]).unwrap();
 	}
+
+	fn collapse_naive(candles: &[TimedCandle], interval: i64) -> Vec<TimedCandle> {
+		let mut result: Vec<TimedCandle> = Vec::new();
+
+		for candle in candles {
+			let start = candle.timestamp - candle.timestamp.rem_euclid(interval);
+
+			match result.last_mut() {
+				Some(last) if last.timestamp == start => {
+					last.candle = Candle {
+						high: last.candle.high.max(candle.high()),
+						low: last.candle.low.min(candle.low()),
+						close: candle.close(),
+						volume: last.candle.volume + candle.volume(),
+						..last.candle
+					};
+				}
+				_ => result.push(TimedCandle::new(start, candle)),
+			}
+		}
+
+		result
+	}
+
+	#[test]
+	fn test_timeframe_collapse_by_time() {
+		// 1 minute candles with some missing minutes
+		let candles: Vec<_> = RandomTimedCandles::new(0, 60)
+			.take(1000)
+			.enumerate()
+			.filter(|(i, _)| i % 7 != 3 && i % 13 != 5)
+			.map(|(_, x)| x)
+			.collect();
+
+		for &interval in &[60, 120, 300, 900, 3600, 7200] {
+			let mut method =
+				CollapseTimeframeByTime::new(TimeBuckets::new(interval), &candles[0]).unwrap();
+			let converted: Vec<_> = candles.iter().filter_map(|x| method.next(x)).collect();
+
+			let mut expected = collapse_naive(&candles, interval);
+			let last = expected.pop().unwrap();
+
+			assert_eq!(converted.len(), expected.len());
+
+			expected.iter().zip(converted.iter()).for_each(|(a, b)| {
+				assert_eq!(a.timestamp(), b.timestamp());
+				assert_eq_float(a.open(), b.open());
+				assert_eq_float(a.high(), b.high());
+				assert_eq_float(a.low(), b.low());
+				assert_eq_float(a.close(), b.close());
+				assert_eq_float(a.volume(), b.volume());
+			});
+
+			let current = method.flush().unwrap();
+			assert_eq!(last.timestamp(), current.timestamp());
+			assert_eq_float(last.volume(), current.volume());
+
+			assert_eq!(
+				converted,
+				candles
+					.collapse_timeframe_by_time(TimeBuckets::new(interval))
+					.unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_timeframe_collapse_by_time_partial() {
+		// series starts in the middle of the 15 minutes bucket
+		let candles: Vec<_> = RandomTimedCandles::new(5 * 60, 60).take(100).collect();
+		let buckets = TimeBuckets::new(15 * 60);
+
+		let full = candles.collapse_timeframe_by_time(buckets).unwrap();
+		let partial = candles
+			.collapse_timeframe_by_time(TimeBuckets {
+				partial: true,
+				..buckets
+			})
+			.unwrap();
+
+		assert_eq!(full.len() + 2, partial.len());
+		assert_eq!(partial[0].timestamp(), 0);
+		assert_eq!(full[0].timestamp(), 15 * 60);
+		assert_eq!(&partial[1..partial.len() - 1], full.as_slice());
+		assert_eq!(partial.last().unwrap().timestamp(), 90 * 60);
+	}
+
+	#[test]
+	fn test_timeframe_collapse_by_time_flush_partial() {
+		// all the candles are in the middle of the first 15 minutes bucket
+		let candles: Vec<_> = RandomTimedCandles::new(5 * 60, 60).take(3).collect();
+
+		for partial in [false, true] {
+			let buckets = TimeBuckets {
+				partial,
+				..TimeBuckets::new(15 * 60)
+			};
+			let mut method = CollapseTimeframeByTime::new(buckets, &candles[0]).unwrap();
+
+			assert!(candles.iter().all(|x| method.next(x).is_none()));
+			assert_eq!(method.flush().is_some(), partial);
+			assert_eq!(method.flush(), None);
+		}
+	}
+
+	#[test]
+	fn test_timeframe_collapse_by_time_offset() {
+		let candles: Vec<_> = RandomTimedCandles::new(0, 60).take(1000).collect();
+
+		let buckets = TimeBuckets {
+			origin: 30 * 60,
+			tz_offset: 3 * 3600,
+			..TimeBuckets::new(3600)
+		};
+
+		let converted = candles.collapse_timeframe_by_time(buckets).unwrap();
+
+		// the first bucket starts at 00:30 UTC: 03:30 in local time
+		assert_eq!(converted[0].timestamp(), 30 * 60);
+		assert_eq_float(converted[0].open(), candles[30].open());
+
+		converted
+			.windows(2)
+			.for_each(|w| assert_eq!(w[1].timestamp() - w[0].timestamp(), 3600));
+	}
+
+	#[test]
+	fn test_time_buckets_bounds() {
+		let buckets = TimeBuckets {
+			origin: 30,
+			tz_offset: 3600,
+			..TimeBuckets::new(86_400)
+		};
+
+		let start = buckets.bucket_start(Timestamp::MAX);
+		assert!(Timestamp::MAX - start < 86_400);
+		assert_eq!(buckets.bucket_start(start), start);
+
+		assert_eq!(buckets.bucket_start(Timestamp::MIN), Timestamp::MIN);
+		assert_eq!(buckets.bucket_start(-1), -3600 + 30);
+	}
+
+	#[test]
+	#[should_panic(expected = "InvalidParameter")]
+	fn test_timeframe_collapse_by_time_fail() {
+		let candles: Vec<_> = RandomTimedCandles::new(0, 60).take(1).collect();
+		CollapseTimeframeByTime::new(TimeBuckets::new(0), &candles[0]).unwrap();
+	}
 }
@@ -27,9 +27,33 @@ use serde::{Deserialize, Serialize};
 ///
 /// This method is relatively slow compare to the other methods.
 ///
+/// # Presets
+///
+/// There are constructors, which design the `weights` for the most common filters:
+///
+/// * [`Conv::gaussian`] and [`Conv::lanczos`] smoothing kernels;
+/// * [`Conv::low_pass`], [`Conv::high_pass`] and [`Conv::band_pass`] [windowed-sinc](https://en.wikipedia.org/wiki/Sinc_filter) filters
+///   with the [`WindowFunction`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Conv, WindowFunction};
+///
+/// // removes the cycles shorter than 10 bars
+/// let mut low_pass = Conv::low_pass(41, 10.0, WindowFunction::Blackman, &1.0).unwrap();
+/// // removes the trend and the cycles longer than 10 bars
+/// let mut high_pass = Conv::high_pass(41, 10.0, WindowFunction::Blackman, &1.0).unwrap();
+///
+/// // constant value is a trend only
+/// assert!((low_pass.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(high_pass.next(&1.0).abs() < 1e-9);
+/// ```
+///
 /// # See also
 ///
-/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA)
+/// [`WMA`](crate::methods::WMA), [`SWMA`](crate::methods::SWMA), [`SavitzkyGolay`](crate::methods::SavitzkyGolay)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
@@ -42,6 +66,155 @@ pub struct Conv {
 	wsum_invert: ValueType,
 }
 
+impl Conv {
+	/// Creates convolution with `weights`, which are applied as is, without normalizing by their sum
+	///
+	/// `weights` go from the oldest value to the newest one.
+	/// Useful for the kernels, which sum is not `1.0`, like the derivative filters.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `weights` length is not in \[`1`; [`PeriodType::MAX`]\]
+	pub fn new_raw(weights: Vec<ValueType>, value: &ValueType) -> Result<Self, Error> {
+		let mut conv = Self::new(weights, value)?;
+		conv.wsum_invert = 1.0;
+
+		Ok(conv)
+	}
+
+	/// Creates [Gaussian](https://en.wikipedia.org/wiki/Gaussian_filter) smoothing filter over the window of size `length`
+	///
+	/// Kernel is centered in the middle of the window and `sigma` is its standard deviation in bars.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `length` is `0` or `sigma` is not a finite number > `0.0`
+	pub fn gaussian(
+		length: PeriodType,
+		sigma: ValueType,
+		value: &ValueType,
+	) -> Result<Self, Error> {
+		validate_length(length)?;
+		Error::ensure(
+			sigma > 0.0 && sigma.is_finite(),
+			"sigma",
+			sigma,
+			"must be a finite number > 0.0",
+		)?;
+
+		#[allow(clippy::useless_conversion)] // when `ValueType` is `f32`
+		let sigma = f64::from(sigma);
+		let weights = kernel(length, |_, x| (-0.5 * (x / sigma).powi(2)).exp());
+
+		Self::new(normalize(weights), value)
+	}
+
+	/// Creates [Lanczos](https://en.wikipedia.org/wiki/Lanczos_resampling) smoothing filter over the window of size `length`
+	///
+	/// Kernel sinc(x) * sinc(x / `lobes`) spans \(-`lobes`; `lobes`\) over the window. `2` or `3` `lobes` are commonly used.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `length` or `lobes` is `0`
+	pub fn lanczos(length: PeriodType, lobes: u8, value: &ValueType) -> Result<Self, Error> {
+		validate_length(length)?;
+		Error::ensure(lobes > 0, "lobes", lobes, "must be > 0")?;
+
+		let lobes = f64::from(lobes);
+		let scale = 2.0 * lobes / (length as f64 + 1.0);
+		let weights = kernel(length, |_, x| sinc(x * scale) * sinc(x * scale / lobes));
+
+		Self::new(normalize(weights), value)
+	}
+
+	/// Creates windowed-sinc low-pass filter over the window of size `length`, which removes the cycles shorter than `period` bars
+	///
+	/// The longer is the window, the sharper is the cutoff.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `length` is `0` or `period` is not a finite number >= `2.0`
+	pub fn low_pass(
+		length: PeriodType,
+		period: ValueType,
+		window: WindowFunction,
+		value: &ValueType,
+	) -> Result<Self, Error> {
+		validate_length(length)?;
+		validate_period("period", period)?;
+
+		Self::new(low_pass(length, period, window), value)
+	}
+
+	/// Creates windowed-sinc high-pass filter over the window of size `length`, which removes the trend and the cycles longer than `period` bars
+	///
+	/// Filter has a zero gain for the constant values, so it oscillates around `0.0`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `length` is not odd or `period` is not a finite number >= `2.0`
+	pub fn high_pass(
+		length: PeriodType,
+		period: ValueType,
+		window: WindowFunction,
+		value: &ValueType,
+	) -> Result<Self, Error> {
+		Error::ensure(length % 2 == 1, "length", length, "must be odd")?;
+		validate_period("period", period)?;
+
+		// spectral inversion of the low-pass filter
+		let mut weights = low_pass(length, period, window);
+		for w in &mut weights {
+			*w = -*w;
+		}
+		weights[length as usize / 2] += 1.0;
+
+		Self::new_raw(weights, value)
+	}
+
+	/// Creates windowed-sinc band-pass filter over the window of size `length`, which keeps only the cycles from `short_period` to `long_period` bars
+	///
+	/// Filter has a zero gain for the constant values, so it oscillates around `0.0`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `length` is `0`, `short_period` is not a finite number >= `2.0`
+	/// or `long_period` is not a finite number > `short_period`
+	pub fn band_pass(
+		length: PeriodType,
+		short_period: ValueType,
+		long_period: ValueType,
+		window: WindowFunction,
+		value: &ValueType,
+	) -> Result<Self, Error> {
+		validate_length(length)?;
+		validate_period("short_period", short_period)?;
+		validate_period("long_period", long_period)?;
+		Error::ensure(
+			long_period > short_period,
+			"long_period",
+			long_period,
+			format_args!("must be > short_period {short_period}"),
+		)?;
+
+		let weights = low_pass(length, short_period, window)
+			.into_iter()
+			.zip(low_pass(length, long_period, window))
+			.map(|(short, long)| short - long)
+			.collect();
+
+		Self::new_raw(weights, value)
+	}
+
+	/// Returns weights of the convolution (from the oldest value to the newest)
+	///
+	/// Weights are returned as they were given, so for [`Conv::new`] the output is still divided by their sum.
+	#[must_use]
+	pub fn weights(&self) -> &[ValueType] {
+		&self.weights
+	}
+}
+
 impl Method for Conv {
 	type Params = Vec<ValueType>;
 	type Input = ValueType;
@@ -61,7 +234,11 @@ impl Method for Conv {
 					wsum_invert,
 				})
 			}
-			_ => Err(Error::WrongMethodParameters),
+			length => Err(Error::invalid_parameter(
+				"weights length",
+				length,
+				format_args!("must be in [1; {MAX_WEIGHTS_LEN}]"),
+			)),
 		}
 	}
 
@@ -70,6 +247,10 @@ impl Method for Conv {
 		self.window.push(*value);
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for Conv {
@@ -83,12 +264,102 @@ impl Peekable<<Self as Method>::Output> for Conv {
 	}
 }
 
+/// [Window function](https://en.wikipedia.org/wiki/Window_function) for the windowed-sinc filters of [`Conv`]
+///
+/// Window is stretched over the whole kernel without the zero end points.
+/// Wider windows give the lower side lobes at the cost of the wider transition band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum WindowFunction {
+	/// Hamming window: the narrowest transition band with ~43 dB side lobes suppression
+	Hamming,
+
+	/// Hann window: ~44 dB side lobes suppression, which fall off faster than Hamming's
+	Hann,
+
+	/// Blackman window: the widest transition band with ~74 dB side lobes suppression. This is the default window.
+	#[default]
+	Blackman,
+}
+
+impl WindowFunction {
+	fn weight(self, i: usize, length: usize) -> f64 {
+		let phase = 2.0 * std::f64::consts::PI * (i + 1) as f64 / (length + 1) as f64;
+
+		match self {
+			Self::Hamming => 0.46f64.mul_add(-phase.cos(), 0.54),
+			Self::Hann => 0.5f64.mul_add(-phase.cos(), 0.5),
+			Self::Blackman => {
+				0.08f64.mul_add((2.0 * phase).cos(), 0.5f64.mul_add(-phase.cos(), 0.42))
+			}
+		}
+	}
+}
+
+fn validate_length(length: PeriodType) -> Result<(), Error> {
+	Error::ensure(length > 0, "length", length, "must be > 0")
+}
+
+fn validate_period(name: &str, period: ValueType) -> Result<(), Error> {
+	Error::ensure(
+		period >= 2.0 && period.is_finite(),
+		name,
+		period,
+		"must be a finite number >= 2.0",
+	)
+}
+
+/// Normalized sinc function: sin(πx) / (πx)
+fn sinc(x: f64) -> f64 {
+	if x == 0.0 {
+		1.0
+	} else {
+		let x = std::f64::consts::PI * x;
+		x.sin() / x
+	}
+}
+
+/// Returns weights of the `kernel` function of the tap index and its offset from the middle of the window
+#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+fn kernel(length: PeriodType, kernel: impl Fn(usize, f64) -> f64) -> Vec<ValueType> {
+	let center = (length as f64 - 1.0) / 2.0;
+
+	(0..length)
+		.map(|i| kernel(i as usize, i as f64 - center) as ValueType)
+		.collect()
+}
+
+/// Returns weights of the windowed-sinc low-pass filter with the unit gain for the constant values
+fn low_pass(length: PeriodType, period: ValueType, window: WindowFunction) -> Vec<ValueType> {
+	#[allow(clippy::useless_conversion)] // when `ValueType` is `f32`
+	let frequency = 2.0 / f64::from(period);
+	let weights = kernel(length, |i, x| {
+		sinc(x * frequency) * window.weight(i, length as usize)
+	});
+
+	normalize(weights)
+}
+
+/// Returns `weights` divided by their sum
+fn normalize(weights: Vec<ValueType>) -> Vec<ValueType> {
+	let sum: ValueType = weights.iter().sum();
+	weights.into_iter().map(|w| w / sum).collect()
+}
+
 #[cfg(test)]
-#[allow(clippy::suboptimal_flops)]
+#[allow(clippy::suboptimal_flops, trivial_numeric_casts)]
 mod tests {
-	use super::{Conv as TestingMethod, Method};
+	use super::{Conv as TestingMethod, Method, WindowFunction};
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	const WINDOWS: [WindowFunction; 3] = [
+		WindowFunction::Hamming,
+		WindowFunction::Hann,
+		WindowFunction::Blackman,
+	];
 
 	fn get_weights(length: PeriodType) -> Vec<ValueType> {
 		(0..length)
@@ -152,4 +423,151 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_conv_raw() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		// weights sum to zero, so the output is a difference between the newest and the oldest values
+		for length in 2..255 {
+			let mut weights = vec![0.0; length];
+			weights[0] = -1.0;
+			weights[length - 1] = 1.0;
+
+			let mut conv = TestingMethod::new_raw(weights, &src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let expected = x - src[(i + 1).saturating_sub(length)];
+				assert_eq_float(expected, conv.next(x));
+			});
+		}
+	}
+
+	/// Returns amplitude of the filter output for the sine wave of the `period` with the unit amplitude
+	///
+	/// `period` should evenly divide `600`
+	#[allow(clippy::useless_conversion, clippy::cast_possible_truncation)]
+	fn gain(conv: &mut TestingMethod, period: ValueType) -> ValueType {
+		let sine = |i: usize| (2.0 * std::f64::consts::PI * i as f64 / f64::from(period)).sin();
+		let warmup = conv.warmup_len() + 1;
+
+		let squares: ValueType = (0..warmup + 600)
+			.map(|i| conv.next(&(sine(i) as ValueType)))
+			.skip(warmup)
+			.map(|x| x * x)
+			.sum();
+
+		(squares / 300.0).sqrt()
+	}
+
+	fn assert_symmetric(weights: &[ValueType]) {
+		weights
+			.iter()
+			.zip(weights.iter().rev())
+			.for_each(|(&a, &b)| assert_eq_float(a, b));
+	}
+
+	#[test]
+	fn test_conv_gaussian() {
+		for length in 1..255 {
+			let sigma = length as ValueType / 4.0;
+			let mut conv = TestingMethod::gaussian(length, sigma, &5.5).unwrap();
+			let weights = conv.weights().to_vec();
+
+			assert_symmetric(&weights);
+			// weights ratio of the neighbour taps is exp(-((x + 1)² - x²) / 2σ²)
+			let center = (length as ValueType - 1.0) / 2.0;
+			weights.windows(2).enumerate().for_each(|(i, w)| {
+				let x = i as ValueType - center;
+				let ratio = (-(2.0 * x + 1.0) / (2.0 * sigma * sigma)).exp();
+				assert_eq_float(ratio, w[1] / w[0]);
+			});
+
+			test_const_float(&mut conv, &5.5, 5.5);
+		}
+	}
+
+	#[test]
+	fn test_conv_lanczos() {
+		for lobes in 1..=4 {
+			for length in 1..255 {
+				let mut conv = TestingMethod::lanczos(length, lobes, &5.5).unwrap();
+				let weights = conv.weights().to_vec();
+
+				assert_symmetric(&weights);
+				let max = weights.iter().fold(0.0, |max: ValueType, &w| max.max(w));
+				assert_eq_float(max, weights[length as usize / 2]);
+
+				test_const_float(&mut conv, &5.5, 5.5);
+			}
+		}
+	}
+
+	#[test]
+	fn test_conv_low_pass() {
+		for window in WINDOWS {
+			let mut conv = TestingMethod::low_pass(61, 10.0, window, &5.5).unwrap();
+			test_const_float(&mut conv, &5.5, 5.5);
+
+			assert_symmetric(conv.weights());
+			assert!(gain(&mut conv, 4.0) < 0.02);
+			assert!((gain(&mut conv, 60.0) - 1.0).abs() < 0.02);
+		}
+	}
+
+	#[test]
+	fn test_conv_high_pass() {
+		for window in WINDOWS {
+			let mut conv = TestingMethod::high_pass(61, 10.0, window, &5.5).unwrap();
+			test_const_float(&mut conv, &5.5, 0.0);
+
+			assert_symmetric(conv.weights());
+			assert!((gain(&mut conv, 4.0) - 1.0).abs() < 0.02);
+			assert!(gain(&mut conv, 60.0) < 0.02);
+		}
+	}
+
+	#[test]
+	fn test_conv_band_pass() {
+		for window in WINDOWS {
+			let mut conv = TestingMethod::band_pass(101, 8.0, 30.0, window, &5.5).unwrap();
+			test_const_float(&mut conv, &5.5, 0.0);
+
+			assert_symmetric(conv.weights());
+			assert!(gain(&mut conv, 3.0) < 0.02);
+			assert!((gain(&mut conv, 15.0) - 1.0).abs() < 0.02);
+			assert!(gain(&mut conv, 200.0) < 0.02);
+		}
+	}
+
+	#[test]
+	fn test_conv_presets_parameters() {
+		let window = WindowFunction::default();
+
+		assert!(TestingMethod::gaussian(0, 1.0, &1.0).is_err());
+		assert!(TestingMethod::gaussian(10, 0.0, &1.0).is_err());
+		assert!(TestingMethod::gaussian(10, ValueType::INFINITY, &1.0).is_err());
+		assert!(TestingMethod::gaussian(1, 0.1, &1.0).is_ok());
+
+		assert!(TestingMethod::lanczos(0, 3, &1.0).is_err());
+		assert!(TestingMethod::lanczos(10, 0, &1.0).is_err());
+		assert!(TestingMethod::lanczos(1, 1, &1.0).is_ok());
+
+		assert!(TestingMethod::low_pass(0, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::low_pass(10, 1.9, window, &1.0).is_err());
+		assert!(TestingMethod::low_pass(1, 2.0, window, &1.0).is_ok());
+
+		assert!(TestingMethod::high_pass(0, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::high_pass(10, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::high_pass(11, 1.0, window, &1.0).is_err());
+		assert!(TestingMethod::high_pass(11, 10.0, window, &1.0).is_ok());
+
+		assert!(TestingMethod::band_pass(0, 5.0, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::band_pass(10, 1.0, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::band_pass(10, 10.0, 10.0, window, &1.0).is_err());
+		assert!(TestingMethod::band_pass(10, 5.0, ValueType::NAN, window, &1.0).is_err());
+		assert!(TestingMethod::band_pass(10, 5.0, 10.0, window, &1.0).is_ok());
+	}
 }
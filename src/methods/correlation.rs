@@ -0,0 +1,695 @@
+use crate::core::Method;
+use crate::core::{centered_sums, non_zero_m2, Error, PeriodType, RunningSum, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling means, sums of squared deviations and co-deviation of two timeseries over the window
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct CoMoments {
+	mean_value: RunningSum,
+	mean_base: RunningSum,
+	m2_value: RunningSum,
+	m2_base: RunningSum,
+	c2: RunningSum,
+	divider: ValueType,
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl CoMoments {
+	fn new(length: PeriodType, &(value, base): &(ValueType, ValueType)) -> Result<Self, Error> {
+		Error::ensure(length > 1, "length", length, "must be > 1")?;
+
+		Ok(Self {
+			mean_value: RunningSum::new(value),
+			mean_base: RunningSum::new(base),
+			m2_value: RunningSum::new(0.0),
+			m2_base: RunningSum::new(0.0),
+			c2: RunningSum::new(0.0),
+			divider: (length as ValueType).recip(),
+			window: Window::new(length, (value, base)),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &(value, base): &(ValueType, ValueType)) {
+		let (prev_value, prev_base) = self.window.push((value, base));
+
+		let prev_mean_value = self.mean_value.value();
+		let prev_mean_base = self.mean_base.value();
+
+		self.mean_value.add((value - prev_value) * self.divider);
+		self.mean_base.add((base - prev_base) * self.divider);
+
+		let mean_value = self.mean_value.value();
+		let mean_base = self.mean_base.value();
+
+		// rolling Welford's update of the co-moments:
+		// C' = C + (x_new - mean_x') * (y_new - mean_y) - (x_old - mean_x') * (y_old - mean_y)
+		self.m2_value.add((value - mean_value).mul_add(
+			value - prev_mean_value,
+			-(prev_value - mean_value) * (prev_value - prev_mean_value),
+		));
+		self.m2_base.add((base - mean_base).mul_add(
+			base - prev_mean_base,
+			-(prev_base - mean_base) * (prev_base - prev_mean_base),
+		));
+		self.c2.add((value - mean_value).mul_add(
+			base - prev_mean_base,
+			-(prev_value - mean_value) * (prev_base - prev_mean_base),
+		));
+
+		if self.window.is_aligned() {
+			self.recompute();
+		}
+	}
+
+	fn recompute(&mut self) {
+		let (mean_value, [m2_value]) =
+			centered_sums(self.window.iter().map(|&(value, _)| value), |x| [x * x]);
+		let (mean_base, [m2_base]) =
+			centered_sums(self.window.iter().map(|&(_, base)| base), |x| [x * x]);
+
+		let mut c2 = RunningSum::new(0.0);
+		for &(value, base) in &self.window {
+			c2.add((value - mean_value) * (base - mean_base));
+		}
+
+		self.mean_value = RunningSum::new(mean_value);
+		self.mean_base = RunningSum::new(mean_base);
+		self.m2_value = RunningSum::new(m2_value);
+		self.m2_base = RunningSum::new(m2_base);
+		self.c2 = c2;
+	}
+
+	fn m2_value(&self) -> ValueType {
+		non_zero_m2(
+			self.m2_value.value(),
+			self.mean_value.value(),
+			self.window.len() as ValueType,
+		)
+	}
+
+	fn m2_base(&self) -> ValueType {
+		non_zero_m2(
+			self.m2_base.value(),
+			self.mean_base.value(),
+			self.window.len() as ValueType,
+		)
+	}
+
+	fn covariance(&self) -> ValueType {
+		self.c2.value() / (self.window.len() - 1) as ValueType
+	}
+
+	fn correlation(&self) -> ValueType {
+		let m2 = self.m2_value() * self.m2_base();
+
+		if m2 > 0.0 {
+			(self.c2.value() / m2.sqrt()).clamp(-1.0, 1.0)
+		} else {
+			0.0
+		}
+	}
+
+	fn beta(&self) -> ValueType {
+		let m2_base = self.m2_base();
+
+		if m2_base > 0.0 {
+			self.c2.value() / m2_base
+		} else {
+			0.0
+		}
+	}
+
+	const fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
+}
+
+/// Moving sample [Covariance](https://en.wikipedia.org/wiki/Covariance) of two timeseries of type [`ValueType`] over the window of size `length`
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Covariance;
+///
+/// let mut covariance = Covariance::new(3, &(1.0, 2.0)).unwrap();
+///
+/// covariance.next(&(1.0, 2.0));
+/// covariance.next(&(2.0, 4.0));
+///
+/// // `base` always equals `value` * 2
+/// assert_eq!(covariance.next(&(3.0, 6.0)), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized: co-moments are recomputed from the window once per `length` values
+///
+/// # See also
+///
+/// [`Correlation`], [`Beta`], [`StDev`](crate::methods::StDev)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Covariance(CoMoments);
+
+impl Method for Covariance {
+	type Params = PeriodType;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		CoMoments::new(length, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value);
+		self.0.covariance()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Covariance {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.covariance()
+	}
+}
+
+/// Moving [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient) of two timeseries of type [`ValueType`] over the window of size `length`
+///
+/// When all the values of any of the timeseries in the window are equal, output value is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Correlation;
+///
+/// let mut correlation = Correlation::new(3, &(1.0, 2.0)).unwrap();
+///
+/// correlation.next(&(2.0, 0.0));
+/// correlation.next(&(3.0, -2.0));
+///
+/// // `base` always equals `4.0 - value * 2.0`
+/// assert_eq!(correlation.next(&(4.0, -4.0)), -1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized, the same as [`Covariance`]
+///
+/// # See also
+///
+/// [`SpearmanCorrelation`], [`Covariance`], [`Beta`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Correlation(CoMoments);
+
+impl Method for Correlation {
+	type Params = PeriodType;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		CoMoments::new(length, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value);
+		self.0.correlation()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Correlation {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.correlation()
+	}
+}
+
+/// Moving [OLS](https://en.wikipedia.org/wiki/Ordinary_least_squares) beta of the `value` timeseries against the `base` timeseries of type [`ValueType`] over the window of size `length`
+///
+/// Beta is a slope of the line `value = alpha + beta * base`, which fits the values in the window the best.
+/// The intercept is available through [`Beta::alpha`].
+///
+/// When all the `base` values in the window are equal, beta is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Beta;
+///
+/// let mut beta = Beta::new(3, &(1.0, 0.0)).unwrap();
+///
+/// beta.next(&(3.0, 1.0));
+/// beta.next(&(5.0, 2.0));
+///
+/// // `value` always equals `1.0 + 2.0 * base`
+/// assert_eq!(beta.next(&(7.0, 3.0)), 2.0);
+/// assert_eq!(beta.alpha(), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized, the same as [`Covariance`]
+///
+/// # See also
+///
+/// [`Covariance`], [`Correlation`], [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Beta(CoMoments);
+
+impl Beta {
+	/// Returns the intercept `alpha` of the line `value = alpha + beta * base`
+	#[inline]
+	#[must_use]
+	pub fn alpha(&self) -> ValueType {
+		(-self.0.beta()).mul_add(self.0.mean_base.value(), self.0.mean_value.value())
+	}
+}
+
+impl Method for Beta {
+	type Params = PeriodType;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		CoMoments::new(length, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value);
+		self.0.beta()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Beta {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.beta()
+	}
+}
+
+/// Moving [Spearman's rank correlation coefficient](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient) of two timeseries of type [`ValueType`] over the window of size `length`
+///
+/// It is a [`Correlation`] of the ranks of the values in the window. Equal values get an average rank.
+///
+/// When all the values of any of the timeseries in the window are equal, output value is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::SpearmanCorrelation;
+///
+/// let mut correlation = SpearmanCorrelation::new(4, &(1.0, 1.0)).unwrap();
+///
+/// correlation.next(&(1.0, 1.0));
+/// correlation.next(&(2.0, 4.0));
+/// correlation.next(&(3.0, 9.0));
+///
+/// // `base` always grows with `value`
+/// assert_eq!(correlation.next(&(4.0, 16.0)), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length` * log(`length`))
+///
+/// # See also
+///
+/// [`Correlation`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpearmanCorrelation {
+	value: ValueType,
+	window: Window<(ValueType, ValueType)>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	order: Vec<(ValueType, usize)>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	value_ranks: Vec<ValueType>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	base_ranks: Vec<ValueType>,
+}
+
+impl SpearmanCorrelation {
+	fn correlation(&mut self) -> ValueType {
+		let length = self.window.len() as usize;
+		self.value_ranks.resize(length, 0.0);
+		self.base_ranks.resize(length, 0.0);
+
+		rank(
+			self.window.iter().map(|x| x.0),
+			&mut self.order,
+			&mut self.value_ranks,
+		);
+		rank(
+			self.window.iter().map(|x| x.1),
+			&mut self.order,
+			&mut self.base_ranks,
+		);
+
+		// mean of the ranks is always (length - 1) / 2
+		let mean = (length - 1) as ValueType / 2.0;
+		let (mut c2, mut m2_value, mut m2_base) = (0.0, 0.0, 0.0);
+		for (value, base) in self.value_ranks.iter().zip(&self.base_ranks) {
+			let (value, base) = (value - mean, base - mean);
+
+			c2 = value.mul_add(base, c2);
+			m2_value = value.mul_add(value, m2_value);
+			m2_base = base.mul_add(base, m2_base);
+		}
+
+		if m2_value > 0.0 && m2_base > 0.0 {
+			(c2 / (m2_value * m2_base).sqrt()).clamp(-1.0, 1.0)
+		} else {
+			0.0
+		}
+	}
+}
+
+impl Method for SpearmanCorrelation {
+	type Params = PeriodType;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 1, "length", length, "must be > 1")?;
+
+		Ok(Self {
+			value: 0.0,
+			window: Window::new(length, value),
+			order: Vec::with_capacity(length as usize),
+			value_ranks: Vec::with_capacity(length as usize),
+			base_ranks: Vec::with_capacity(length as usize),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.window.push(value);
+		self.value = self.correlation();
+
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for SpearmanCorrelation {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+/// Writes zero-based ranks of the `values` into the `ranks`, giving equal values an average rank
+fn rank(
+	values: impl Iterator<Item = ValueType>,
+	order: &mut Vec<(ValueType, usize)>,
+	ranks: &mut [ValueType],
+) {
+	order.clear();
+	order.extend(values.zip(0..));
+	order.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+	let mut start = 0;
+	while start < order.len() {
+		let end = order[start..]
+			.iter()
+			.position(|x| x.0 > order[start].0)
+			.map_or(order.len(), |x| x + start);
+
+		let rank = (start + end - 1) as ValueType / 2.0;
+		order[start..end].iter().for_each(|&(_, i)| ranks[i] = rank);
+
+		start = end;
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Beta, Correlation, Covariance, Method, SpearmanCorrelation};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{assert_near, test_const_float, test_naive, test_warmup_with};
+
+	const LENGTHS: [PeriodType; 18] = [
+		3, 4, 5, 7, 10, 11, 13, 17, 20, 21, 25, 70, 77, 100, 125, 128, 173, 254,
+	];
+
+	fn source() -> Vec<(ValueType, ValueType)> {
+		RandomCandles::default()
+			.take(300)
+			.map(|x| (x.close, x.volume))
+			.collect()
+	}
+
+	fn naive_moments(window: &[(ValueType, ValueType)]) -> (ValueType, ValueType, ValueType) {
+		let length = window.len() as ValueType;
+		let mean_value = window.iter().map(|x| x.0).sum::<ValueType>() / length;
+		let mean_base = window.iter().map(|x| x.1).sum::<ValueType>() / length;
+
+		window.iter().fold(
+			(0.0, 0.0, 0.0),
+			|(c2, m2_value, m2_base), &(value, base)| {
+				(
+					c2 + (value - mean_value) * (base - mean_base),
+					m2_value + (value - mean_value).powi(2),
+					m2_base + (base - mean_base).powi(2),
+				)
+			},
+		)
+	}
+
+	#[allow(clippy::float_cmp)]
+	fn naive_ranks(values: &[ValueType]) -> Vec<ValueType> {
+		values
+			.iter()
+			.map(|x| {
+				let less = values.iter().filter(|y| *y < x).count();
+				let equal = values.iter().filter(|y| *y == x).count();
+
+				less as ValueType + (equal - 1) as ValueType / 2.0
+			})
+			.collect()
+	}
+
+	fn test_parameters<M>()
+	where
+		M: Method<Params = PeriodType, Input = (ValueType, ValueType)>,
+	{
+		assert!(M::new(0, &(1.0, 1.0)).is_err());
+		assert!(M::new(1, &(1.0, 1.0)).is_err());
+		assert!(M::new(2, &(1.0, 1.0)).is_ok());
+	}
+
+	#[test]
+	fn test_co_moments_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, i as ValueType / 3.0);
+
+			test_const_float(&mut Covariance::new(i, &input).unwrap(), &input, 0.0);
+			test_const_float(&mut Correlation::new(i, &input).unwrap(), &input, 0.0);
+			test_const_float(&mut Beta::new(i, &input).unwrap(), &input, 0.0);
+			test_const_float(
+				&mut SpearmanCorrelation::new(i, &input).unwrap(),
+				&input,
+				0.0,
+			);
+		}
+	}
+
+	#[test]
+	fn test_co_moments_warmup() {
+		let src = source();
+		let (value, base) = src[0];
+		let (initial1, initial2) = ((value - 10.0, base + 30.0), (value + 30.0, base + 20.0));
+
+		for length in 2..100 {
+			test_warmup_with::<Covariance>(length, &src, &initial1, &initial2);
+			test_warmup_with::<Correlation>(length, &src, &initial1, &initial2);
+			test_warmup_with::<Beta>(length, &src, &initial1, &initial2);
+			test_warmup_with::<SpearmanCorrelation>(length, &src, &initial1, &initial2);
+		}
+	}
+
+	#[test]
+	fn test_covariance() {
+		test_parameters::<Covariance>();
+		test_naive::<Covariance>(&source(), LENGTHS, |window| {
+			naive_moments(window).0 / (window.len() - 1) as ValueType
+		});
+	}
+
+	#[test]
+	fn test_correlation() {
+		test_parameters::<Correlation>();
+		test_naive::<Correlation>(&source(), LENGTHS, |window| {
+			let (c2, m2_value, m2_base) = naive_moments(window);
+
+			if m2_value > 0.0 && m2_base > 0.0 {
+				c2 / (m2_value * m2_base).sqrt()
+			} else {
+				0.0
+			}
+		});
+	}
+
+	#[test]
+	fn test_beta() {
+		test_parameters::<Beta>();
+		test_naive::<Beta>(&source(), LENGTHS, |window| {
+			let (c2, _, m2_base) = naive_moments(window);
+
+			if m2_base > 0.0 {
+				c2 / m2_base
+			} else {
+				0.0
+			}
+		});
+	}
+
+	#[test]
+	fn test_beta_alpha() {
+		let src = source();
+
+		for length in 2..255 {
+			let mut beta = Beta::new(length, &(src[0].0 * 3.0 - 2.0, src[0].0)).unwrap();
+
+			for (i, &(x, _)) in src.iter().enumerate() {
+				let value = beta.next(&(x * 3.0 - 2.0, x));
+
+				if i >= beta.warmup_len() {
+					assert_near(3.0, value);
+					assert_near(-2.0, beta.alpha());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_spearman_correlation() {
+		test_parameters::<SpearmanCorrelation>();
+		test_naive::<SpearmanCorrelation>(&source(), LENGTHS, |window| {
+			let values: Vec<_> = window.iter().map(|x| x.0).collect();
+			let bases: Vec<_> = window.iter().map(|x| x.1).collect();
+
+			let ranks: Vec<_> = naive_ranks(&values)
+				.into_iter()
+				.zip(naive_ranks(&bases))
+				.collect();
+			let (c2, m2_value, m2_base) = naive_moments(&ranks);
+
+			if m2_value > 0.0 && m2_base > 0.0 {
+				c2 / (m2_value * m2_base).sqrt()
+			} else {
+				0.0
+			}
+		});
+	}
+
+	#[test]
+	fn test_spearman_correlation_ties() {
+		let mut correlation = SpearmanCorrelation::new(5, &(1.0, 5.0)).unwrap();
+
+		correlation.next(&(2.0, 4.0));
+		correlation.next(&(2.0, 3.0));
+		correlation.next(&(3.0, 3.0));
+
+		// value ranks are [0, 1.5, 1.5, 3, 4], base ranks are [4, 3, 1.5, 1.5, 0]
+		assert_eq_float(-8.75 / 9.5, correlation.next(&(4.0, 1.0)));
+	}
+}
@@ -84,6 +84,10 @@ impl Method for Cross {
 
 		((up as i8) - (down as i8)).into()
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
 
 /// Searches for `value` timeseries line crosses `base` line upwards
@@ -173,6 +177,10 @@ impl Method for CrossAbove {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		Action::from(self.binary(value.0, value.1) as i8)
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
 
 /// Searches for `value` timeseries line crosses `base` line downwards
@@ -262,6 +270,10 @@ impl Method for CrossUnder {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		Action::from(self.binary(value.0, value.1) as i8)
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
 
 #[cfg(test)]
@@ -66,7 +66,7 @@ impl Method for Derivative {
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
 				divider: (length as ValueType).recip(),
 				window: Window::new(length, *value),
@@ -79,6 +79,10 @@ impl Method for Derivative {
 		let prev_value = self.window.push(*value);
 		(value - prev_value) * self.divider
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,514 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+use crate::methods::{RoofingFilter, WMA};
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers' Hilbert transformer over the last `7` values, which shifts the phase of the cycles by 90 degrees
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct HilbertTransform(Window<ValueType>);
+
+impl HilbertTransform {
+	fn new(value: ValueType) -> Self {
+		Self(Window::new(7, value))
+	}
+
+	/// Returns the input value of `index` bars ago
+	fn input(&self, index: PeriodType) -> ValueType {
+		self.0[index]
+	}
+
+	/// `gain` compensates the amplitude of the transform, which depends on the period of the cycle
+	fn next(&mut self, value: ValueType, gain: ValueType) -> ValueType {
+		self.0.push(value);
+		let x = &self.0;
+
+		(x[0] - x[6]).mul_add(0.0962, (x[2] - x[4]) * 0.5769) * gain
+	}
+}
+
+/// Ehlers' homodyne discriminator, which measures the dominant cycle period of the values
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct Homodyne {
+	smoother: WMA,
+	detrender: HilbertTransform,
+	quadrature: HilbertTransform,
+	in_phase_lead: HilbertTransform,
+	quadrature_lead: HilbertTransform,
+	phasor: (ValueType, ValueType),
+	product: (ValueType, ValueType),
+	period: ValueType,
+	min_period: ValueType,
+	max_period: ValueType,
+}
+
+impl Homodyne {
+	pub(super) fn new(
+		value: ValueType,
+		min_period: ValueType,
+		max_period: ValueType,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			smoother: WMA::new(4, &value)?,
+			detrender: HilbertTransform::new(value),
+			quadrature: HilbertTransform::new(0.0),
+			in_phase_lead: HilbertTransform::new(0.0),
+			quadrature_lead: HilbertTransform::new(0.0),
+			phasor: (0.0, 0.0),
+			product: (0.0, 0.0),
+			period: min_period,
+			min_period,
+			max_period,
+		})
+	}
+
+	/// Returns the last measured period
+	pub(super) const fn period(&self) -> ValueType {
+		self.period
+	}
+
+	/// Returns in-phase and quadrature components of the cycle
+	pub(super) fn next(&mut self, value: ValueType) -> (ValueType, ValueType) {
+		let gain = self.period.mul_add(0.075, 0.54);
+
+		let smooth = self.smoother.next(&value);
+		let detrender = self.detrender.next(smooth, gain);
+		let quadrature = self.quadrature.next(detrender, gain);
+		let in_phase = self.quadrature.input(3);
+
+		// advance the phase of the components by 90 degrees
+		let in_phase_lead = self.in_phase_lead.next(in_phase, gain);
+		let quadrature_lead = self.quadrature_lead.next(quadrature, gain);
+
+		let (i1, q1) = self.phasor;
+		let i2 = (in_phase - quadrature_lead).mul_add(0.2, 0.8 * i1);
+		let q2 = (quadrature + in_phase_lead).mul_add(0.2, 0.8 * q1);
+		self.phasor = (i2, q2);
+
+		// multiplication by the complex conjugate of the previous phasor gives the phase change per bar
+		let (re, im) = self.product;
+		let re = i2.mul_add(i1, q2 * q1).mul_add(0.2, 0.8 * re);
+		let im = i2.mul_add(q1, -q2 * i1).mul_add(0.2, 0.8 * im);
+		self.product = (re, im);
+
+		let period = if re != 0.0 && im != 0.0 {
+			360.0 / (im / re).atan().to_degrees()
+		} else {
+			self.period
+		};
+		let period = period
+			.min(1.5 * self.period)
+			.max(0.67 * self.period)
+			.clamp(self.min_period, self.max_period);
+		self.period = period.mul_add(0.2, 0.8 * self.period);
+
+		(in_phase, quadrature)
+	}
+}
+
+/// Count of the values for the correlation at the every lag
+const AVERAGE_LENGTH: PeriodType = 3;
+/// The shortest lag, which is taken into account by the periodogram
+const FIRST_LAG: PeriodType = 3;
+/// The longest period of the periodogram, which keeps cosine and sine of the every lag for the every period
+const MAX_PERIOD: PeriodType = 250;
+
+/// Ehlers' autocorrelation periodogram over the values, filtered by the [`RoofingFilter`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Periodogram {
+	roofing: RoofingFilter,
+	filtered: Window<ValueType>,
+	/// cosine and sine of the every lag for the every period
+	basis: Vec<(ValueType, ValueType)>,
+	correlations: Vec<ValueType>,
+	power: Vec<ValueType>,
+	max_power: ValueType,
+	min_period: PeriodType,
+	period: ValueType,
+}
+
+impl Periodogram {
+	#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+	fn new(
+		value: ValueType,
+		min_period: PeriodType,
+		max_period: PeriodType,
+	) -> Result<Self, Error> {
+		Error::ensure(
+			max_period <= MAX_PERIOD,
+			"max_period",
+			max_period,
+			format_args!("must be <= {MAX_PERIOD}"),
+		)?;
+
+		let size = max_period + AVERAGE_LENGTH;
+		let roofing = RoofingFilter::new((max_period, min_period), &value)?;
+
+		let mut basis = Vec::new();
+		for period in min_period..=max_period {
+			for lag in FIRST_LAG..=max_period {
+				let angle = 2.0 * PI * lag as f64 / period as f64;
+				basis.push((angle.cos() as ValueType, angle.sin() as ValueType));
+			}
+		}
+
+		Ok(Self {
+			filtered: Window::new(size, roofing.peek()),
+			roofing,
+			basis,
+			correlations: vec![0.0; (max_period - FIRST_LAG + 1) as usize],
+			power: vec![0.0; (max_period - min_period + 1) as usize],
+			max_power: 0.0,
+			min_period,
+			period: min_period as ValueType,
+		})
+	}
+
+	/// Returns Pearson correlation between the last values and the values `lag` bars ago
+	fn correlation(&self, lag: PeriodType) -> ValueType {
+		let (mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+
+		for index in 0..AVERAGE_LENGTH {
+			let x = self.filtered[index];
+			let y = self.filtered[index + lag];
+
+			sx += x;
+			sy += y;
+			sxx = x.mul_add(x, sxx);
+			syy = y.mul_add(y, syy);
+			sxy = x.mul_add(y, sxy);
+		}
+
+		let n = AVERAGE_LENGTH as ValueType;
+		let denominator = n.mul_add(sxx, -sx * sx) * n.mul_add(syy, -sy * sy);
+
+		if denominator > 0.0 {
+			n.mul_add(sxy, -sx * sy) / denominator.sqrt()
+		} else {
+			0.0
+		}
+	}
+
+	fn next(&mut self, value: ValueType) -> ValueType {
+		self.filtered.push(self.roofing.next(&value));
+
+		for (i, lag) in (FIRST_LAG..).take(self.correlations.len()).enumerate() {
+			self.correlations[i] = self.correlation(lag);
+		}
+
+		let lags = self.correlations.len();
+		for (power, basis) in self.power.iter_mut().zip(self.basis.chunks_exact(lags)) {
+			let (cosine, sine) = basis.iter().zip(&self.correlations).fold(
+				(0.0, 0.0),
+				|(cosine, sine), (&(cos, sin), &correlation)| {
+					(
+						correlation.mul_add(cos, cosine),
+						correlation.mul_add(sin, sine),
+					)
+				},
+			);
+
+			let square = cosine.mul_add(cosine, sine * sine);
+			*power = (square * square).mul_add(0.2, 0.8 * *power);
+		}
+
+		// automatic gain control with the slowly decaying peak
+		let peak = self.power.iter().copied().fold(0.0, ValueType::max);
+		self.max_power = peak.max(0.995 * self.max_power);
+
+		if self.max_power > 0.0 {
+			let (weighted, total) = (self.min_period..)
+				.zip(&self.power)
+				.map(|(period, &power)| (period as ValueType, power / self.max_power))
+				.filter(|&(_, power)| power >= 0.5)
+				.fold((0.0, 0.0), |(weighted, total), (period, power)| {
+					(period.mul_add(power, weighted), total + power)
+				});
+
+			if total > 0.0 {
+				self.period = weighted / total;
+			}
+		}
+
+		self.period
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.roofing.warmup_len() + self.filtered.len() as usize
+	}
+}
+
+/// Algorithm of the [`DominantCycle`] estimation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CycleEstimator {
+	/// Ehlers' [homodyne discriminator](https://www.mesasoftware.com/papers/MAMA.pdf), which measures the phase change
+	/// of the Hilbert transform per bar. It reacts fast, but it is sensitive to the noise.
+	#[default]
+	Homodyne,
+
+	/// Ehlers' [autocorrelation periodogram](https://www.mesasoftware.com/papers/Cycle%20Analytics%20for%20Traders.pdf),
+	/// which finds the period with the highest power in the spectrum of the autocorrelation of the filtered values.
+	/// It is more robust, but it is O(`max_period`²) per bar.
+	Autocorrelation,
+}
+
+impl FromStr for CycleEstimator {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"homodyne" => Ok(Self::Homodyne),
+			"autocorrelation" => Ok(Self::Autocorrelation),
+
+			value => Err(Error::invalid_parameter(
+				"estimator",
+				value,
+				"must be `homodyne` or `autocorrelation`",
+			)),
+		}
+	}
+}
+
+impl fmt::Display for CycleEstimator {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Homodyne => f.write_str("homodyne"),
+			Self::Autocorrelation => f.write_str("autocorrelation"),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Estimator {
+	Homodyne {
+		homodyne: Homodyne,
+		period: ValueType,
+	},
+	Autocorrelation(Periodogram),
+}
+
+/// Ehlers' dominant cycle period estimation for timeseries of type [`ValueType`]
+///
+/// Measures the period (in bars) of the strongest cycle of the values at the every bar, so the period-based
+/// methods and indicators may adapt to it. See [`Adaptive`](crate::indicators::Adaptive) for the indicators.
+///
+/// At the start it returns `min_period`.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`estimator`: [`CycleEstimator`], `min_period`: [`PeriodType`], `max_period`: [`PeriodType`]\)
+///
+/// `min_period` should be > `2`
+///
+/// `max_period` should be > `min_period`. For the [`Autocorrelation`](CycleEstimator::Autocorrelation) it also should be
+/// <= `250`, because the periodogram keeps O(`max_period`²) precomputed values.
+///
+/// Ehlers uses the periods from `6` to `50` bars for the [`Homodyne`](CycleEstimator::Homodyne)
+/// and from `10` to `48` bars for the [`Autocorrelation`](CycleEstimator::Autocorrelation).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`], which is the period in \[`min_period`; `max_period`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{CycleEstimator, DominantCycle};
+///
+/// let mut dominant_cycle = DominantCycle::new((CycleEstimator::Autocorrelation, 10, 48), &0.0).unwrap();
+///
+/// let period = (0..300)
+///     .map(|i| (i as f64 * std::f64::consts::PI / 10.0).sin())
+///     .map(|x| dominant_cycle.next(&x))
+///     .last()
+///     .unwrap();
+///
+/// assert!((period - 20.0).abs() < 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) for the [`Homodyne`](CycleEstimator::Homodyne) and O(`max_period`²) for the [`Autocorrelation`](CycleEstimator::Autocorrelation)
+///
+/// # See also
+///
+/// [`MAMA`](crate::methods::MAMA), [`RoofingFilter`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DominantCycle {
+	estimator: Estimator,
+	max_period: PeriodType,
+}
+
+impl Method for DominantCycle {
+	type Params = (CycleEstimator, PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(
+		(estimator, min_period, max_period): Self::Params,
+		&value: &Self::Input,
+	) -> Result<Self, Error> {
+		Error::ensure(min_period > 2, "min_period", min_period, "must be > 2")?;
+		Error::ensure(
+			max_period > min_period,
+			"max_period",
+			max_period,
+			format_args!("must be > min_period {min_period}"),
+		)?;
+
+		let estimator = match estimator {
+			CycleEstimator::Homodyne => Estimator::Homodyne {
+				homodyne: Homodyne::new(value, min_period as ValueType, max_period as ValueType)?,
+				period: min_period as ValueType,
+			},
+			CycleEstimator::Autocorrelation => {
+				Estimator::Autocorrelation(Periodogram::new(value, min_period, max_period)?)
+			}
+		};
+
+		Ok(Self {
+			estimator,
+			max_period,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		match &mut self.estimator {
+			Estimator::Homodyne { homodyne, period } => {
+				homodyne.next(value);
+				*period = homodyne.period().mul_add(0.33, 0.67 * *period);
+
+				*period
+			}
+			Estimator::Autocorrelation(periodogram) => periodogram.next(value),
+		}
+	}
+
+	fn warmup_len(&self) -> usize {
+		match &self.estimator {
+			Estimator::Homodyne { .. } => self.max_period as usize,
+			Estimator::Autocorrelation(periodogram) => periodogram.warmup_len(),
+		}
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for DominantCycle {
+	fn peek(&self) -> <Self as Method>::Output {
+		match &self.estimator {
+			Estimator::Homodyne { period, .. } => *period,
+			Estimator::Autocorrelation(periodogram) => periodogram.period,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CycleEstimator, DominantCycle, Method};
+	use crate::core::ValueType;
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const_float;
+	use std::f64::consts::PI;
+
+	const ESTIMATORS: [CycleEstimator; 2] =
+		[CycleEstimator::Homodyne, CycleEstimator::Autocorrelation];
+
+	#[test]
+	fn test_dominant_cycle_const() {
+		for estimator in ESTIMATORS {
+			for i in 3..40 {
+				let input = (i as ValueType + 56.0) / 16.3251;
+				let mut method = DominantCycle::new((estimator, i, 48), &input).unwrap();
+
+				test_const_float(&mut method, &input, i as ValueType);
+			}
+		}
+	}
+
+	#[test]
+	fn test_dominant_cycle_sine() {
+		for estimator in ESTIMATORS {
+			for period in [12, 16, 20, 25, 30, 40] {
+				#[allow(clippy::cast_possible_truncation, trivial_numeric_casts)]
+				let sine = |i: usize| (2.0 * PI * i as f64 / period as f64).sin() as ValueType;
+				let mut method = DominantCycle::new((estimator, 10, 48), &0.0).unwrap();
+
+				for i in 0..600 {
+					let value = method.next(&(sine(i) + 100.0));
+
+					if i > 400 {
+						let error = (value - period as ValueType).abs();
+						assert!(
+							error < 0.1 * period as ValueType,
+							"{estimator} {period}: {value}"
+						);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_dominant_cycle_range() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(500)
+			.map(|c| c.close)
+			.collect();
+
+		for estimator in ESTIMATORS {
+			let mut method = DominantCycle::new((estimator, 8, 30), &src[0]).unwrap();
+
+			for x in &src {
+				let value = method.next(x);
+				assert!((8.0..=30.0).contains(&value), "{estimator}: {value}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_cycle_estimator_parse() {
+		for estimator in ESTIMATORS {
+			assert_eq!(
+				estimator.to_string().parse::<CycleEstimator>().unwrap(),
+				estimator
+			);
+		}
+
+		assert_eq!(
+			"Autocorrelation".parse::<CycleEstimator>().unwrap(),
+			CycleEstimator::Autocorrelation
+		);
+		assert!("hilbert".parse::<CycleEstimator>().is_err());
+	}
+
+	#[test]
+	fn test_dominant_cycle_parameters() {
+		for estimator in ESTIMATORS {
+			assert!(DominantCycle::new((estimator, 2, 48), &1.0).is_err());
+			assert!(DominantCycle::new((estimator, 10, 10), &1.0).is_err());
+			assert!(DominantCycle::new((estimator, 3, 4), &1.0).is_ok());
+		}
+
+		assert!(DominantCycle::new((CycleEstimator::Autocorrelation, 10, 250), &1.0).is_ok());
+		assert!(DominantCycle::new((CycleEstimator::Autocorrelation, 10, 251), &1.0).is_err());
+	}
+}
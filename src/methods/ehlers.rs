@@ -0,0 +1,733 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::{Biquad, BiquadCoefficients, SWMA};
+use std::f64::consts::{PI, SQRT_2};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+const fn coefficients(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> BiquadCoefficients {
+	BiquadCoefficients {
+		b0: b0 as ValueType,
+		b1: b1 as ValueType,
+		b2: b2 as ValueType,
+		a1: a1 as ValueType,
+		a2: a2 as ValueType,
+	}
+}
+
+/// Returns smoothing factor of the same form as the [`EMA`](crate::methods::EMA) has
+fn alpha(length: PeriodType) -> f64 {
+	2.0 / (length as f64 + 1.0)
+}
+
+/// Returns `alpha` of Ehlers' high-pass filters for the cycle of `angle` radians per bar
+fn high_pass_alpha(angle: f64) -> f64 {
+	(angle.cos() + angle.sin() - 1.0) / angle.cos()
+}
+
+/// Ehlers' two pole high-pass filter, which removes the trend from the values
+fn high_pass(alpha: f64) -> BiquadCoefficients {
+	let gain = (1.0 - alpha / 2.0).powi(2);
+	let decay = 1.0 - alpha;
+
+	coefficients(gain, -2.0 * gain, gain, -2.0 * decay, decay * decay)
+}
+
+fn super_smoother(period: PeriodType) -> BiquadCoefficients {
+	let angle = SQRT_2 * PI / period as f64;
+	let a = (-angle).exp();
+	let b = 2.0 * a * angle.cos();
+	let c = 1.0 + a.mul_add(a, -b);
+
+	coefficients(c / 2.0, c / 2.0, 0.0, -b, a * a)
+}
+
+/// Ehlers' [Super Smoother](https://www.mesasoftware.com/papers/PredictiveIndicators.pdf) filter of specified `period` for timeseries of type [`ValueType`]
+///
+/// Two pole low-pass filter, which removes the cycles shorter than `period` bars
+/// with much less lag than the moving averages with the same smoothing.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::SuperSmoother;
+///
+/// let mut super_smoother = SuperSmoother::new(10, &1.0).unwrap();
+///
+/// assert!((super_smoother.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(super_smoother.next(&2.0) < 1.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Butterworth`](crate::methods::Butterworth), [`RoofingFilter`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuperSmoother(Biquad);
+
+impl Method for SuperSmoother {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(period: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(period > 1, "period", period, "must be > 1")?;
+
+		Biquad::new(super_smoother(period), value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl MovingAverage for SuperSmoother {}
+
+impl Peekable<<Self as Method>::Output> for SuperSmoother {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// Ehlers' [Roofing Filter](https://www.mesasoftware.com/papers/PredictiveIndicators.pdf) for timeseries of type [`ValueType`]
+///
+/// Keeps only the cycles from `low_period` to `high_period` bars: the trend is removed by the two pole high-pass filter
+/// and the noise is removed by the [`SuperSmoother`]. Output values oscillate around zero.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`high_period`: [`PeriodType`], `low_period`: [`PeriodType`]\)
+///
+/// `low_period` should be > `1`
+///
+/// `high_period` should be > `low_period`
+///
+/// Ehlers uses `high_period` = `48` and `low_period` = `10`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RoofingFilter;
+///
+/// let mut roofing = RoofingFilter::new((48, 10), &5.0).unwrap();
+///
+/// assert!(roofing.next(&5.0).abs() < 1e-9);
+/// assert!(roofing.next(&6.0) > 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SuperSmoother`], [`FilterBand::BandPass`](crate::methods::FilterBand::BandPass)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoofingFilter {
+	high_pass: Biquad,
+	smoother: SuperSmoother,
+}
+
+impl Method for RoofingFilter {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((high_period, low_period): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			high_period > low_period,
+			"high_period",
+			high_period,
+			format_args!("must be > low_period {low_period}"),
+		)?;
+
+		let angle = SQRT_2 * PI / high_period as f64;
+		let high_pass = Biquad::new(high_pass(high_pass_alpha(angle)), value)?;
+
+		Ok(Self {
+			smoother: SuperSmoother::new(low_period, &high_pass.peek())?,
+			high_pass,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.smoother.next(&self.high_pass.next(value))
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.high_pass.warmup_len() + self.smoother.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for RoofingFilter {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.smoother.peek()
+	}
+}
+
+/// Ehlers' [Decycler](https://www.mesasoftware.com/papers/Decyclers.pdf) of specified `period` for timeseries of type [`ValueType`]
+///
+/// Keeps the trend by subtracting the cycles shorter than `period` bars from the values,
+/// which are found by the one pole high-pass filter.
+///
+/// # Parameters
+///
+/// Has a single parameter `period`: [`PeriodType`]
+///
+/// `period` should be > `4`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Decycler;
+///
+/// let mut decycler = Decycler::new(20, &1.0).unwrap();
+///
+/// assert!((decycler.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(decycler.next(&2.0) < 1.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SuperSmoother`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Decycler(Biquad);
+
+impl Method for Decycler {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(period: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(period > 4, "period", period, "must be > 4")?;
+
+		let alpha = high_pass_alpha(2.0 * PI / period as f64);
+		let coefficients = coefficients(alpha / 2.0, alpha / 2.0, 0.0, alpha - 1.0, 0.0);
+
+		Biquad::new(coefficients, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl MovingAverage for Decycler {}
+
+impl Peekable<<Self as Method>::Output> for Decycler {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// Ehlers' [Cyber Cycle](https://www.mesasoftware.com/papers/TheInverseFisherTransform.pdf) for timeseries of type [`ValueType`]
+///
+/// Isolates the cycle component of the values by the two pole high-pass filter over the values smoothed by [`SWMA`] of length `4`.
+/// Output values oscillate around zero. The previous output value is used as the [`trigger`](Self::trigger) line.
+///
+/// Unlike the original implementation, it is in the steady state for the constant `value` from the start,
+/// so it does not need a special formula for the first bars.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`. Smoothing factor `alpha` is `2` / (`length` + `1`),
+/// so Ehlers' default `alpha` = `0.07` is about `length` = `28`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CyberCycle;
+///
+/// let mut cyber_cycle = CyberCycle::new(28, &5.0).unwrap();
+///
+/// assert!(cyber_cycle.next(&5.0).abs() < 1e-9);
+/// let value = cyber_cycle.next(&6.0);
+/// assert!(value > 0.0);
+///
+/// cyber_cycle.next(&7.0);
+/// assert_eq!(cyber_cycle.trigger(), value);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RoofingFilter`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CyberCycle {
+	smoother: SWMA,
+	high_pass: Biquad,
+	trigger: ValueType,
+}
+
+impl CyberCycle {
+	/// Returns the trigger line value, which is the previous output value
+	#[must_use]
+	pub const fn trigger(&self) -> ValueType {
+		self.trigger
+	}
+}
+
+impl Method for CyberCycle {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+
+		let high_pass = Biquad::new(high_pass(alpha(length)), value)?;
+
+		Ok(Self {
+			smoother: SWMA::new(4, value)?,
+			trigger: high_pass.peek(),
+			high_pass,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.trigger = self.high_pass.peek();
+		self.high_pass.next(&self.smoother.next(value))
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.smoother.warmup_len() + self.high_pass.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for CyberCycle {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.high_pass.peek()
+	}
+}
+
+/// Ehlers' [Instantaneous Trendline](https://www.mesasoftware.com/papers/TheInverseFisherTransform.pdf) for timeseries of type [`ValueType`]
+///
+/// Removes the cycles from the values with almost no lag. The [`trigger`](Self::trigger) line is the trendline,
+/// extrapolated by 2 bars.
+///
+/// Unlike the original implementation, it is in the steady state for the constant `value` from the start,
+/// so it does not need a special formula for the first bars.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`. Smoothing factor `alpha` is `2` / (`length` + `1`),
+/// so Ehlers' default `alpha` = `0.07` is about `length` = `28`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::InstantaneousTrendline;
+///
+/// let mut trendline = InstantaneousTrendline::new(28, &1.0).unwrap();
+///
+/// assert!((trendline.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(trendline.next(&2.0) < 1.5);
+/// assert!(trendline.trigger() > trendline.peek());
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Decycler`], [`SuperSmoother`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[doc(alias = "ITrend")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InstantaneousTrendline {
+	filter: Biquad,
+	previous: [ValueType; 2],
+}
+
+impl InstantaneousTrendline {
+	/// Returns the trigger line value, which is `2` * `trendline` - `trendline[2]`
+	#[must_use]
+	pub fn trigger(&self) -> ValueType {
+		self.filter.peek().mul_add(2.0, -self.previous[1])
+	}
+}
+
+impl Method for InstantaneousTrendline {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+
+		let alpha = alpha(length);
+		let square = alpha * alpha;
+		let decay = 1.0 - alpha;
+		let coefficients = coefficients(
+			square.mul_add(-0.25, alpha),
+			square / 2.0,
+			square.mul_add(0.75, -alpha),
+			-2.0 * decay,
+			decay * decay,
+		);
+
+		let filter = Biquad::new(coefficients, value)?;
+
+		Ok(Self {
+			previous: [filter.peek(); 2],
+			filter,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.previous = [self.filter.peek(), self.previous[0]];
+		self.filter.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.filter.warmup_len()
+	}
+}
+
+impl MovingAverage for InstantaneousTrendline {}
+
+impl Peekable<<Self as Method>::Output> for InstantaneousTrendline {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.filter.peek()
+	}
+}
+
+#[cfg(test)]
+#[allow(
+	clippy::suboptimal_flops,
+	clippy::cast_possible_truncation,
+	trivial_numeric_casts
+)]
+mod tests {
+	use super::{
+		CyberCycle, Decycler, InstantaneousTrendline, Method, RoofingFilter, SuperSmoother,
+	};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{assert_near, test_const_float};
+	use std::f64::consts::PI;
+
+	fn source() -> Vec<ValueType> {
+		RandomCandles::default()
+			.take(300)
+			.map(|c| c.close)
+			.collect()
+	}
+
+	/// Ehlers' two pole high-pass filter as it is written in his papers
+	fn naive_high_pass(src: &[ValueType], alpha: ValueType) -> Vec<ValueType> {
+		let mut output = vec![0.0; src.len()];
+		let at = |i: usize, back: usize| src[i.saturating_sub(back)];
+
+		for i in 0..src.len() {
+			let prev = |back: usize| if i >= back { output[i - back] } else { 0.0 };
+			let value = (1.0 - alpha / 2.0).powi(2) * (at(i, 0) - 2.0 * at(i, 1) + at(i, 2))
+				+ 2.0 * (1.0 - alpha) * prev(1)
+				- (1.0 - alpha).powi(2) * prev(2);
+			output[i] = value;
+		}
+
+		output
+	}
+
+	fn naive_super_smoother(src: &[ValueType], period: ValueType) -> Vec<ValueType> {
+		let a = (-ValueType::sqrt(2.0) * PI as ValueType / period).exp();
+		let b = 2.0 * a * (ValueType::sqrt(2.0) * PI as ValueType / period).cos();
+		let (c2, c3) = (b, -a * a);
+		let c1 = 1.0 - c2 - c3;
+
+		let mut output = vec![src[0]; src.len()];
+		for i in 0..src.len() {
+			let prev = |back: usize| output[i.saturating_sub(back)];
+			let value =
+				c1 * (src[i] + src[i.saturating_sub(1)]) / 2.0 + c2 * prev(1) + c3 * prev(2);
+			output[i] = value;
+		}
+
+		output
+	}
+
+	#[test]
+	fn test_ehlers_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+
+			let mut method = SuperSmoother::new(i, &input).unwrap();
+			test_const_float(&mut method, &input, input);
+
+			let mut method = CyberCycle::new(i, &input).unwrap();
+			test_const_float(&mut method, &input, 0.0);
+
+			let mut method = InstantaneousTrendline::new(i, &input).unwrap();
+			test_const_float(&mut method, &input, input);
+
+			if i > 4 {
+				let mut method = Decycler::new(i, &input).unwrap();
+				test_const_float(&mut method, &input, input);
+			}
+
+			if i < 254 {
+				let mut method = RoofingFilter::new((i + 1, i), &input).unwrap();
+				test_const_float(&mut method, &input, 0.0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_super_smoother() {
+		let src = source();
+
+		for period in [2, 3, 5, 10, 20, 40] {
+			let expected = naive_super_smoother(&src, period as ValueType);
+			let mut method = SuperSmoother::new(period, &src[0]).unwrap();
+
+			for (&x, &expected) in src.iter().zip(&expected) {
+				assert_near(expected, method.next(&x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_super_smoother_nyquist() {
+		let mut method = SuperSmoother::new(10, &0.0).unwrap();
+
+		let value = (0..200)
+			.map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+			.map(|x| method.next(&x))
+			.last()
+			.unwrap();
+
+		assert!(value.abs() < 1e-3);
+	}
+
+	#[test]
+	fn test_roofing_filter() {
+		let src = source();
+
+		for (high_period, low_period) in [(48, 10), (20, 5), (100, 30)] {
+			let angle = ValueType::sqrt(2.0) * PI as ValueType / high_period as ValueType;
+			let alpha = (angle.cos() + angle.sin() - 1.0) / angle.cos();
+
+			let high_pass = naive_high_pass(&src, alpha);
+			let expected = naive_super_smoother(&high_pass, low_period as ValueType);
+			let mut method = RoofingFilter::new((high_period, low_period), &src[0]).unwrap();
+
+			for (&x, &expected) in src.iter().zip(&expected) {
+				assert_near(expected, method.next(&x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_roofing_filter_trend() {
+		let mut method = RoofingFilter::new((48, 10), &0.0).unwrap();
+
+		let value = (0..1000)
+			.map(|i| i as ValueType * 0.5)
+			.map(|x| method.next(&x))
+			.last()
+			.unwrap();
+
+		assert_near(0.0, value);
+	}
+
+	#[test]
+	fn test_decycler() {
+		let src = source();
+
+		for period in [5, 10, 20, 60] {
+			let angle = 2.0 * PI as ValueType / period as ValueType;
+			let alpha = (angle.cos() + angle.sin() - 1.0) / angle.cos();
+			let mut method = Decycler::new(period, &src[0]).unwrap();
+			let mut prev = (src[0], src[0]);
+
+			for &x in &src {
+				let expected = alpha / 2.0 * (x + prev.0) + (1.0 - alpha) * prev.1;
+				prev = (x, expected);
+
+				assert_near(expected, method.next(&x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_cyber_cycle() {
+		let src = source();
+
+		for length in [1, 5, 10, 28, 60] {
+			let alpha = 2.0 / (length as ValueType + 1.0);
+			let smooth: Vec<ValueType> = (0..src.len())
+				.map(|i| {
+					let at = |back: usize| src[i.saturating_sub(back)];
+					(at(0) + 2.0 * at(1) + 2.0 * at(2) + at(3)) / 6.0
+				})
+				.collect();
+			let expected = naive_high_pass(&smooth, alpha);
+			let mut method = CyberCycle::new(length, &src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				assert_near(expected[i], method.next(&x));
+				assert_near(expected[i.saturating_sub(1)], method.trigger());
+			}
+		}
+	}
+
+	#[test]
+	fn test_instantaneous_trendline() {
+		let src = source();
+
+		for length in [1, 5, 10, 28, 60] {
+			let a = 2.0 / (length as ValueType + 1.0);
+			let mut method = InstantaneousTrendline::new(length, &src[0]).unwrap();
+			let mut output = vec![src[0]; src.len()];
+
+			for i in 0..src.len() {
+				let at = |back: usize| src[i.saturating_sub(back)];
+				let prev = |back: usize| output[i.saturating_sub(back)];
+
+				let value = (a - a * a / 4.0) * at(0) + 0.5 * a * a * at(1)
+					- (a - 0.75 * a * a) * at(2)
+					+ 2.0 * (1.0 - a) * prev(1)
+					- (1.0 - a) * (1.0 - a) * prev(2);
+				let trigger = 2.0 * value - prev(2);
+				output[i] = value;
+
+				assert_near(value, method.next(&src[i]));
+				assert_near(trigger, method.trigger());
+			}
+		}
+	}
+
+	#[test]
+	fn test_instantaneous_trendline_lag() {
+		// trendline follows the linear trend without lag
+		let mut method = InstantaneousTrendline::new(28, &0.0).unwrap();
+
+		for i in 0..1000 {
+			let x = i as ValueType * 0.5;
+			let value = method.next(&x);
+
+			if i > 500 {
+				assert_eq_float(x, value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_ehlers_parameters() {
+		let checks: [(PeriodType, bool); 4] = [(0, false), (1, false), (2, true), (5, true)];
+		for (period, ok) in checks {
+			assert_eq!(SuperSmoother::new(period, &1.0).is_ok(), ok);
+		}
+
+		assert!(Decycler::new(4, &1.0).is_err());
+		assert!(Decycler::new(5, &1.0).is_ok());
+
+		assert!(CyberCycle::new(0, &1.0).is_err());
+		assert!(InstantaneousTrendline::new(0, &1.0).is_err());
+
+		assert!(RoofingFilter::new((10, 10), &1.0).is_err());
+		assert!(RoofingFilter::new((10, 1), &1.0).is_err());
+		assert!(RoofingFilter::new((3, 2), &1.0).is_ok());
+	}
+}
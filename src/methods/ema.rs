@@ -1,5 +1,5 @@
 use crate::core::{Error, PeriodType, ValueType};
-use crate::core::{Method, MovingAverage};
+use crate::core::{Method, MovingAverage, Seed};
 use crate::helpers::Peekable;
 
 #[cfg(feature = "serde")]
@@ -49,31 +49,103 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EMA {
+	length: PeriodType,
 	alpha: ValueType,
 	value: ValueType,
+	seed_length: PeriodType,
+	seed_count: PeriodType,
 }
 
-impl Method for EMA {
-	type Params = PeriodType;
-	type Input = ValueType;
-	type Output = Self::Input;
+impl EMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// See also [`Seed`]
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Seed;
+	/// use yata::methods::EMA;
+	///
+	/// let mut ema = EMA::new_seeded(3, Seed::SMA, &1.0).unwrap();
+	///
+	/// assert_eq!(ema.next(&1.0), 1.0);
+	/// assert_eq!(ema.next(&2.0), 1.5);
+	/// assert_eq!(ema.next(&6.0), 3.0); // simple average of the first 3 values
+	/// assert_eq!(ema.next(&7.0), 5.0);
+	/// ```
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		let seed_length = match seed {
+			Seed::Initial => 0,
+			Seed::SMA => length,
+		};
+
+		Self::with_seed_length(length, seed_length, *value)
+	}
 
-	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+	pub(crate) fn with_seed_length(
+		length: PeriodType,
+		seed_length: PeriodType,
+		value: ValueType,
+	) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => {
 				let alpha = 2. / ((length + 1) as ValueType);
-				Ok(Self { alpha, value })
+				Ok(Self {
+					length,
+					alpha,
+					value,
+					seed_length,
+					seed_count: 0,
+				})
 			}
 		}
 	}
 
+	/// Returns `true` if the method is still collecting input values for the [`Seed::SMA`] initialization
+	const fn is_seeding(&self) -> bool {
+		self.seed_count < self.seed_length
+	}
+
+	/// Feeds the `value` only after the `source` is seeded, otherwise just passes the `value` through
+	fn next_after(&mut self, source: &Self, value: ValueType) -> ValueType {
+		if source.is_seeding() {
+			self.value = value;
+			value
+		} else {
+			self.next(&value)
+		}
+	}
+}
+
+impl Method for EMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Self::new_seeded(length, Seed::Initial, value)
+	}
+
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
-		self.value = (value - self.value).mul_add(self.alpha, self.value);
+		if self.is_seeding() {
+			self.seed_count += 1;
+			self.value += (value - self.value) / self.seed_count as ValueType;
+		} else {
+			self.value = (value - self.value).mul_add(self.alpha, self.value);
+		}
 
 		self.value
 	}
+
+	fn warmup_len(&self) -> usize {
+		if self.seed_length > 0 {
+			self.seed_length as usize - 1
+		} else {
+			self.length as usize - 1
+		}
+	}
 }
 
 impl MovingAverage for EMA {}
@@ -96,24 +168,37 @@ pub struct DMA {
 	dma: EMA,
 }
 
+impl DMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// Every inner [EMA] is seeded in turn, so with [`Seed::SMA`] the result is the same as TA-Lib produces.
+	///
+	/// See also [`Seed`]
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			ema: EMA::new_seeded(length, seed, value)?,
+			dma: EMA::new_seeded(length, seed, value)?,
+		})
+	}
+}
+
 impl Method for DMA {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				ema: EMA::new(length, value)?,
-				dma: EMA::new(length, value)?,
-			}),
-		}
+		Self::new_seeded(length, Seed::Initial, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
-		self.dma.next(&self.ema.next(value))
+		let e_ma = self.ema.next(value);
+		self.dma.next_after(&self.ema, e_ma)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.ema.warmup_len() + self.dma.warmup_len()
 	}
 }
 
@@ -137,24 +222,37 @@ pub struct TMA {
 	tma: EMA,
 }
 
+impl TMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// Every inner [EMA] is seeded in turn, so with [`Seed::SMA`] the result is the same as TA-Lib produces.
+	///
+	/// See also [`Seed`]
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			dma: DMA::new_seeded(length, seed, value)?,
+			tma: EMA::new_seeded(length, seed, value)?,
+		})
+	}
+}
+
 impl Method for TMA {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				dma: DMA::new(length, value)?,
-				tma: EMA::new(length, value)?,
-			}),
-		}
+		Self::new_seeded(length, Seed::Initial, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
-		self.tma.next(&self.dma.next(value))
+		let d_ma = self.dma.next(value);
+		self.tma.next_after(&self.dma.dma, d_ma)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.dma.warmup_len() + self.tma.warmup_len()
 	}
 }
 
@@ -215,28 +313,40 @@ pub struct DEMA {
 	dma: EMA,
 }
 
+impl DEMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// Every inner [EMA] is seeded in turn, so with [`Seed::SMA`] the result is the same as TA-Lib produces.
+	///
+	/// See also [`Seed`]
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			ema: EMA::new_seeded(length, seed, value)?,
+			dma: EMA::new_seeded(length, seed, value)?,
+		})
+	}
+}
+
 impl Method for DEMA {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				ema: EMA::new(length, value)?,
-				dma: EMA::new(length, value)?,
-			}),
-		}
+		Self::new_seeded(length, Seed::Initial, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		let e_ma = self.ema.next(value);
-		self.dma.next(&e_ma);
+		self.dma.next_after(&self.ema, e_ma);
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ema.warmup_len() + self.dma.warmup_len()
+	}
 }
 
 impl MovingAverage for DEMA {}
@@ -301,30 +411,42 @@ pub struct TEMA {
 	tma: EMA,
 }
 
+impl TEMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// Every inner [EMA] is seeded in turn, so with [`Seed::SMA`] the result is the same as TA-Lib produces.
+	///
+	/// See also [`Seed`]
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			ema: EMA::new_seeded(length, seed, value)?,
+			dma: EMA::new_seeded(length, seed, value)?,
+			tma: EMA::new_seeded(length, seed, value)?,
+		})
+	}
+}
+
 impl Method for TEMA {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				ema: EMA::new(length, value)?,
-				dma: EMA::new(length, value)?,
-				tma: EMA::new(length, value)?,
-			}),
-		}
+		Self::new_seeded(length, Seed::Initial, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		let e_ma = self.ema.next(value);
-		let d_ma = self.dma.next(&e_ma);
-		self.tma.next(&d_ma);
+		let d_ma = self.dma.next_after(&self.ema, e_ma);
+		self.tma.next_after(&self.dma, d_ma);
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.ema.warmup_len() + self.dma.warmup_len() + self.tma.warmup_len()
+	}
 }
 
 impl MovingAverage for TEMA {}
@@ -344,7 +466,7 @@ impl Peekable<<Self as Method>::Output> for TEMA {
 #[allow(clippy::suboptimal_flops)]
 mod tests {
 	use super::{DEMA, DMA, EMA, TEMA, TMA};
-	use crate::core::{Method, ValueType};
+	use crate::core::{Method, Seed, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
 
@@ -617,4 +739,94 @@ mod tests {
 			}
 		});
 	}
+
+	#[test]
+	fn test_ema_warmup_len() {
+		for length in 1..255 {
+			let n = length as usize - 1;
+
+			assert_eq!(EMA::new(length, &0.).unwrap().warmup_len(), n);
+			assert_eq!(DMA::new(length, &0.).unwrap().warmup_len(), n * 2);
+			assert_eq!(DEMA::new(length, &0.).unwrap().warmup_len(), n * 2);
+			assert_eq!(TMA::new(length, &0.).unwrap().warmup_len(), n * 3);
+			assert_eq!(TEMA::new(length, &0.).unwrap().warmup_len(), n * 3);
+		}
+	}
+
+	/// EMA seeded with SMA of the first `length` values, the way TA-Lib calculates it
+	fn ema_seeded(src: &[ValueType], length: usize) -> Vec<ValueType> {
+		let alpha = 2. / (length + 1) as ValueType;
+		let mut value = src[..length].iter().sum::<ValueType>() / length as ValueType;
+
+		let mut result = vec![value];
+		for &x in &src[length..] {
+			value = alpha * x + (1. - alpha) * value;
+			result.push(value);
+		}
+
+		result
+	}
+
+	#[test]
+	fn test_ema_seeded() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		(1..100).for_each(|length| {
+			let mut ma = EMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let length = length as usize;
+			let expected = ema_seeded(&src, length);
+
+			for (i, x) in src.iter().enumerate() {
+				let value = ma.next(x);
+
+				if i < length {
+					let avg = src[..=i].iter().sum::<ValueType>() / (i + 1) as ValueType;
+					assert_eq_float(avg, value);
+				} else {
+					assert_eq_float(expected[i + 1 - length], value);
+				}
+			}
+		});
+	}
+
+	#[test]
+	fn test_dema_tema_seeded() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		(1..50).for_each(|length| {
+			let mut ema2 = DMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let mut dema2 = DEMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let mut ema3 = TMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let mut tema2 = TEMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let length = length as usize;
+
+			let e1 = ema_seeded(&src, length);
+			let e2 = ema_seeded(&e1, length);
+			let e3 = ema_seeded(&e2, length);
+
+			for (i, x) in src.iter().enumerate() {
+				let (d, de, t, te) = (ema2.next(x), dema2.next(x), ema3.next(x), tema2.next(x));
+
+				if i >= ema2.warmup_len() {
+					let (v1, v2) = (e1[i + 1 - length], e2[i + 2 - length * 2]);
+					assert_eq_float(v2, d);
+					assert_eq_float(2. * v1 - v2, de);
+				}
+
+				if i >= ema3.warmup_len() {
+					let v1 = e1[i + 1 - length];
+					let v2 = e2[i + 2 - length * 2];
+					let v3 = e3[i + 3 - length * 3];
+					assert_eq_float(v3, t);
+					assert_eq_float(3. * (v1 - v2) + v3, te);
+				}
+			}
+		});
+	}
 }
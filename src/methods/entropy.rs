@@ -0,0 +1,372 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, RunningSum, ValueType, Window};
+use crate::helpers::Peekable;
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Binning of the returns for [`Entropy`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Binning {
+	/// `bins` bins of equal width over \[`lower`; `upper`\)
+	///
+	/// Returns out of the range fall into the outermost bins.
+	Range {
+		/// Lower bound of the first bin
+		lower: ValueType,
+		/// Upper bound of the last bin
+		upper: ValueType,
+		/// Count of the bins
+		bins: PeriodType,
+	},
+
+	/// Unbounded bins of the same width: \[`k` * `width`; (`k` + 1) * `width`\) for every integer `k`
+	Width(ValueType),
+}
+
+impl Binning {
+	fn validate(self) -> Result<(), Error> {
+		match self {
+			Self::Range { lower, upper, bins } => {
+				Error::ensure(
+					lower < upper,
+					"lower",
+					lower,
+					format_args!("must be < upper {upper}"),
+				)?;
+				Error::ensure(bins > 0, "bins", bins, "must be > 0")
+			}
+			Self::Width(width) => Error::ensure(
+				width > 0.0 && width.is_finite(),
+				"width",
+				width,
+				"must be a finite number > 0",
+			),
+		}
+	}
+
+	#[inline]
+	#[allow(clippy::cast_possible_truncation)]
+	fn bin(self, value: ValueType) -> i64 {
+		match self {
+			Self::Range { lower, upper, bins } => {
+				let bin = ((value - lower) * bins as ValueType / (upper - lower)).floor() as i64;
+
+				bin.clamp(0, bins as i64 - 1)
+			}
+			Self::Width(width) => (value / width).floor() as i64,
+		}
+	}
+}
+
+/// Moving [Shannon entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory)) of the returns over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// Return is a relative change of the value: (`value` - `prev_value`) / `prev_value`.
+/// Every return is put into one of the bins, configured by [`Binning`],
+/// and entropy is calculated over the frequencies of the bins in the window.
+///
+/// Output value is measured in bits. It is `0.0`, when all the returns fall into the same bin,
+/// and it is `log2(length)`, when every return falls into its own bin.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `binning`: [`Binning`]\)
+///
+/// `length` should be > `0`
+///
+/// For [`Binning::Range`] `lower` should be < `upper` and `bins` should be > `0`.
+///
+/// For [`Binning::Width`] `width` should be > `0.0`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Binning, Entropy};
+///
+/// // returns from -5% to 5% are split into 10 bins
+/// let binning = Binning::Range { lower: -0.05, upper: 0.05, bins: 10 };
+/// let mut entropy = Entropy::new((2, binning), &100.0).unwrap();
+///
+/// // returns are [0%, 2%]
+/// assert_eq!(entropy.next(&102.0), 1.0);
+/// // returns are [2%, 0%]
+/// assert_eq!(entropy.next(&102.0), 1.0);
+/// // returns are [0%, 0%]
+/// assert_eq!(entropy.next(&102.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`))
+///
+/// # See also
+///
+/// [`RateOfChange`](crate::methods::RateOfChange)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Entropy {
+	binning: Binning,
+	prev_value: ValueType,
+	sum: RunningSum,
+	counts: BTreeMap<i64, PeriodType>,
+	window: Window<i64>,
+}
+
+/// Returns `count` * log2(`count`)
+#[inline]
+fn weight(count: PeriodType) -> ValueType {
+	if count > 1 {
+		let count = count as ValueType;
+		count * count.log2()
+	} else {
+		0.0
+	}
+}
+
+impl Entropy {
+	#[inline]
+	fn change_count(&mut self, bin: i64, increment: bool) {
+		let count = self.counts.entry(bin).or_default();
+		let prev_count = *count;
+
+		if increment {
+			*count += 1;
+		} else {
+			*count -= 1;
+		}
+
+		self.sum.add(weight(*count) - weight(prev_count));
+
+		if *count == 0 {
+			self.counts.remove(&bin);
+		}
+	}
+
+	fn recompute(&mut self) {
+		let mut sum = RunningSum::new(0.0);
+		self.counts
+			.values()
+			.for_each(|&count| sum.add(weight(count)));
+		self.sum = sum;
+	}
+}
+
+impl Method for Entropy {
+	type Params = (PeriodType, Binning);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, binning): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+		binning.validate()?;
+
+		let bin = binning.bin(0.0);
+
+		Ok(Self {
+			binning,
+			prev_value: value,
+			sum: RunningSum::new(weight(length)),
+			counts: BTreeMap::from([(bin, length)]),
+			window: Window::new(length, bin),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let bin = self
+			.binning
+			.bin((value - self.prev_value) / self.prev_value);
+		self.prev_value = value;
+
+		let prev_bin = self.window.push(bin);
+		if prev_bin != bin {
+			self.change_count(prev_bin, false);
+			self.change_count(bin, true);
+		}
+
+		if cfg!(feature = "stable_sums") && self.window.is_aligned() {
+			self.recompute();
+		}
+
+		self.peek()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Entropy {
+	fn peek(&self) -> <Self as Method>::Output {
+		let length = self.window.len() as ValueType;
+
+		// H = log2(N) - Σ(count * log2(count)) / N
+		(length.log2() - self.sum.value() / length).max(0.0)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Binning, Entropy as TestingMethod, Method};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const_float, test_warmup};
+	use std::collections::HashMap;
+
+	const BINNINGS: [Binning; 4] = [
+		Binning::Width(0.001),
+		Binning::Width(0.01),
+		Binning::Range {
+			lower: -0.01,
+			upper: 0.01,
+			bins: 8,
+		},
+		Binning::Range {
+			lower: 0.0,
+			upper: 0.1,
+			bins: 3,
+		},
+	];
+
+	fn naive_entropy(returns: &[ValueType], binning: Binning) -> ValueType {
+		let mut counts = HashMap::new();
+		for &x in returns {
+			*counts.entry(binning.bin(x)).or_insert(0_usize) += 1;
+		}
+
+		let length = returns.len() as ValueType;
+		counts
+			.values()
+			.map(|&count| {
+				let p = count as ValueType / length;
+				-p * p.log2()
+			})
+			.sum()
+	}
+
+	#[test]
+	fn test_entropy_const() {
+		for binning in BINNINGS {
+			for i in 1..255 {
+				let input = (i as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((i, binning), &input).unwrap();
+
+				test_const_float(&mut method, &input, 0.0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_entropy() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for binning in BINNINGS {
+			for length in [
+				1, 2, 3, 4, 5, 7, 10, 11, 13, 17, 20, 21, 25, 70, 77, 100, 125, 128, 173, 254,
+			] {
+				let mut method = TestingMethod::new((length, binning), &src[0]).unwrap();
+				let mut returns = vec![0.0; length as usize];
+
+				src.iter().enumerate().for_each(|(i, x)| {
+					let prev = src[i.saturating_sub(1)];
+					returns.remove(0);
+					returns.push((x - prev) / prev);
+
+					assert_eq_float(naive_entropy(&returns, binning), method.next(x));
+				});
+			}
+		}
+	}
+
+	#[test]
+	fn test_entropy_periodic() {
+		// returns repeat with the period, which evenly divides the window, so every return has the same frequency
+		let returns = [-0.021, 0.0, 0.013, -0.007, 0.036, 0.027, -0.044];
+
+		for period in 1..=returns.len() {
+			for periods in 1..10 {
+				let length = PeriodType::try_from(period * periods).unwrap();
+				let mut method =
+					TestingMethod::new((length, Binning::Width(0.005)), &100.0).unwrap();
+				let mut value = 100.0;
+
+				for i in 0..length as usize * 3 {
+					value *= 1.0 + returns[i % period];
+					let entropy = method.next(&value);
+
+					if i >= length as usize {
+						assert_eq_float((period as ValueType).log2(), entropy);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_entropy_skewed() {
+		// 3 of every 4 returns fall into the same bin
+		let returns = [0.01, 0.01, 0.01, -0.01];
+		let expected = -(0.75 * ValueType::log2(0.75) + 0.25 * ValueType::log2(0.25));
+
+		let mut method = TestingMethod::new((40, Binning::Width(0.005)), &100.0).unwrap();
+		let mut value = 100.0;
+
+		for i in 0..200 {
+			value *= 1.0 + returns[i % 4];
+			let entropy = method.next(&value);
+
+			if i >= 40 {
+				assert_eq_float(expected, entropy);
+			}
+		}
+	}
+
+	#[test]
+	fn test_entropy_parameters() {
+		assert!(TestingMethod::new((0, Binning::Width(0.01)), &1.0).is_err());
+		assert!(TestingMethod::new((1, Binning::Width(0.01)), &1.0).is_ok());
+		assert!(TestingMethod::new((10, Binning::Width(0.0)), &1.0).is_err());
+		assert!(TestingMethod::new((10, Binning::Width(ValueType::INFINITY)), &1.0).is_err());
+
+		let range = |lower, upper, bins| Binning::Range { lower, upper, bins };
+		assert!(TestingMethod::new((10, range(0.1, 0.1, 5)), &1.0).is_err());
+		assert!(TestingMethod::new((10, range(0.1, -0.1, 5)), &1.0).is_err());
+		assert!(TestingMethod::new((10, range(-0.1, 0.1, 0)), &1.0).is_err());
+		assert!(TestingMethod::new((10, range(-0.1, 0.1, 1)), &1.0).is_ok());
+	}
+
+	#[test]
+	fn test_entropy_warmup() {
+		// first returns after the different initial values of `test_warmup` are a bit lower and a bit higher than -100%,
+		// so they fall into the different bins
+		let binning = Binning::Range {
+			lower: -3.0,
+			upper: 1.0,
+			bins: 2,
+		};
+
+		// entropy over the single return is always `0.0`
+		for length in 2..100 {
+			test_warmup::<TestingMethod>((length, binning));
+		}
+	}
+}
@@ -0,0 +1,202 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+use crate::methods::{Highest, Lowest};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers' [Fractal Adaptive Moving Average](https://www.mesasoftware.com/papers/FRAMA.pdf) of specified `length` for timeseries of type [`ValueType`]
+///
+/// Measures the fractal dimension D of the values over the window of `length` bars and uses it as the smoothing factor of
+/// the exponential average: `alpha` = exp(-4.6 × (D - 1)), which is clamped to \[`0.01`; `1.0`\].
+/// So it follows the values fast in the trends (D → `1`) and almost stops in the congestion zones (D → `2`).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be even and > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::FRAMA;
+///
+/// let mut frama = FRAMA::new(4, &1.0).unwrap();
+///
+/// // straight line has the lowest fractal dimension, so it is followed without any lag
+/// assert_eq!(frama.next(&2.0), 2.0);
+/// assert_eq!(frama.next(&3.0), 3.0);
+/// assert_eq!(frama.next(&4.0), 4.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [EMA](crate::methods::EMA), [KAMA](crate::indicators::KAMA), [Vidya](crate::methods::Vidya)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[doc(alias = "FractalAdaptive")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FRAMA {
+	highest: Highest,
+	lowest: Lowest,
+	/// highest and lowest values of the older half of the window
+	halves: Window<(ValueType, ValueType)>,
+	alpha: ValueType,
+	value: ValueType,
+}
+
+impl Method for FRAMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			length > 0 && length % 2 == 0,
+			"length",
+			length,
+			"must be even and > 0",
+		)?;
+
+		let half = length / 2;
+
+		Ok(Self {
+			highest: Highest::new(half, &value)?,
+			lowest: Lowest::new(half, &value)?,
+			halves: Window::new(half, (value, value)),
+			alpha: 1.0,
+			value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let newer = (self.highest.next(&value), self.lowest.next(&value));
+		let older = self.halves.push(newer);
+
+		let half = self.halves.len() as ValueType;
+		let n1 = (newer.0 - newer.1) / half;
+		let n2 = (older.0 - older.1) / half;
+		let n3 = (newer.0.max(older.0) - newer.1.min(older.1)) / (2.0 * half);
+
+		// keep the previous dimension, when there is no range to measure it
+		if n1 > 0.0 && n2 > 0.0 && n3 > 0.0 {
+			let dimension = ((n1 + n2) / n3).log2();
+			self.alpha = (-4.6 * (dimension - 1.0)).exp().clamp(0.01, 1.0);
+		}
+
+		self.value = (value - self.value).mul_add(self.alpha, self.value);
+
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.halves.len() as usize * 2 - 1
+	}
+}
+
+impl MovingAverage for FRAMA {}
+
+impl Peekable<<Self as Method>::Output> for FRAMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, FRAMA as TestingMethod};
+	use crate::core::{MovingAverageConstructor, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles, MA};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_frama_const() {
+		for i in (2..255).step_by(2) {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_frama() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let range = |values: &[ValueType]| {
+			let highest = values.iter().copied().fold(ValueType::MIN, ValueType::max);
+			let lowest = values.iter().copied().fold(ValueType::MAX, ValueType::min);
+			highest - lowest
+		};
+
+		for length in (2..100).step_by(2) {
+			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
+			let (length, half) = (length as usize, length as usize / 2);
+
+			// the window before the first value is filled with the initial value
+			let padded: Vec<ValueType> = std::iter::repeat_n(src[0], length)
+				.chain(src.iter().copied())
+				.collect();
+
+			let mut value = src[0];
+			let mut alpha = 1.0;
+			for i in 0..src.len() {
+				let window = &padded[i + 1..=i + length];
+				let n1 = range(&window[half..]) / half as ValueType;
+				let n2 = range(&window[..half]) / half as ValueType;
+				let n3 = range(window) / length as ValueType;
+
+				if n1 > 0.0 && n2 > 0.0 && n3 > 0.0 {
+					let dimension = ((n1 + n2) / n3).log2();
+					alpha = (-4.6 * (dimension - 1.0)).exp().clamp(0.01, 1.0);
+				}
+				value = alpha * src[i] + (1.0 - alpha) * value;
+
+				assert_eq_float(value, ma.next(&src[i]));
+			}
+		}
+	}
+
+	#[test]
+	fn test_frama_parameters() {
+		assert!(TestingMethod::new(0, &1.0).is_err());
+		assert!(TestingMethod::new(3, &1.0).is_err());
+		assert!(TestingMethod::new(2, &1.0).is_ok());
+	}
+
+	#[test]
+	fn test_frama_ma_odd_length() {
+		assert_eq!(MA::FRAMA(3).init(1.0).unwrap().warmup_len(), 3);
+
+		// the window of the maximum length is too large to be allocated for the wider period types
+		#[cfg(not(any(feature = "period_type_u32", feature = "period_type_u64")))]
+		assert_eq!(
+			MA::FRAMA(crate::core::PeriodType::MAX)
+				.init(1.0)
+				.unwrap()
+				.warmup_len(),
+			crate::core::PeriodType::MAX as usize - 2
+		);
+	}
+}
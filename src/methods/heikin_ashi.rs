@@ -25,8 +25,7 @@ impl Method for HeikinAshi {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		let open = (self.prev.open() + self.prev.close()) * 0.5;
 		let close = value.ohlc4();
-		self.prev = 
-		Candle {
+		self.prev = Candle {
 			open,
 			high: value.high().max(open),
 			low: value.low().min(open),
@@ -35,6 +34,10 @@ impl Method for HeikinAshi {
 		};
 		self.prev.clone()
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
 
 #[cfg(test)]
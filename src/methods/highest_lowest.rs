@@ -1,6 +1,7 @@
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, ValueType};
 use crate::helpers::Peekable;
+use std::collections::VecDeque;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -42,9 +43,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Performance
 ///
-/// O(`length`)
-///
-/// This method is relatively very slow compare to the other methods.
+/// Amortized O(1)
 ///
 /// # See also
 ///
@@ -57,11 +56,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HighestLowestDelta {
-	// highest: Highest,
-	// lowest: Lowest,
-	highest: ValueType,
-	lowest: ValueType,
-	window: Window<ValueType>,
+	highest: MonotonicQueue<true>,
+	lowest: MonotonicQueue<false>,
 }
 
 impl Method for HighestLowestDelta {
@@ -78,50 +74,30 @@ impl Method for HighestLowestDelta {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
-				window: Window::new(length, value),
-				highest: value,
-				lowest: value,
+				highest: MonotonicQueue::new(length, value),
+				lowest: MonotonicQueue::new(length, value),
 			}),
 		}
 	}
 
 	#[inline]
 	fn next(&mut self, &value: &Self::Input) -> ValueType {
-		let left_value = self.window.push(value);
-
-		let mut search = false;
-		if value >= self.highest {
-			self.highest = value;
-		// It's not a mistake. We really need a bit-to-bit comparison of float values here
-		} else if left_value.to_bits() == self.highest.to_bits() {
-			search = true;
-		}
-
-		if value <= self.lowest {
-			self.lowest = value;
-		// It's not a mistake. We really need a bit-to-bit comparison of float values here
-		} else if left_value.to_bits() == self.lowest.to_bits() {
-			search = true;
-		}
+		self.highest.push(value);
+		self.lowest.push(value);
 
-		if search {
-			let (min, max) = self
-				.window
-				.iter()
-				.fold((value, value), |(min, max), &v| (min.min(v), max.max(v)));
-			self.highest = max;
-			self.lowest = min;
-		}
+		self.peek()
+	}
 
-		self.highest - self.lowest
+	fn warmup_len(&self) -> usize {
+		self.highest.warmup_len()
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for HighestLowestDelta {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.highest - self.lowest
+		self.highest.value() - self.lowest.value()
 	}
 }
 
@@ -160,9 +136,7 @@ impl Peekable<<Self as Method>::Output> for HighestLowestDelta {
 ///
 /// # Performance
 ///
-/// O(`length`)
-///
-/// This method is relatively slow compare to the other methods.
+/// Amortized O(1)
 ///
 /// # See also
 ///
@@ -174,10 +148,7 @@ impl Peekable<<Self as Method>::Output> for HighestLowestDelta {
 /// [`LowestIndex`]: crate::methods::LowestIndex
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Highest {
-	value: ValueType,
-	window: Window<ValueType>,
-}
+pub struct Highest(MonotonicQueue<true>);
 
 impl Method for Highest {
 	type Params = PeriodType;
@@ -190,11 +161,8 @@ impl Method for Highest {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				value,
-			}),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
+			length => Ok(Self(MonotonicQueue::new(length, value))),
 		}
 	}
 
@@ -205,22 +173,18 @@ impl Method for Highest {
 			"Highest method cannot operate with NAN values"
 		);
 
-		let left_value = self.window.push(value);
-
-		if value >= self.value {
-			self.value = value;
-		// It's not a mistake. We really need a bit-to-bit comparison of float values here
-		} else if left_value.to_bits() == self.value.to_bits() {
-			self.value = self.window.iter().fold(value, |a, &b| a.max(b));
-		}
+		self.0.push(value);
+		self.0.value()
+	}
 
-		self.value
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for Highest {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.value
+		self.0.value()
 	}
 }
 
@@ -259,9 +223,7 @@ impl Peekable<<Self as Method>::Output> for Highest {
 ///
 /// # Performance
 ///
-/// O(`length`)
-///
-/// This method is relatively slow compare to the other methods.
+/// Amortized O(1)
 ///
 /// # See also
 ///
@@ -273,10 +235,7 @@ impl Peekable<<Self as Method>::Output> for Highest {
 /// [`LowestIndex`]: crate::methods::LowestIndex
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Lowest {
-	value: ValueType,
-	window: Window<ValueType>,
-}
+pub struct Lowest(MonotonicQueue<false>);
 
 impl Method for Lowest {
 	type Params = PeriodType;
@@ -289,11 +248,8 @@ impl Method for Lowest {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				value,
-			}),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
+			length => Ok(Self(MonotonicQueue::new(length, value))),
 		}
 	}
 
@@ -304,22 +260,95 @@ impl Method for Lowest {
 			"Lowest method cannot operate with NAN values"
 		);
 
-		let left_value = self.window.push(value);
-
-		if value <= self.value {
-			self.value = value;
-		// It's not a mistake. We really need a bit-to-bit comparison of float values here
-		} else if left_value.to_bits() == self.value.to_bits() {
-			self.value = self.window.iter().fold(value, |a, &b| a.min(b));
-		}
+		self.0.push(value);
+		self.0.value()
+	}
 
-		self.value
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for Lowest {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.value
+		self.0.value()
+	}
+}
+
+/// Sliding window of the last `length` values, which keeps only the candidates for the extremum
+///
+/// Values are kept in the order of their arrival and are monotonic: non-increasing for `HIGHEST = true`
+/// and non-decreasing otherwise. So the front of the queue is always the extremum of the window.
+/// Every value is pushed and popped at most once, which gives amortized O(1) per step.
+///
+/// When there are several equal extremums, only the newest one is kept.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct MonotonicQueue<const HIGHEST: bool> {
+	/// `(position, value)` pairs
+	queue: VecDeque<(usize, ValueType)>,
+	position: usize,
+	length: PeriodType,
+}
+
+impl<const HIGHEST: bool> MonotonicQueue<HIGHEST> {
+	/// Creates a queue as if it is filled with `length` copies of `value`
+	pub(super) fn new(length: PeriodType, value: ValueType) -> Self {
+		let mut queue = VecDeque::with_capacity(length as usize);
+		queue.push_back((0, value));
+
+		Self {
+			queue,
+			position: 0,
+			length,
+		}
+	}
+
+	#[inline]
+	pub(super) fn push(&mut self, value: ValueType) {
+		self.position = self.position.wrapping_add(1);
+
+		if self.age(self.queue[0].0) >= self.length as usize {
+			self.queue.pop_front();
+		}
+
+		while let Some(&(_, last)) = self.queue.back() {
+			let dominated = if HIGHEST {
+				value >= last
+			} else {
+				value <= last
+			};
+
+			if !dominated {
+				break;
+			}
+
+			self.queue.pop_back();
+		}
+
+		self.queue.push_back((self.position, value));
+	}
+
+	/// Returns the extremum over the window
+	#[inline]
+	pub(super) fn value(&self) -> ValueType {
+		self.queue[0].1
+	}
+
+	/// Returns how many steps ago the extremum was pushed
+	#[inline]
+	#[allow(clippy::cast_possible_truncation)]
+	pub(super) fn index(&self) -> PeriodType {
+		self.age(self.queue[0].0) as PeriodType
+	}
+
+	pub(super) const fn warmup_len(&self) -> usize {
+		(self.length as usize).saturating_sub(1)
+	}
+
+	#[inline]
+	const fn age(&self, position: usize) -> usize {
+		self.position.wrapping_sub(position)
 	}
 }
 
@@ -328,7 +357,7 @@ mod tests {
 	use super::{Highest, HighestLowestDelta, Lowest};
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_warmup};
 
 	#[test]
 	fn test_highest_const() {
@@ -458,4 +487,13 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_highest_lowest_warmup() {
+		for length in 1..100 {
+			test_warmup::<Highest>(length);
+			test_warmup::<Lowest>(length);
+			test_warmup::<HighestLowestDelta>(length);
+		}
+	}
 }
@@ -1,5 +1,6 @@
+use super::highest_lowest::MonotonicQueue;
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, ValueType};
 use crate::helpers::Peekable;
 
 #[cfg(feature = "serde")]
@@ -42,9 +43,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Performance
 ///
-/// O(`length`)
-///
-/// This method is relatively slow compare to the other methods.
+/// Amortized O(1)
 ///
 /// # See also
 ///
@@ -57,11 +56,7 @@ use serde::{Deserialize, Serialize};
 /// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct HighestIndex {
-	index: PeriodType,
-	value: ValueType,
-	window: Window<ValueType>,
-}
+pub struct HighestIndex(MonotonicQueue<true>);
 
 impl Method for HighestIndex {
 	type Params = PeriodType;
@@ -74,12 +69,8 @@ impl Method for HighestIndex {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				index: 0,
-				value,
-			}),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
+			length => Ok(Self(MonotonicQueue::new(length, value))),
 		}
 	}
 
@@ -90,32 +81,18 @@ impl Method for HighestIndex {
 			"HighestIndex method cannot operate with NAN values"
 		);
 
-		self.window.push(value);
-		self.index += 1;
-
-		#[allow(clippy::cast_possible_truncation)]
-		if value >= self.value {
-			self.value = value;
-			self.index = 0;
-		} else if self.index == self.window.len() {
-			let (index, value) = self
-				.window
-				.iter()
-				.copied()
-				.enumerate()
-				.fold((0, value), |a, b| if b.1 > a.1 { b } else { a });
-
-			self.index = index as PeriodType; // self.window.len() - index as PeriodType - 1;
-			self.value = value;
-		}
+		self.0.push(value);
+		self.0.index()
+	}
 
-		self.index
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for HighestIndex {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.index
+		self.0.index()
 	}
 }
 
@@ -156,9 +133,7 @@ impl Peekable<<Self as Method>::Output> for HighestIndex {
 ///
 /// # Performance
 ///
-/// O(`length`)
-///
-/// This method is relatively slow compare to the other methods.
+/// Amortized O(1)
 ///
 /// # See also
 ///
@@ -171,11 +146,7 @@ impl Peekable<<Self as Method>::Output> for HighestIndex {
 /// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct LowestIndex {
-	index: PeriodType,
-	value: ValueType,
-	window: Window<ValueType>,
-}
+pub struct LowestIndex(MonotonicQueue<false>);
 
 impl Method for LowestIndex {
 	type Params = PeriodType;
@@ -188,12 +159,8 @@ impl Method for LowestIndex {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				index: 0,
-				value,
-			}),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
+			length => Ok(Self(MonotonicQueue::new(length, value))),
 		}
 	}
 
@@ -204,32 +171,18 @@ impl Method for LowestIndex {
 			"LowestIndex method cannot operate with NAN values"
 		);
 
-		self.window.push(value);
-		self.index += 1;
-
-		#[allow(clippy::cast_possible_truncation)]
-		if value <= self.value {
-			self.value = value;
-			self.index = 0;
-		} else if self.index == self.window.len() {
-			let (index, value) = self
-				.window
-				.iter()
-				.copied()
-				.enumerate()
-				.fold((0, value), |a, b| if b.1 < a.1 { b } else { a });
-
-			self.index = index as PeriodType; // self.window.len() - index as PeriodType - 1;
-			self.value = value;
-		}
+		self.0.push(value);
+		self.0.index()
+	}
 
-		self.index
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for LowestIndex {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.index
+		self.0.index()
 	}
 }
 
@@ -362,4 +315,27 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_highest_lowest_index_ties() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close.round())
+			.collect();
+
+		(1..40).for_each(|length| {
+			let mut highest = HighestIndex::new(length, &src[0]).unwrap();
+			let mut lowest = LowestIndex::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let past = |j: usize| src[i.saturating_sub(j)];
+				let max_index = (0..length).fold(0, |m, j| if past(j) > past(m) { j } else { m });
+				let min_index = (0..length).fold(0, |m, j| if past(j) < past(m) { j } else { m });
+
+				assert_eq!(max_index, highest.next(x) as usize);
+				assert_eq!(min_index, lowest.next(x) as usize);
+			});
+		});
+	}
 }
@@ -70,7 +70,7 @@ impl Method for HMA {
 		#[allow(clippy::cast_possible_truncation)]
 		#[allow(clippy::cast_sign_loss)]
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
+			0 | 1 => Err(Error::invalid_parameter("length", length, "must be > 1")),
 			length => Ok(Self {
 				wma1: WMA::new(length / 2, value)?,
 				wma2: WMA::new(length, value)?,
@@ -86,6 +86,10 @@ impl Method for HMA {
 
 		self.wma3.next(&w1.mul_add(2., -w2))
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.wma1.warmup_len().max(self.wma2.warmup_len()) + self.wma3.warmup_len()
+	}
 }
 
 impl MovingAverage for HMA {}
@@ -102,7 +106,7 @@ mod tests {
 	use crate::core::Method;
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const_float;
+	use crate::methods::tests::{test_const_float, test_warmup};
 
 	#[test]
 	fn test_hma_const() {
@@ -138,4 +142,11 @@ mod tests {
 			}
 		});
 	}
+
+	#[test]
+	fn test_hma_warmup() {
+		for length in 2..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
 }
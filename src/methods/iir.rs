@@ -0,0 +1,1113 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use std::f64::consts::PI;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coefficients of the [`Biquad`] section, normalized so `a0` = `1.0`
+///
+/// Transfer function is H(z) = (`b0` + `b1`z⁻¹ + `b2`z⁻²) / (`1` + `a1`z⁻¹ + `a2`z⁻²).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BiquadCoefficients {
+	/// Feedforward coefficient of the current value
+	pub b0: ValueType,
+	/// Feedforward coefficient of the previous value
+	pub b1: ValueType,
+	/// Feedforward coefficient of the value 2 bars ago
+	pub b2: ValueType,
+	/// Feedback coefficient of the previous output value
+	pub a1: ValueType,
+	/// Feedback coefficient of the output value 2 bars ago
+	pub a2: ValueType,
+}
+
+impl BiquadCoefficients {
+	fn validate(self) -> Result<(), Error> {
+		let Self { b0, b1, b2, a1, a2 } = self;
+
+		Error::ensure(
+			[b0, b1, b2, a1, a2].iter().all(|x| x.is_finite()),
+			"coefficients",
+			format_args!("{self:?}"),
+			"must be finite numbers",
+		)?;
+		// both poles are inside the unit circle
+		Error::ensure(
+			a2.abs() < 1.0 && a1.abs() < 1.0 + a2,
+			"coefficients",
+			format_args!("{self:?}"),
+			"must describe a stable filter: |a2| < 1 and |a1| < 1 + a2",
+		)
+	}
+
+	/// Returns gain of the section for the constant values
+	fn dc_gain(self) -> ValueType {
+		(self.b0 + self.b1 + self.b2) / (1.0 + self.a1 + self.a2)
+	}
+
+	/// Returns count of bars, after which impulse response of the slowest pole decays below 1%
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::useless_conversion // when `ValueType` is `f64`
+	)]
+	fn settling_len(self) -> usize {
+		let (a1, a2) = (f64::from(self.a1), f64::from(self.a2));
+		let discriminant = a1.mul_add(a1, -4.0 * a2);
+
+		let radius = if discriminant < 0.0 {
+			a2.sqrt()
+		} else {
+			a1.abs().midpoint(discriminant.sqrt())
+		};
+
+		let len = if radius > 0.0 {
+			0.01f64.log(radius).ceil() as usize
+		} else {
+			0
+		};
+
+		len.max(2)
+	}
+}
+
+/// [Biquad](https://en.wikipedia.org/wiki/Digital_biquad_filter) filter section for timeseries of type [`ValueType`]
+///
+/// Second order recursive filter, which is a building block for the higher order [`IirFilter`]s.
+///
+/// At the start it is in the steady state for the constant `value`.
+///
+/// # Parameters
+///
+/// Has a single parameter `coefficients`: [`BiquadCoefficients`]
+///
+/// Coefficients should be finite and should describe a stable filter: |`a2`| < `1` and |`a1`| < `1` + `a2`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Biquad, BiquadCoefficients};
+///
+/// // y = 0.5x + 0.5y[-1], which is the same as EMA of length 3
+/// let coefficients = BiquadCoefficients { b0: 0.5, b1: 0.0, b2: 0.0, a1: -0.5, a2: 0.0 };
+/// let mut biquad = Biquad::new(coefficients, &3.0).unwrap();
+///
+/// assert_eq!(biquad.next(&3.0), 3.0);
+/// assert_eq!(biquad.next(&6.0), 4.5);
+/// assert_eq!(biquad.next(&9.0), 6.75);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`IirFilter`], [`EMA`](crate::methods::EMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Biquad {
+	coefficients: BiquadCoefficients,
+	s1: ValueType,
+	s2: ValueType,
+	value: ValueType,
+}
+
+impl Biquad {
+	/// Returns coefficients of the section
+	#[must_use]
+	pub const fn coefficients(&self) -> BiquadCoefficients {
+		self.coefficients
+	}
+}
+
+impl Method for Biquad {
+	type Params = BiquadCoefficients;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(coefficients: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		coefficients.validate()?;
+
+		let BiquadCoefficients { b0, b2, a2, .. } = coefficients;
+		let output = coefficients.dc_gain() * value;
+
+		// transposed direct form II state for the constant input
+		Ok(Self {
+			coefficients,
+			s1: b0.mul_add(-value, output),
+			s2: b2.mul_add(value, -a2 * output),
+			value: output,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let BiquadCoefficients { b0, b1, b2, a1, a2 } = self.coefficients;
+
+		self.value = b0.mul_add(value, self.s1);
+		self.s1 = b1.mul_add(value, a1.mul_add(-self.value, self.s2));
+		self.s2 = b2.mul_add(value, -a2 * self.value);
+
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.coefficients.settling_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Biquad {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+/// Frequency band of the [`IirFilter`] designs
+///
+/// Cutoffs are expressed as periods in bars and should be finite numbers > `2.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FilterBand {
+	/// Keeps the trend and removes the cycles shorter than `period` bars
+	LowPass {
+		/// Cutoff period
+		period: ValueType,
+	},
+
+	/// Removes the trend and the cycles longer than `period` bars
+	HighPass {
+		/// Cutoff period
+		period: ValueType,
+	},
+
+	/// Keeps only the cycles from `short_period` to `long_period` bars
+	BandPass {
+		/// Shortest period of the band
+		short_period: ValueType,
+		/// Longest period of the band, should be > `short_period`
+		long_period: ValueType,
+	},
+}
+
+impl FilterBand {
+	fn validate(self) -> Result<(), Error> {
+		let validate_period = |name, period: ValueType| {
+			Error::ensure(
+				period > 2.0 && period.is_finite(),
+				name,
+				period,
+				"must be a finite number > 2.0",
+			)
+		};
+
+		match self {
+			Self::LowPass { period } | Self::HighPass { period } => {
+				validate_period("period", period)
+			}
+			Self::BandPass {
+				short_period,
+				long_period,
+			} => {
+				validate_period("short_period", short_period)?;
+				validate_period("long_period", long_period)?;
+				Error::ensure(
+					long_period > short_period,
+					"long_period",
+					long_period,
+					format_args!("must be > short_period {short_period}"),
+				)
+			}
+		}
+	}
+
+	/// Returns sections of the filter, designed from the poles of the normalized analog low-pass prototype
+	///
+	/// `poles` contains only one pole of the every complex conjugate pair.
+	#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+	fn design(self, poles: &[Complex]) -> Vec<BiquadCoefficients> {
+		// prewarped analog frequency for the bilinear transform s = 2(1 - z⁻¹) / (1 + z⁻¹)
+		#[allow(clippy::useless_conversion)] // when `ValueType` is `f32`
+		let warp = |period: ValueType| 2.0 * (PI / f64::from(period)).tan();
+		let digital = |s: Complex| (Complex::real(2.0) + s) / (Complex::real(2.0) - s);
+
+		// numerator, denominator and the frequency to normalize the gain at
+		let mut sections: Vec<([f64; 3], [f64; 2])> = Vec::with_capacity(poles.len() * 2);
+		let frequency = match self {
+			Self::LowPass { period } => {
+				let cutoff = warp(period);
+				for &pole in poles {
+					sections.push(section(&[digital(pole * cutoff)], 1.0));
+				}
+
+				0.0
+			}
+			Self::HighPass { period } => {
+				let cutoff = warp(period);
+				for &pole in poles {
+					sections.push(section(&[digital(Complex::real(cutoff) / pole)], -1.0));
+				}
+
+				PI
+			}
+			Self::BandPass {
+				short_period,
+				long_period,
+			} => {
+				let (lower, upper) = (warp(long_period), warp(short_period));
+				let (center2, width) = (lower * upper, upper - lower);
+
+				// every prototype pole p gives 2 poles: s² - p * width * s + center² = 0
+				for &pole in poles {
+					let b = pole * width;
+					let root = (b * b - Complex::real(4.0 * center2)).sqrt();
+					let (s1, s2) = ((b + root) * 0.5, (b - root) * 0.5);
+
+					if pole.im == 0.0 {
+						sections.push(section(&[digital(s1), digital(s2)], 0.0));
+					} else {
+						sections.push(section(&[digital(s1)], 0.0));
+						sections.push(section(&[digital(s2)], 0.0));
+					}
+				}
+
+				2.0 * (center2.sqrt() / 2.0).atan()
+			}
+		};
+
+		sections
+			.into_iter()
+			.map(|(b, a)| {
+				// unit gain at the `frequency`
+				let z1 = Complex::from_angle(-frequency);
+				let z2 = z1 * z1;
+				let num = Complex::real(b[0]) + z1 * b[1] + z2 * b[2];
+				let den = Complex::real(1.0) + z1 * a[0] + z2 * a[1];
+				let gain = den.abs() / num.abs();
+
+				BiquadCoefficients {
+					b0: (b[0] * gain) as ValueType,
+					b1: (b[1] * gain) as ValueType,
+					b2: (b[2] * gain) as ValueType,
+					a1: a[0] as ValueType,
+					a2: a[1] as ValueType,
+				}
+			})
+			.collect()
+	}
+}
+
+/// Returns numerator and denominator of the section with the digital `poles`
+///
+/// Single complex pole also stands for its conjugate pair, single real pole gives the first order section.
+/// Zeros are placed at z = `-1` for `sign` = `1` (low-pass), at z = `1` for `sign` = `-1` (high-pass)
+/// or at both of them for `sign` = `0` (band-pass).
+fn section(poles: &[Complex], sign: f64) -> ([f64; 3], [f64; 2]) {
+	let a = match *poles {
+		[pole] if pole.im == 0.0 => [-pole.re, 0.0],
+		[pole] => [-2.0 * pole.re, pole.re.mul_add(pole.re, pole.im * pole.im)],
+		_ => {
+			let (sum, product) = (poles[0] + poles[1], poles[0] * poles[1]);
+			[-sum.re, product.re]
+		}
+	};
+
+	let b = if sign == 0.0 {
+		[1.0, 0.0, -1.0]
+	} else if a[1] == 0.0 {
+		[1.0, sign, 0.0]
+	} else {
+		[1.0, 2.0 * sign, 1.0]
+	};
+
+	(b, a)
+}
+
+/// Minimal complex numbers arithmetic for the filters design
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+	re: f64,
+	im: f64,
+}
+
+impl Complex {
+	const fn real(re: f64) -> Self {
+		Self { re, im: 0.0 }
+	}
+
+	fn from_angle(angle: f64) -> Self {
+		Self {
+			re: angle.cos(),
+			im: angle.sin(),
+		}
+	}
+
+	fn abs(self) -> f64 {
+		self.re.hypot(self.im)
+	}
+
+	fn sqrt(self) -> Self {
+		let abs = self.abs();
+		let re = abs.midpoint(self.re).sqrt();
+		let im = ((abs - self.re) / 2.0).sqrt();
+
+		Self {
+			re,
+			im: if self.im < 0.0 { -im } else { im },
+		}
+	}
+}
+
+impl std::ops::Add for Complex {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self {
+			re: self.re + rhs.re,
+			im: self.im + rhs.im,
+		}
+	}
+}
+
+impl std::ops::Sub for Complex {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self {
+			re: self.re - rhs.re,
+			im: self.im - rhs.im,
+		}
+	}
+}
+
+impl std::ops::Mul for Complex {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		Self {
+			re: self.re.mul_add(rhs.re, -self.im * rhs.im),
+			im: self.re.mul_add(rhs.im, self.im * rhs.re),
+		}
+	}
+}
+
+impl std::ops::Mul<f64> for Complex {
+	type Output = Self;
+
+	fn mul(self, rhs: f64) -> Self {
+		Self {
+			re: self.re * rhs,
+			im: self.im * rhs,
+		}
+	}
+}
+
+impl std::ops::Div for Complex {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self {
+		let norm = rhs.re.mul_add(rhs.re, rhs.im * rhs.im);
+
+		Self {
+			re: self.re.mul_add(rhs.re, self.im * rhs.im) / norm,
+			im: self.im.mul_add(rhs.re, -self.re * rhs.im) / norm,
+		}
+	}
+}
+
+const MAX_ORDER: u8 = 16;
+
+fn validate_order(order: u8) -> Result<(), Error> {
+	Error::ensure(
+		(1..=MAX_ORDER).contains(&order),
+		"order",
+		order,
+		format_args!("must be in [1; {MAX_ORDER}]"),
+	)
+}
+
+/// Returns upper half-plane poles of the analog Butterworth low-pass prototype with the unit cutoff
+fn butterworth_poles(order: u8) -> Vec<Complex> {
+	let n = f64::from(order);
+
+	(0..order.div_ceil(2))
+		.map(|k| Complex::from_angle(PI * 2.0f64.mul_add(f64::from(k), n + 1.0) / (2.0 * n)))
+		.map(|pole| {
+			if pole.im.abs() < 1e-12 {
+				Complex::real(pole.re)
+			} else {
+				pole
+			}
+		})
+		.collect()
+}
+
+/// Returns upper half-plane poles of the analog Chebyshev type I low-pass prototype with the unit cutoff
+fn chebyshev_poles(order: u8, ripple: ValueType) -> Vec<Complex> {
+	let n = f64::from(order);
+	#[allow(clippy::useless_conversion)] // when `ValueType` is `f32`
+	let epsilon = (10f64.powf(f64::from(ripple) / 10.0) - 1.0).sqrt();
+	let mu = epsilon.recip().asinh() / n;
+
+	(0..order.div_ceil(2))
+		.map(|k| {
+			let theta = PI * 2.0f64.mul_add(f64::from(k), 1.0) / (2.0 * n);
+			let im = mu.cosh() * theta.cos();
+
+			Complex {
+				re: -mu.sinh() * theta.sin(),
+				im: if im.abs() < 1e-12 { 0.0 } else { im },
+			}
+		})
+		.collect()
+}
+
+/// Recursive filter for timeseries of type [`ValueType`], which is a cascade of the [`Biquad`] sections
+///
+/// # Parameters
+///
+/// Has a single parameter `sections`: Vec<[`BiquadCoefficients`]>
+///
+/// `sections` vector's length must be > `0`
+///
+/// # Designs
+///
+/// There are constructors, which design the `sections` for the [`FilterBand`]:
+///
+/// * [`IirFilter::butterworth`] has the flattest passband;
+/// * [`IirFilter::chebyshev`] has the sharper cutoff at the cost of the ripples in the passband.
+///
+/// Designs use the bilinear transform, so the cutoff periods are exact.
+/// Low-pass filters have the unit gain for the constant values,
+/// high-pass filters have the unit gain for the cycles of `2` bars,
+/// and band-pass filters have the unit gain in the center of the band.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{FilterBand, IirFilter};
+///
+/// // keeps only the cycles from 10 to 40 bars
+/// let band = FilterBand::BandPass { short_period: 10.0, long_period: 40.0 };
+/// let mut band_pass = IirFilter::butterworth(band, 2, &100.0).unwrap();
+///
+/// // constant value has no cycles
+/// assert!(band_pass.next(&100.0).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// O(`sections` length)
+///
+/// # See also
+///
+/// [`Butterworth`], [`Chebyshev`], [`Conv`](crate::methods::Conv)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IirFilter {
+	sections: Vec<Biquad>,
+}
+
+impl IirFilter {
+	/// Creates [Butterworth](https://en.wikipedia.org/wiki/Butterworth_filter) filter of the `order` for the `band`
+	///
+	/// For the band-pass filter `order` is the order of the prototype, so the resulting filter has twice the order.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `band` is invalid or `order` is not in \[`1`; `16`\]
+	pub fn butterworth(band: FilterBand, order: u8, value: &ValueType) -> Result<Self, Error> {
+		band.validate()?;
+		validate_order(order)?;
+
+		Self::new(band.design(&butterworth_poles(order)), value)
+	}
+
+	/// Creates [Chebyshev type I](https://en.wikipedia.org/wiki/Chebyshev_filter) filter of the `order` with the passband `ripple` in dB for the `band`
+	///
+	/// For the band-pass filter `order` is the order of the prototype, so the resulting filter has twice the order.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `band` is invalid, `order` is not in \[`1`; `16`\] or `ripple` is not a finite number > `0.0`
+	pub fn chebyshev(
+		band: FilterBand,
+		order: u8,
+		ripple: ValueType,
+		value: &ValueType,
+	) -> Result<Self, Error> {
+		band.validate()?;
+		validate_order(order)?;
+		Error::ensure(
+			ripple > 0.0 && ripple.is_finite(),
+			"ripple",
+			ripple,
+			"must be a finite number > 0.0",
+		)?;
+
+		Self::new(band.design(&chebyshev_poles(order, ripple)), value)
+	}
+
+	/// Returns sections of the filter
+	#[must_use]
+	pub fn sections(&self) -> &[Biquad] {
+		&self.sections
+	}
+}
+
+impl Method for IirFilter {
+	type Params = Vec<BiquadCoefficients>;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(sections: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			!sections.is_empty(),
+			"sections length",
+			sections.len(),
+			"must be > 0",
+		)?;
+
+		let mut value = value;
+		let sections = sections
+			.into_iter()
+			.map(|coefficients| {
+				let section = Biquad::new(coefficients, &value)?;
+				value = section.peek();
+				Ok(section)
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Self { sections })
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.sections
+			.iter_mut()
+			.fold(value, |value, section| section.next(&value))
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.sections.iter().map(Method::warmup_len).sum()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for IirFilter {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.sections.last().map_or(0.0, Peekable::peek)
+	}
+}
+
+/// [Butterworth](https://en.wikipedia.org/wiki/Butterworth_filter) low-pass filter for timeseries of type [`ValueType`]
+///
+/// Smooths the values by removing the cycles shorter than `period` bars. It has the flattest passband
+/// and much less lag than the moving averages with the same smoothing.
+/// The higher is the `order`, the sharper is the cutoff and the higher is the lag.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`period`: [`PeriodType`], `order`: [`u8`]\)
+///
+/// `period` should be > `2`
+///
+/// `order` should be in \[`1`; `16`\]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Butterworth;
+///
+/// let mut butterworth = Butterworth::new((20, 2), &1.0).unwrap();
+///
+/// assert!((butterworth.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(butterworth.next(&2.0) < 1.1);
+/// ```
+///
+/// # Performance
+///
+/// O(`order`)
+///
+/// # See also
+///
+/// [`IirFilter::butterworth`], [`Chebyshev`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Butterworth(IirFilter);
+
+impl Method for Butterworth {
+	type Params = (PeriodType, u8);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((period, order): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(period > 2, "period", period, "must be > 2")?;
+
+		let band = FilterBand::LowPass {
+			period: period as ValueType,
+		};
+		IirFilter::butterworth(band, order, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl MovingAverage for Butterworth {}
+
+impl Peekable<<Self as Method>::Output> for Butterworth {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// [Chebyshev type I](https://en.wikipedia.org/wiki/Chebyshev_filter) low-pass filter for timeseries of type [`ValueType`]
+///
+/// Smooths the values by removing the cycles shorter than `period` bars. It has the sharper cutoff than [`Butterworth`]
+/// of the same `order` at the cost of the `ripple` (in dB) in the passband. Gain for the constant values is always `1.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`period`: [`PeriodType`], `order`: [`u8`], `ripple`: [`ValueType`]\)
+///
+/// `period` should be > `2`
+///
+/// `order` should be in \[`1`; `16`\]
+///
+/// `ripple` should be > `0.0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Chebyshev;
+///
+/// let mut chebyshev = Chebyshev::new((20, 4, 0.5), &1.0).unwrap();
+///
+/// assert!((chebyshev.next(&1.0) - 1.0).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// O(`order`)
+///
+/// # See also
+///
+/// [`IirFilter::chebyshev`], [`Butterworth`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chebyshev(IirFilter);
+
+impl Method for Chebyshev {
+	type Params = (PeriodType, u8, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((period, order, ripple): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(period > 2, "period", period, "must be > 2")?;
+
+		let band = FilterBand::LowPass {
+			period: period as ValueType,
+		};
+		IirFilter::chebyshev(band, order, ripple, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl MovingAverage for Chebyshev {}
+
+impl Peekable<<Self as Method>::Output> for Chebyshev {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+#[cfg(test)]
+#[allow(
+	clippy::suboptimal_flops,
+	clippy::useless_conversion,
+	trivial_numeric_casts
+)]
+mod tests {
+	use super::{
+		Biquad, BiquadCoefficients, Butterworth, Chebyshev, FilterBand, IirFilter, Method,
+	};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::EMA;
+	use std::f64::consts::PI;
+
+	/// Periods, which evenly divide the count of the samples for the gain measurement
+	const PERIODS: [usize; 16] = [3, 4, 5, 6, 8, 10, 12, 16, 20, 24, 30, 40, 60, 80, 120, 240];
+	const SAMPLES: usize = 2400;
+
+	const BANDS: [FilterBand; 3] = [
+		FilterBand::LowPass { period: 20.0 },
+		FilterBand::HighPass { period: 20.0 },
+		FilterBand::BandPass {
+			short_period: 10.0,
+			long_period: 40.0,
+		},
+	];
+
+	/// Returns amplitude of the filter output for the sine wave of the `period` with the unit amplitude
+	fn gain(filter: &mut IirFilter, period: usize) -> f64 {
+		#[allow(clippy::cast_possible_truncation)]
+		let sine = |i: usize| (2.0 * PI * i as f64 / period as f64).sin() as ValueType;
+		let warmup = filter.warmup_len() * 3 + 100;
+
+		let squares: f64 = (0..warmup + SAMPLES)
+			.map(|i| filter.next(&sine(i)))
+			.skip(warmup)
+			.map(|x| f64::from(x) * f64::from(x))
+			.sum();
+
+		(2.0 * squares / SAMPLES as f64).sqrt()
+	}
+
+	/// Returns frequency of the normalized low-pass prototype, which corresponds to the `period`
+	fn prototype_frequency(band: FilterBand, period: usize) -> f64 {
+		let warp = |period: f64| (PI / period).tan();
+		let frequency = warp(period as f64);
+
+		match band {
+			FilterBand::LowPass { period } => frequency / warp(f64::from(period)),
+			FilterBand::HighPass { period } => warp(f64::from(period)) / frequency,
+			FilterBand::BandPass {
+				short_period,
+				long_period,
+			} => {
+				let (lower, upper) = (warp(f64::from(long_period)), warp(f64::from(short_period)));
+				frequency.mul_add(frequency, -lower * upper).abs() / ((upper - lower) * frequency)
+			}
+		}
+	}
+
+	/// Chebyshev polynomial of the first kind
+	fn chebyshev_polynomial(order: u8, x: f64) -> f64 {
+		let n = f64::from(order);
+
+		if x.abs() <= 1.0 {
+			(n * x.acos()).cos()
+		} else {
+			(n * x.abs().acosh()).cosh()
+		}
+	}
+
+	/// Checks that the measured `gain` is near to the `expected` one
+	///
+	/// Measured gain keeps a residual of the transient response of the filter, which decays during the warm-up, but not completely,
+	/// so it is compared with a looser precision than `assert_near` uses.
+	fn assert_gain(expected: f64, gain: f64) {
+		const GAIN_SIGMA: f64 = if cfg!(feature = "value_type_f32") {
+			1e-3
+		} else {
+			1e-7
+		};
+
+		assert!(
+			(expected - gain).abs() <= GAIN_SIGMA * expected.max(1.0),
+			"expected gain {expected}, got {gain}"
+		);
+	}
+
+	#[test]
+	fn test_biquad_const() {
+		let coefficients = BiquadCoefficients {
+			b0: 0.2,
+			b1: 0.3,
+			b2: -0.1,
+			a1: -1.2,
+			a2: 0.5,
+		};
+
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = Biquad::new(coefficients, &input).unwrap();
+
+			// dc gain is 0.4 / 0.3
+			test_const_float(&mut method, &input, input * 4.0 / 3.0);
+		}
+	}
+
+	#[test]
+	fn test_biquad() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let coefficients = BiquadCoefficients {
+			b0: 0.2,
+			b1: 0.3,
+			b2: -0.1,
+			a1: -1.2,
+			a2: 0.5,
+		};
+		let BiquadCoefficients { b0, b1, b2, a1, a2 } = coefficients;
+		let mut biquad = Biquad::new(coefficients, &src[0]).unwrap();
+
+		// direct form I with the steady state history
+		let output = src[0] * 4.0 / 3.0;
+		let (mut x1, mut x2, mut y1, mut y2) = (src[0], src[0], output, output);
+
+		for &x in &src {
+			let y = b0 * x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+			(x2, x1, y2, y1) = (x1, x, y1, y);
+
+			assert_eq_float(y, biquad.next(&x));
+		}
+	}
+
+	#[test]
+	fn test_biquad_ema() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let alpha = 2.0 / (length as ValueType + 1.0);
+			let coefficients = BiquadCoefficients {
+				b0: alpha,
+				b1: 0.0,
+				b2: 0.0,
+				a1: alpha - 1.0,
+				a2: 0.0,
+			};
+
+			let mut biquad = Biquad::new(coefficients, &src[0]).unwrap();
+			let mut ema = EMA::new(length, &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(ema.next(x), biquad.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_biquad_parameters() {
+		let biquad = |a1, a2| {
+			let coefficients = BiquadCoefficients {
+				b0: 1.0,
+				b1: 0.0,
+				b2: 0.0,
+				a1,
+				a2,
+			};
+			Biquad::new(coefficients, &1.0)
+		};
+
+		assert!(biquad(0.0, 0.0).is_ok());
+		assert!(biquad(-1.9, 0.95).is_ok());
+		assert!(biquad(-1.0, 0.0).is_err());
+		assert!(biquad(0.0, 1.0).is_err());
+		assert!(biquad(-2.0, 0.99).is_err());
+		assert!(biquad(0.0, -1.0).is_err());
+		assert!(biquad(ValueType::NAN, 0.0).is_err());
+		assert!(IirFilter::new(Vec::new(), &1.0).is_err());
+	}
+
+	#[test]
+	fn test_iir_filter_const() {
+		for band in BANDS {
+			let output = match band {
+				FilterBand::LowPass { .. } => 5.5,
+				_ => 0.0,
+			};
+
+			for order in 1..=16 {
+				let mut method = IirFilter::butterworth(band, order, &5.5).unwrap();
+				test_const_float(&mut method, &5.5, output);
+
+				let mut method = IirFilter::chebyshev(band, order, 1.0, &5.5).unwrap();
+				test_const_float(&mut method, &5.5, output);
+			}
+		}
+	}
+
+	#[test]
+	fn test_iir_filter_sections() {
+		for order in 1..=16_u8 {
+			let sections = order.div_ceil(2) as usize;
+
+			for band in BANDS {
+				let filter = IirFilter::butterworth(band, order, &1.0).unwrap();
+				let expected = match band {
+					FilterBand::BandPass { .. } => order as usize,
+					_ => sections,
+				};
+
+				assert_eq!(filter.sections().len(), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_iir_filter_butterworth() {
+		for band in BANDS {
+			for order in 1..=6 {
+				let mut filter = IirFilter::butterworth(band, order, &0.0).unwrap();
+
+				for period in PERIODS {
+					let w = prototype_frequency(band, period);
+					let expected = (1.0 + w.powi(2 * i32::from(order))).sqrt().recip();
+
+					assert_gain(expected, gain(&mut filter, period));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_iir_filter_chebyshev() {
+		for band in BANDS {
+			for order in 1..=6 {
+				for ripple in [0.1, 0.5, 1.0, 3.0] {
+					let mut filter = IirFilter::chebyshev(band, order, ripple, &0.0).unwrap();
+
+					let epsilon2 = 10f64.powf(f64::from(ripple) / 10.0) - 1.0;
+					let response = |w| {
+						(1.0 + epsilon2 * chebyshev_polynomial(order, w).powi(2))
+							.sqrt()
+							.recip()
+					};
+
+					for period in PERIODS {
+						let w = prototype_frequency(band, period);
+						let expected = response(w) / response(0.0);
+
+						assert_gain(expected, gain(&mut filter, period));
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_iir_filter_parameters() {
+		let low_pass = |period| FilterBand::LowPass { period };
+		let band_pass = |short_period, long_period| FilterBand::BandPass {
+			short_period,
+			long_period,
+		};
+
+		assert!(IirFilter::butterworth(low_pass(2.0), 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(low_pass(2.1), 2, &1.0).is_ok());
+		assert!(IirFilter::butterworth(low_pass(ValueType::INFINITY), 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(low_pass(10.0), 0, &1.0).is_err());
+		assert!(IirFilter::butterworth(low_pass(10.0), 16, &1.0).is_ok());
+		assert!(IirFilter::butterworth(low_pass(10.0), 17, &1.0).is_err());
+		assert!(IirFilter::butterworth(FilterBand::HighPass { period: 1.0 }, 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(band_pass(10.0, 10.0), 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(band_pass(10.0, 5.0), 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(band_pass(2.0, 5.0), 2, &1.0).is_err());
+		assert!(IirFilter::butterworth(band_pass(5.0, 10.0), 2, &1.0).is_ok());
+		assert!(IirFilter::chebyshev(low_pass(10.0), 2, 0.0, &1.0).is_err());
+		assert!(IirFilter::chebyshev(low_pass(10.0), 2, ValueType::NAN, &1.0).is_err());
+		assert!(IirFilter::chebyshev(low_pass(10.0), 2, 0.5, &1.0).is_ok());
+	}
+
+	#[test]
+	fn test_butterworth_chebyshev() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for period in [3, 5, 10, 20, 50, 100, 254] {
+			for order in 1..=8 {
+				let band = FilterBand::LowPass {
+					period: period as ValueType,
+				};
+
+				let mut butterworth = Butterworth::new((period, order), &src[0]).unwrap();
+				let mut filter = IirFilter::butterworth(band, order, &src[0]).unwrap();
+				test_const_float(
+					&mut Butterworth::new((period, order), &2.5).unwrap(),
+					&2.5,
+					2.5,
+				);
+
+				let mut chebyshev = Chebyshev::new((period, order, 0.5), &src[0]).unwrap();
+				let mut filter2 = IirFilter::chebyshev(band, order, 0.5, &src[0]).unwrap();
+				test_const_float(
+					&mut Chebyshev::new((period, order, 0.5), &2.5).unwrap(),
+					&2.5,
+					2.5,
+				);
+
+				for x in &src {
+					assert_eq_float(filter.next(x), butterworth.next(x));
+					assert_eq_float(filter2.next(x), chebyshev.next(x));
+				}
+			}
+		}
+
+		assert!(Butterworth::new((2, 2), &1.0).is_err());
+		assert!(Chebyshev::new((2, 2, 0.5), &1.0).is_err());
+	}
+}
@@ -1,5 +1,5 @@
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, RunningSum, ValueType, Window};
 use crate::helpers::Peekable;
 
 #[cfg(feature = "serde")]
@@ -82,10 +82,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Integral {
-	value: ValueType,
+	value: RunningSum,
 	window: Window<ValueType>,
 }
 
+impl Integral {
+	fn recompute(&mut self) {
+		let mut value = RunningSum::new(0.0);
+		self.window.iter().for_each(|&x| value.add(x));
+
+		self.value = value;
+	}
+}
+
 /// Just an alias for Integral
 pub type Sum = Integral;
 
@@ -97,19 +106,27 @@ impl Method for Integral {
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		Ok(Self {
 			window: Window::new(length, value),
-			value: value * length as ValueType,
+			value: RunningSum::new(value * length as ValueType),
 		})
 	}
 
 	#[inline]
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
-		self.value += value;
+		self.value.add(value);
 
 		if !self.window.is_empty() {
-			self.value -= self.window.push(value);
+			self.value.add(-self.window.push(value));
+
+			if cfg!(feature = "stable_sums") && self.window.is_aligned() {
+				self.recompute();
+			}
 		}
 
-		self.value
+		self.value.value()
+	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
 	}
 }
 
@@ -121,7 +138,7 @@ impl Default for Integral {
 
 impl Peekable<<Self as Method>::Output> for Integral {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.value
+		self.value.value()
 	}
 }
 
@@ -131,6 +148,8 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
 
 	#[test]
 	fn test_integral_const() {
@@ -204,4 +223,10 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_integral_stable_sums() {
+		test_stable_sums::<TestingMethod>(47, |window| window.iter().sum());
+	}
 }
@@ -1,4 +1,4 @@
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{centered_sums, non_zero_m2, Error, PeriodType, RunningSum, ValueType, Window};
 use crate::core::{Method, MovingAverage};
 use crate::helpers::Peekable;
 
@@ -25,14 +25,18 @@ use serde::{Deserialize, Serialize};
 ///
 /// O(1)
 ///
+/// # See also
+///
+/// [`LinRegStats`]
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[doc(alias = "LSMA")]
 pub struct LinReg {
-	s_xy: ValueType,
-	s_y: ValueType,
+	s_xy: RunningSum,
+	s_y: RunningSum,
 	s_x: ValueType,
 	float_length: ValueType,
 	length_invert: ValueType,
@@ -49,7 +53,10 @@ impl LinReg {
 	#[must_use]
 	pub fn tan(&self) -> ValueType {
 		// y = kx + b, x=0
-		self.s_xy.mul_add(self.float_length, self.s_x * self.s_y) * self.divider
+		self.s_xy
+			.value()
+			.mul_add(self.float_length, self.s_x * self.s_y.value())
+			* self.divider
 	}
 
 	/// Returns current value
@@ -57,7 +64,23 @@ impl LinReg {
 	#[must_use]
 	pub fn b(&self) -> ValueType {
 		// y = kx + b, x=0
-		self.s_x.mul_add(self.tan(), self.s_y) * self.length_invert
+		self.s_x.mul_add(self.tan(), self.s_y.value()) * self.length_invert
+	}
+
+	#[allow(clippy::similar_names)]
+	fn recompute(&mut self) {
+		let mut s_y = RunningSum::new(0.0);
+		let mut s_xy = RunningSum::new(0.0);
+		let mut x = 0.0;
+
+		for &y in &self.window {
+			s_y.add(-y);
+			s_xy.add(-x * y);
+			x += 1.0;
+		}
+
+		self.s_y = s_y;
+		self.s_xy = s_xy;
 	}
 }
 
@@ -70,7 +93,7 @@ impl Method for LinReg {
 		#![allow(clippy::all)]
 		#[allow(clippy::suspicious_operation_groupings)] // s_x * s_x looks suspicious, but it's not
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
+			0 | 1 => Err(Error::invalid_parameter("length", length, "must be > 1")),
 			length => {
 				let l64 = length as usize;
 				let float_length = length as ValueType;
@@ -88,8 +111,8 @@ impl Method for LinReg {
 					length_invert,
 					divider,
 					s_x,
-					s_y: -value * float_length,
-					s_xy: value * s_x,
+					s_y: RunningSum::new(-value * float_length),
+					s_xy: RunningSum::new(value * s_x),
 					window: Window::new(length, value),
 				})
 			}
@@ -100,11 +123,20 @@ impl Method for LinReg {
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
 		let past_value = self.window.push(value);
 
-		self.s_xy += past_value.mul_add(self.float_length, self.s_y);
-		self.s_y += past_value - value;
+		self.s_xy
+			.add(past_value.mul_add(self.float_length, self.s_y.value()));
+		self.s_y.add(past_value - value);
+
+		if cfg!(feature = "stable_sums") && self.window.is_aligned() {
+			self.recompute();
+		}
 
 		self.b()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 impl MovingAverage for LinReg {}
@@ -115,13 +147,243 @@ impl Peekable<<Self as Method>::Output> for LinReg {
 	}
 }
 
+/// [Linear regression](https://en.wikipedia.org/wiki/Simple_linear_regression) over the last `length` values of timeseries of type [`ValueType`] with the goodness of fit statistics
+///
+/// Output value is the same as the output value of [`LinReg`]: the value of the regression line at the current bar.
+/// Statistics of the current regression line are available through the methods:
+///
+/// * [`r_squared`](LinRegStats::r_squared) - [coefficient of determination](https://en.wikipedia.org/wiki/Coefficient_of_determination);
+/// * [`std_error`](LinRegStats::std_error) - standard error of the estimate;
+/// * [`t_stat`](LinRegStats::t_stat) - t-statistic of the slope;
+/// * [`forecast`](LinRegStats::forecast) - value of the regression line N bars ahead.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `2`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::LinRegStats;
+///
+/// let mut lin_reg = LinRegStats::new(4, &1.0).unwrap();
+///
+/// lin_reg.next(&2.0);
+/// lin_reg.next(&3.0);
+///
+/// // values [1.0, 2.0, 3.0, 4.0] lay on the line exactly
+/// assert_eq!(lin_reg.next(&4.0), 4.0);
+/// assert_eq!(lin_reg.tan(), 1.0);
+/// assert_eq!(lin_reg.forecast(2), 6.0);
+/// assert_eq!(lin_reg.r_squared(), 1.0);
+/// assert_eq!(lin_reg.std_error(), 0.0);
+///
+/// // values [2.0, 3.0, 4.0, 3.0]
+/// lin_reg.next(&3.0);
+/// assert!((lin_reg.r_squared() - 0.4).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized: centered sums of squares and co-deviation are recomputed from the window once per `length` values
+///
+/// # See also
+///
+/// [`LinReg`], [`StDev`](crate::methods::StDev)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinRegStats {
+	lin_reg: LinReg,
+	mean: RunningSum,
+	// sum of squared deviations of the values from their mean
+	s_yy: RunningSum,
+	// sum of products of deviations of bar indexes and values from their means
+	s_xy: RunningSum,
+	s_xx: ValueType,
+}
+
+impl LinRegStats {
+	/// Returns tangent of the current regression line, which is a change of the value per bar
+	#[inline]
+	#[must_use]
+	pub fn tan(&self) -> ValueType {
+		self.lin_reg.tan()
+	}
+
+	/// Returns value of the current regression line at the current bar
+	#[inline]
+	#[must_use]
+	pub fn b(&self) -> ValueType {
+		self.lin_reg.b()
+	}
+
+	/// Returns value of the current regression line `bars` ahead of the current bar
+	#[inline]
+	#[must_use]
+	pub fn forecast(&self, bars: PeriodType) -> ValueType {
+		self.tan().mul_add(bars as ValueType, self.b())
+	}
+
+	/// Returns total and residual sums of squared deviations of the values in the window
+	fn sums_of_squares(&self) -> (ValueType, ValueType) {
+		let length = self.lin_reg.float_length;
+		let ss_tot = non_zero_m2(self.s_yy.value(), self.mean.value(), length);
+
+		if ss_tot <= 0.0 {
+			return (0.0, 0.0);
+		}
+
+		// both sums are centered, so their difference loses only the precision of the explained part of the total sum
+		let s_xy = self.s_xy.value();
+		let ss_res = s_xy.mul_add(-s_xy / self.s_xx, ss_tot);
+
+		// residuals of the values, which lay on the line, may be a bit greater than zero because of rounding errors
+		if ss_res > ss_tot * ValueType::EPSILON * length {
+			(ss_tot, ss_res)
+		} else {
+			(ss_tot, 0.0)
+		}
+	}
+
+	#[allow(clippy::similar_names)]
+	fn recompute(&mut self) {
+		// x of the oldest value relative to the middle of the window
+		let mut x = -(self.lin_reg.float_length - 1.0) / 2.0;
+		let (mean, [s_yy, s_xy]) = centered_sums(self.lin_reg.window.iter_rev().copied(), |y| {
+			let terms = [y * y, x * y];
+			x += 1.0;
+			terms
+		});
+
+		self.mean = RunningSum::new(mean);
+		self.s_yy = RunningSum::new(s_yy);
+		self.s_xy = RunningSum::new(s_xy);
+	}
+
+	/// Returns coefficient of determination (R²) of the current regression line
+	///
+	/// Output value is always in \[`0.0`; `1.0`\]. When all the values in the window are equal, it is `0.0`.
+	#[must_use]
+	pub fn r_squared(&self) -> ValueType {
+		let (ss_tot, ss_res) = self.sums_of_squares();
+
+		if ss_tot > 0.0 {
+			1.0 - ss_res / ss_tot
+		} else {
+			0.0
+		}
+	}
+
+	/// Returns standard error of the estimate: square root of the residual sum of squares divided by `length - 2`
+	#[must_use]
+	pub fn std_error(&self) -> ValueType {
+		let (_, ss_res) = self.sums_of_squares();
+
+		(ss_res / (self.lin_reg.float_length - 2.0)).sqrt()
+	}
+
+	/// Returns t-statistic of the slope: [`tan`](LinRegStats::tan) divided by its standard error
+	///
+	/// When there are no residuals, it is infinite with the sign of the slope.
+	/// When all the values in the window are equal, it is `0.0`.
+	#[must_use]
+	pub fn t_stat(&self) -> ValueType {
+		let tan = self.tan();
+		let (ss_tot, ss_res) = self.sums_of_squares();
+
+		if ss_res > 0.0 {
+			tan * (self.s_xx * (self.lin_reg.float_length - 2.0) / ss_res).sqrt()
+		} else if ss_tot > 0.0 {
+			ValueType::INFINITY.copysign(tan)
+		} else {
+			0.0
+		}
+	}
+}
+
+impl Method for LinRegStats {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 2, "length", length, "must be > 2")?;
+
+		let n = length as ValueType;
+
+		Ok(Self {
+			lin_reg: LinReg::new(length, value)?,
+			mean: RunningSum::new(*value),
+			s_yy: RunningSum::new(0.0),
+			s_xy: RunningSum::new(0.0),
+			// sum of squared deviations of bar indexes from their mean
+			s_xx: n.mul_add(n, -1.0) * n / 12.0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let prev_value = *self.lin_reg.window.oldest();
+		let output = self.lin_reg.next(&value);
+
+		let length = self.lin_reg.float_length;
+		let diff = value - prev_value;
+
+		// rolling Welford's update
+		let prev_mean = self.mean.value();
+		self.mean.add(diff / length);
+		let mean = self.mean.value();
+
+		self.s_yy
+			.add(diff * (value - mean + prev_value - prev_mean));
+
+		// every value, which stays in the window, moves one bar back
+		self.s_xy.add(
+			(length + 1.0).mul_add(prev_value - prev_mean, (length - 1.0) * (value - prev_mean))
+				/ 2.0,
+		);
+
+		if self.lin_reg.window.is_aligned() {
+			self.recompute();
+		}
+
+		output
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.lin_reg.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for LinRegStats {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.b()
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
-	use super::{LinReg as TestingMethod, Method};
+	use super::{LinReg as TestingMethod, LinRegStats, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const_float;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
+	use crate::methods::tests::{test_const_float, test_warmup};
 
 	#[test]
 	fn test_lin_reg_const() {
@@ -176,4 +438,145 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	fn test_lin_reg_warmup() {
+		// linear regression over 2 values always passes through the last value
+		for length in 3..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::float_cmp)]
+	fn test_lin_reg_stats_const() {
+		for i in 3..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = LinRegStats::new(i, &input).unwrap();
+
+			for _ in 0..100 {
+				assert_eq_float(input, method.next(&input));
+				assert_eq_float(input, method.forecast(10));
+				assert_eq!(method.r_squared(), 0.0);
+				assert_eq!(method.std_error(), 0.0);
+				assert_eq!(method.t_stat(), 0.0);
+			}
+		}
+	}
+
+	#[test]
+	#[allow(clippy::float_cmp)]
+	fn test_lin_reg_stats_line() {
+		for length in 3..255 {
+			let input = |i: usize| 3.0 - i as ValueType * 0.25;
+			let mut method = LinRegStats::new(length, &input(0)).unwrap();
+
+			for i in 0..300 {
+				let value = method.next(&input(i));
+
+				if i >= length as usize {
+					assert_eq_float(input(i), value);
+					assert_eq_float(input(i + 7), method.forecast(7));
+					assert_eq_float(-0.25, method.tan());
+					assert_eq_float(1.0, method.r_squared());
+					assert_eq!(method.std_error(), 0.0);
+					assert_eq!(method.t_stat(), ValueType::NEG_INFINITY);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[allow(clippy::similar_names)]
+	fn test_lin_reg_stats() {
+		const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+			1e-5
+		} else {
+			1e-10
+		};
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &length in &[
+			3, 4, 5, 7, 10, 11, 13, 17, 20, 21, 25, 70, 77, 100, 125, 128, 173, 254,
+		] {
+			let mut method = LinRegStats::new(length, &src[0]).unwrap();
+			let n = length as ValueType;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let value = method.next(x);
+
+				if i == 0 {
+					// all the values in the window are equal
+					return;
+				}
+
+				// oldest value has x = 0, current value has x = `length - 1`
+				let window: Vec<ValueType> = (0..length as usize)
+					.rev()
+					.map(|j| src[i.saturating_sub(j)])
+					.collect();
+
+				let mean_x = (n - 1.0) / 2.0;
+				let mean_y = window.iter().sum::<ValueType>() / n;
+				let (s_xy, s_xx, ss_tot) = window.iter().enumerate().fold(
+					(0.0, 0.0, 0.0),
+					|(s_xy, s_xx, ss_tot), (x, y)| {
+						let (dx, dy) = (x as ValueType - mean_x, y - mean_y);
+						(s_xy + dx * dy, s_xx + dx * dx, ss_tot + dy * dy)
+					},
+				);
+
+				let tan = s_xy / s_xx;
+				let line = |x: ValueType| mean_y + tan * (x - mean_x);
+				let ss_res: ValueType = window
+					.iter()
+					.enumerate()
+					.map(|(x, y)| (y - line(x as ValueType)).powi(2))
+					.sum();
+
+				assert_eq_float(line(n - 1.0), value);
+				assert_eq_float(line(n + 4.0), method.forecast(5));
+				assert_eq_float(tan, method.tan());
+				assert_eq_float(1.0 - ss_res / ss_tot, method.r_squared());
+
+				// residual sum of squares loses precision, when values are near to the line, so it is compared relatively to the total sum
+				let std_error = method.std_error();
+				assert!((std_error * std_error * (n - 2.0) - ss_res).abs() < SIGMA * ss_tot);
+				assert_eq_float(tan * s_xx.sqrt(), method.t_stat() * std_error);
+			});
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_stats_warmup() {
+		for length in 3..100 {
+			test_warmup::<LinRegStats>(length);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	#[allow(clippy::similar_names)]
+	fn test_lin_reg_stable_sums() {
+		test_stable_sums::<TestingMethod>(47, |window| {
+			let length = window.len() as ValueType;
+			let mean_x = (length - 1.0) / 2.0;
+			let mean_y = window.iter().sum::<ValueType>() / length;
+
+			let (s_xy, s_xx) =
+				window
+					.iter()
+					.enumerate()
+					.fold((0.0, 0.0), |(s_xy, s_xx), (x, y)| {
+						let dx = x as ValueType - mean_x;
+						(s_xy + dx * (y - mean_y), s_xx + dx * dx)
+					});
+
+			mean_y + s_xy / s_xx * (length - 1.0 - mean_x)
+		});
+	}
 }
@@ -0,0 +1,210 @@
+use super::dominant_cycle::Homodyne;
+use crate::core::{Error, Method, MovingAverage, ValueType};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers' [MESA Adaptive Moving Average](https://www.mesasoftware.com/papers/MAMA.pdf) for timeseries of type [`ValueType`]
+///
+/// Smoothing factor adapts to the rate of the phase change of the dominant cycle, measured by the Hilbert transform:
+/// it is `fast_limit` / `delta_phase` (in degrees per bar), but not less than `slow_limit`.
+/// The Following Adaptive Moving Average ([`fama`](Self::fama)) is calculated over MAMA values with the half of the smoothing factor.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`fast_limit`: [`ValueType`], `slow_limit`: [`ValueType`]\)
+///
+/// `fast_limit` should be in range \(`0.0`; `1.0`\]
+///
+/// `slow_limit` should be in range \(`0.0`; `fast_limit`\]
+///
+/// Ehlers uses `fast_limit` = `0.5` and `slow_limit` = `0.05`.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MAMA;
+///
+/// let mut mama = MAMA::new((0.5, 0.05), &1.0).unwrap();
+///
+/// assert_eq!(mama.next(&1.0), 1.0);
+/// assert_eq!(mama.fama(), 1.0);
+///
+/// let value = mama.next(&2.0);
+/// assert!(value > 1.0 && value < 2.0);
+/// assert!(mama.fama() < value);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`](crate::methods::EMA), [`Vidya`](crate::methods::Vidya)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone)]
+#[doc(alias = "MESAAdaptiveMovingAverage")]
+#[doc(alias = "FAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MAMA {
+	homodyne: Homodyne,
+	fast_limit: ValueType,
+	slow_limit: ValueType,
+	phase: ValueType,
+	mama: ValueType,
+	fama: ValueType,
+}
+
+impl MAMA {
+	/// Returns the last value of the Following Adaptive Moving Average
+	#[must_use]
+	pub const fn fama(&self) -> ValueType {
+		self.fama
+	}
+}
+
+impl Method for MAMA {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((fast_limit, slow_limit): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			fast_limit > 0.0 && fast_limit <= 1.0,
+			"fast_limit",
+			fast_limit,
+			"must be in range (0.0; 1.0]",
+		)?;
+		Error::ensure(
+			slow_limit > 0.0 && slow_limit <= fast_limit,
+			"slow_limit",
+			slow_limit,
+			format_args!("must be in range (0.0; fast_limit {fast_limit}]"),
+		)?;
+
+		Ok(Self {
+			homodyne: Homodyne::new(value, 6.0, 50.0)?,
+			fast_limit,
+			slow_limit,
+			phase: 0.0,
+			mama: value,
+			fama: value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let (in_phase, quadrature) = self.homodyne.next(value);
+
+		let phase = if in_phase == 0.0 {
+			self.phase
+		} else {
+			(quadrature / in_phase).atan().to_degrees()
+		};
+		let delta_phase = (self.phase - phase).max(1.0);
+		self.phase = phase;
+
+		let alpha = (self.fast_limit / delta_phase).max(self.slow_limit);
+		self.mama = alpha.mul_add(value - self.mama, self.mama);
+		self.fama = (0.5 * alpha).mul_add(self.mama - self.fama, self.fama);
+
+		self.mama
+	}
+
+	fn warmup_len(&self) -> usize {
+		// the same as the lookback of TA-Lib
+		32
+	}
+}
+
+impl MovingAverage for MAMA {}
+
+impl Peekable<<Self as Method>::Output> for MAMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.mama
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, MAMA};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_mama_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = MAMA::new((0.5, 0.05), &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+			assert_eq_float(input, method.fama());
+		}
+	}
+
+	#[test]
+	fn test_mama_bounds() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(500)
+			.map(|c| c.close)
+			.collect();
+
+		for limits in [(0.5, 0.05), (1.0, 1.0), (0.2, 0.01)] {
+			let mut method = MAMA::new(limits, &src[0]).unwrap();
+			let (mut min, mut max) = (src[0], src[0]);
+
+			for &x in &src {
+				min = min.min(x);
+				max = max.max(x);
+
+				let value = method.next(&x);
+				assert!(value >= min && value <= max);
+				assert!(method.fama() >= min && method.fama() <= max);
+			}
+		}
+	}
+
+	#[test]
+	fn test_mama_limits() {
+		// with the same limits it is just an EMA
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(100)
+			.map(|c| c.close)
+			.collect();
+
+		let mut method = MAMA::new((0.25, 0.25), &src[0]).unwrap();
+		let (mut mama, mut fama) = (src[0], src[0]);
+
+		for &x in &src {
+			mama += 0.25 * (x - mama);
+			fama += 0.125 * (mama - fama);
+
+			assert!((method.next(&x) - mama).abs() < 1e-4);
+			assert!((method.fama() - fama).abs() < 1e-4);
+		}
+	}
+
+	#[test]
+	fn test_mama_parameters() {
+		assert!(MAMA::new((0.5, 0.05), &1.0).is_ok());
+		assert!(MAMA::new((1.0, 1.0), &1.0).is_ok());
+		assert!(MAMA::new((0.0, 0.0), &1.0).is_err());
+		assert!(MAMA::new((1.1, 0.05), &1.0).is_err());
+		assert!(MAMA::new((0.5, 0.0), &1.0).is_err());
+		assert!(MAMA::new((0.5, 0.6), &1.0).is_err());
+		assert!(MAMA::new((ValueType::NAN, 0.05), &1.0).is_err());
+	}
+}
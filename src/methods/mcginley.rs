@@ -0,0 +1,160 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [McGinley Dynamic](https://www.investopedia.com/terms/m/mcginley-dynamic.asp) of specified `length` for timeseries of type [`ValueType`]
+///
+/// MD = MD\[1\] + (value - MD\[1\]) / (`length` × (value / MD\[1\])⁴)
+///
+/// It speeds up when the values go down and slows down when the values go up, so it follows the price better than [EMA](crate::methods::EMA).
+/// The formula is designed for the positive values.
+///
+/// The adjustment factor 1 / (`length` × (value / MD\[1\])⁴) is limited by `1.0`, so MD never overshoots the value
+/// on the sharp drops.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::McGinley;
+///
+/// let mut mcginley = McGinley::new(3, &1.0).unwrap();
+///
+/// assert_eq!(mcginley.next(&1.0), 1.0);
+/// assert_eq!(mcginley.next(&2.0), 1.0 + 1.0 / 48.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [EMA](crate::methods::EMA), [Vidya](crate::methods::Vidya)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[doc(alias = "McGinleyDynamic")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct McGinley {
+	length: ValueType,
+	value: ValueType,
+}
+
+impl Method for McGinley {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+
+		Ok(Self {
+			length: length as ValueType,
+			value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let ratio = value / self.value;
+
+		// the previous value is zero, so there is nothing to compare with
+		self.value = if ratio.is_finite() {
+			let factor = (self.length * ratio.powi(4)).recip().min(1.0);
+			(value - self.value).mul_add(factor, self.value)
+		} else {
+			value
+		};
+
+		self.value
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn warmup_len(&self) -> usize {
+		self.length as usize - 1
+	}
+}
+
+impl MovingAverage for McGinley {}
+
+impl Peekable<<Self as Method>::Output> for McGinley {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{McGinley as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_mcginley_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_mcginley() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
+
+			let mut value = src[0];
+			for &x in &src {
+				let factor = 1.0 / (length as ValueType * (x / value).powi(4));
+				value += (x - value) * factor.min(1.0);
+
+				assert_eq_float(value, ma.next(&x));
+			}
+		});
+	}
+
+	#[test]
+	fn test_mcginley_zero() {
+		let mut ma = TestingMethod::new(10, &0.0).unwrap();
+
+		assert_eq_float(0.0, ma.next(&0.0));
+		assert_eq_float(5.0, ma.next(&5.0));
+		assert!(ma.next(&6.0) < 6.0);
+	}
+
+	#[test]
+	fn test_mcginley_overshoot() {
+		for length in 1..10 {
+			let mut ma = TestingMethod::new(length, &100.0).unwrap();
+
+			assert_eq_float(1.0, ma.next(&1.0));
+			assert!(ma.next(&100.0) > 1.0);
+		}
+	}
+}
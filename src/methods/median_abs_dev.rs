@@ -50,7 +50,7 @@ impl Method for MedianAbsDev {
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
+			0 | 1 => Err(Error::invalid_parameter("length", length, "must be > 1")),
 			length => Ok(Self {
 				smm: SMM::new(length, value)?,
 				divider: (length as ValueType).recip(),
@@ -64,6 +64,10 @@ impl Method for MedianAbsDev {
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.smm.warmup_len()
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for MedianAbsDev {
@@ -100,7 +104,7 @@ mod tests {
 	}
 
 	#[test]
-	#[should_panic(expected = "WrongMethodParameters")]
+	#[should_panic(expected = "InvalidParameter")]
 	fn test_median_abs_dev1() {
 		let mut candles = RandomCandles::default();
 
@@ -43,14 +43,28 @@ mod rma;
 pub use rma::*;
 mod smm;
 pub use smm::*;
+mod quantile;
+pub use quantile::*;
+mod percent_rank;
+pub use percent_rank::*;
 mod hma;
 pub use hma::*;
 mod lin_reg;
 pub use lin_reg::*;
+mod poly_reg;
+pub use poly_reg::*;
 mod swma;
 pub use swma::*;
 mod conv;
 pub use conv::*;
+mod iir;
+pub use iir::*;
+mod ehlers;
+pub use ehlers::*;
+mod mama;
+pub use mama::*;
+mod dominant_cycle;
+pub use dominant_cycle::*;
 mod vwma;
 pub use vwma::*;
 mod trima;
@@ -68,6 +82,12 @@ mod tsi;
 pub use tsi::*;
 mod st_dev;
 pub use st_dev::*;
+mod z_score;
+pub use z_score::*;
+mod moments;
+pub use moments::*;
+mod entropy;
+pub use entropy::*;
 mod volatility;
 pub use volatility::*;
 mod cci;
@@ -78,9 +98,21 @@ mod median_abs_dev;
 pub use median_abs_dev::*;
 mod vidya;
 pub use vidya::*;
+mod alma;
+pub use alma::*;
+mod t3;
+pub use t3::*;
+mod zlema;
+pub use zlema::*;
+mod mcginley;
+pub use mcginley::*;
+mod frama;
+pub use frama::*;
 
 mod cross;
 pub use cross::*;
+mod correlation;
+pub use correlation::*;
 mod reversal;
 pub use reversal::*;
 mod highest_lowest;
@@ -102,12 +134,12 @@ pub mod renko;
 #[doc(inline)]
 pub use renko::Renko;
 mod collapse_timeframe;
-pub use collapse_timeframe::CollapseTimeframe;
+pub use collapse_timeframe::{CollapseTimeframe, CollapseTimeframeByTime, TimeBuckets};
 
 #[cfg(test)]
 mod tests {
-	use crate::core::{Method, ValueType};
-	use crate::helpers::assert_eq_float;
+	use crate::core::{Method, PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
 	use std::fmt::Debug;
 
 	pub(super) fn test_const<P, I: ?Sized, O: Debug + PartialEq>(
@@ -130,4 +162,126 @@ mod tests {
 			assert_eq_float(output, method.next(input));
 		}
 	}
+
+	/// Checks that `calculated` value is near to the `original` one
+	///
+	/// Rolling moments accumulate rounding errors of the values, which are already out of the window, until they are recomputed,
+	/// so the values are compared with an absolute precision, when they are near to zero.
+	pub(super) fn assert_near(original: ValueType, calculated: ValueType) {
+		const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+			1e-3
+		} else {
+			1e-10
+		};
+
+		assert!(
+			(original - calculated).abs() <= SIGMA * original.abs().max(1.0),
+			"original={original}, calculated={calculated}"
+		);
+	}
+
+	/// Checks that output values after the warm-up period do not depend on the initial value
+	pub(super) fn test_warmup<M>(params: M::Params)
+	where
+		M: Method<Input = ValueType, Output = ValueType>,
+		M::Params: Copy,
+	{
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|c| c.close)
+			.collect();
+
+		test_warmup_with::<M>(params, &src, &(src[0] - 10.), &(src[0] + 30.));
+	}
+
+	/// Checks that output values over `src` after the warm-up period are the same for the methods, initialized by `initial1` and `initial2`
+	pub(super) fn test_warmup_with<M>(
+		params: M::Params,
+		src: &[M::Input],
+		initial1: &M::Input,
+		initial2: &M::Input,
+	) where
+		M: Method<Output = ValueType>,
+		M::Input: Sized,
+		M::Params: Copy,
+	{
+		let mut method1 = M::new(params, initial1).unwrap();
+		let mut method2 = M::new(params, initial2).unwrap();
+		let warmup_len = method1.warmup_len();
+
+		for (i, x) in src.iter().enumerate() {
+			let (value1, value2) = (method1.next(x), method2.next(x));
+
+			if i + 1 == warmup_len {
+				assert!((value1 - value2).abs() > 1e-6);
+			} else if i >= warmup_len {
+				assert_eq_float(value1, value2);
+			}
+		}
+	}
+
+	/// Checks that output values over `src` are near to the `naive` calculation over the window (from the oldest value to the newest) for every length of `lengths`
+	pub(super) fn test_naive<M>(
+		src: &[M::Input],
+		lengths: impl IntoIterator<Item = PeriodType>,
+		naive: impl Fn(&[M::Input]) -> ValueType,
+	) where
+		M: Method<Params = PeriodType, Output = ValueType>,
+		M::Input: Copy,
+	{
+		for length in lengths {
+			let mut method = M::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, x) in src.iter().enumerate() {
+				let window: Vec<M::Input> = (0..length)
+					.rev()
+					.map(|j| src[i.saturating_sub(j)])
+					.collect();
+
+				assert_near(naive(&window), method.next(x));
+			}
+		}
+	}
+
+	/// Checks that output values do not drift away from the `naive` recalculation over the window (from the oldest value to the newest) after 10M steps
+	///
+	/// `length` should not divide the checks interval, so the checks do not fall right after the methods recompute their sums from the window.
+	#[cfg(feature = "stable_sums")]
+	pub(super) fn test_stable_sums<M>(length: PeriodType, naive: impl Fn(&[ValueType]) -> ValueType)
+	where
+		M: Method<Params = PeriodType, Input = ValueType, Output = ValueType>,
+	{
+		const STEPS: usize = 10_000_000;
+		const CHECK_EVERY: usize = 1_000_000;
+
+		#[allow(clippy::cast_precision_loss)]
+		let input = |i: usize| (i as ValueType * 0.7).sin().mul_add(10.0, 1000.0) + (i % 7) as ValueType;
+
+		let mut method = M::new(length, &input(0)).unwrap();
+		let length = length as usize;
+		assert_ne!(
+			CHECK_EVERY % length,
+			0,
+			"checks are aligned with the window"
+		);
+		let mut window = vec![input(0); length];
+
+		for i in 0..STEPS {
+			let x = input(i);
+			let value = method.next(&x);
+			window[i % length] = x;
+
+			if (i + 1) % CHECK_EVERY == 0 {
+				let start = (i + 1) % length;
+				let ordered: Vec<ValueType> = window[start..]
+					.iter()
+					.chain(&window[..start])
+					.copied()
+					.collect();
+
+				assert_eq_float(naive(&ordered), value);
+			}
+		}
+	}
 }
@@ -0,0 +1,440 @@
+use crate::core::Method;
+use crate::core::{centered_sums, non_zero_m2, Error, PeriodType, RunningSum, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling mean and sums of the 2nd, 3rd and 4th powers of deviations over the window
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct CentralMoments {
+	mean: RunningSum,
+	m2: RunningSum,
+	m3: RunningSum,
+	m4: RunningSum,
+	length: ValueType,
+	window: Window<ValueType>,
+}
+
+impl CentralMoments {
+	fn new(length: PeriodType, min_length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Error::ensure(
+			length > min_length,
+			"length",
+			length,
+			format_args!("must be > {min_length}"),
+		)?;
+
+		Ok(Self {
+			mean: RunningSum::new(value),
+			m2: RunningSum::new(0.0),
+			m3: RunningSum::new(0.0),
+			m4: RunningSum::new(0.0),
+			length: length as ValueType,
+			window: Window::new(length, value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: ValueType) {
+		let prev_value = self.window.push(value);
+
+		// Pébay's update formulas for removing the oldest value from the window of size `n + 1`
+		// and then adding the newest value to the window of size `n`
+		let n = self.length - 1.0;
+		let k3 = n - 1.0;
+		let k4 = n.mul_add(n, -n) + 1.0;
+
+		let full_mean = self.mean.value();
+		self.mean.add((full_mean - prev_value) / n);
+
+		let delta = prev_value - self.mean.value();
+		let delta_n = delta / self.length;
+		let term = delta * delta_n * n;
+
+		self.m2.add(-term);
+		let m2 = self.m2.value();
+		self.m3
+			.add((3.0 * m2).mul_add(delta_n, -term * delta_n * k3));
+		let m3 = self.m3.value();
+		self.m4
+			.add(delta_n.mul_add(4.0 * m3, -delta_n * delta_n * term.mul_add(k4, 6.0 * m2)));
+
+		let delta = value - self.mean.value();
+		let delta_n = delta / self.length;
+		let term = delta * delta_n * n;
+		let (m2, m3) = (self.m2.value(), self.m3.value());
+
+		self.mean.add(delta_n);
+		self.m4
+			.add((delta_n * delta_n).mul_add(term.mul_add(k4, 6.0 * m2), -4.0 * delta_n * m3));
+		self.m3
+			.add((term * k3).mul_add(delta_n, -3.0 * delta_n * m2));
+		self.m2.add(term);
+
+		if self.window.is_aligned() {
+			self.recompute();
+		}
+	}
+
+	fn recompute(&mut self) {
+		let (mean, [m2, m3, m4]) = centered_sums(self.window.iter().copied(), |delta| {
+			let delta2 = delta * delta;
+			[delta2, delta2 * delta, delta2 * delta2]
+		});
+
+		self.mean = RunningSum::new(mean);
+		self.m2 = RunningSum::new(m2);
+		self.m3 = RunningSum::new(m3);
+		self.m4 = RunningSum::new(m4);
+	}
+
+	fn non_zero_m2(&self) -> ValueType {
+		non_zero_m2(self.m2.value(), self.mean.value(), self.length)
+	}
+
+	fn skewness(&self) -> ValueType {
+		let m2 = self.non_zero_m2();
+
+		if m2 > 0.0 {
+			self.length.sqrt() * self.m3.value() / (m2 * m2.sqrt())
+		} else {
+			0.0
+		}
+	}
+
+	fn kurtosis(&self) -> ValueType {
+		let m2 = self.non_zero_m2();
+
+		if m2 > 0.0 {
+			// kurtosis can not be lower than `1.0`, but rounding errors may put it a bit lower
+			(self.length * self.m4.value() / (m2 * m2)).max(1.0) - 3.0
+		} else {
+			0.0
+		}
+	}
+
+	const fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
+}
+
+/// Moving [Skewness](https://en.wikipedia.org/wiki/Skewness) over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// It is a population skewness `g1` = `m3` / `m2`^1.5, where `m2` and `m3` are the 2nd and the 3rd central moments of the values in the window.
+/// Positive skewness means the longer right tail of the values distribution, negative skewness means the longer left tail.
+///
+/// When all the values in the window are equal, output value is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `2`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Skewness;
+///
+/// let mut skewness = Skewness::new(5, &0.0).unwrap();
+///
+/// skewness.next(&0.0);
+/// skewness.next(&0.0);
+/// skewness.next(&0.0);
+///
+/// // values [0.0, 0.0, 0.0, 0.0, 1.0]
+/// assert!((skewness.next(&1.0) - 1.5).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized: central moments are recomputed from the window once per `length` values
+///
+/// # See also
+///
+/// [`Kurtosis`], [`StDev`](crate::methods::StDev)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Skewness(CentralMoments);
+
+impl Method for Skewness {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		CentralMoments::new(length, 2, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.0.next(value);
+		self.0.skewness()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Skewness {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.skewness()
+	}
+}
+
+/// Moving excess [Kurtosis](https://en.wikipedia.org/wiki/Kurtosis) over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// It is a population excess kurtosis `g2` = `m4` / `m2`^2 - 3, where `m2` and `m4` are the 2nd and the 4th central moments of the values in the window.
+/// Excess kurtosis of the normal distribution is `0.0`. Positive values mean heavier tails than the normal distribution has.
+///
+/// When all the values in the window are equal, output value is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `3`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always >= `-2.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Kurtosis;
+///
+/// let mut kurtosis = Kurtosis::new(4, &0.0).unwrap();
+///
+/// kurtosis.next(&0.0);
+/// kurtosis.next(&1.0);
+///
+/// // values [0.0, 0.0, 1.0, 1.0]
+/// assert_eq!(kurtosis.next(&1.0), -2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized, the same as [`Skewness`]
+///
+/// # See also
+///
+/// [`Skewness`], [`StDev`](crate::methods::StDev)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Kurtosis(CentralMoments);
+
+impl Method for Kurtosis {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		CentralMoments::new(length, 3, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.0.next(value);
+		self.0.kurtosis()
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Kurtosis {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.kurtosis()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Kurtosis, Method, Skewness};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::RandomCandles;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
+	use crate::methods::tests::{assert_near, test_const_float, test_naive, test_warmup};
+
+	fn source() -> Vec<ValueType> {
+		RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect()
+	}
+
+	fn naive_moments(window: &[ValueType]) -> (ValueType, ValueType, ValueType) {
+		let length = window.len() as ValueType;
+		let mean = window.iter().sum::<ValueType>() / length;
+		let moment = |power| {
+			window
+				.iter()
+				.map(|&x| (x - mean).powi(power))
+				.sum::<ValueType>()
+				/ length
+		};
+
+		(moment(2), moment(3), moment(4))
+	}
+
+	#[allow(clippy::float_cmp)]
+	fn naive_skewness(window: &[ValueType]) -> ValueType {
+		if window.iter().all(|&x| x == window[0]) {
+			return 0.0;
+		}
+
+		let (m2, m3, _) = naive_moments(window);
+		m3 / m2.powf(1.5)
+	}
+
+	#[allow(clippy::float_cmp)]
+	fn naive_kurtosis(window: &[ValueType]) -> ValueType {
+		if window.iter().all(|&x| x == window[0]) {
+			return 0.0;
+		}
+
+		let (m2, _, m4) = naive_moments(window);
+		m4 / (m2 * m2) - 3.0
+	}
+
+	/// Feeds repeating `values` and checks the output after every full period
+	fn test_periodic<M>(values: &[ValueType], expected: ValueType)
+	where
+		M: Method<Params = PeriodType, Input = ValueType, Output = ValueType>,
+	{
+		let length = values.len();
+		let input = |i: usize| values[i % length] * 3.5 + 100.0;
+		let mut method = M::new(PeriodType::try_from(length).unwrap(), &input(0)).unwrap();
+
+		for i in 0..length * 20 {
+			let value = method.next(&input(i));
+
+			if i >= length {
+				assert_near(expected, value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_skewness_const() {
+		for i in 3..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = Skewness::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_kurtosis_const() {
+		for i in 4..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = Kurtosis::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_skewness() {
+		test_naive::<Skewness>(&source(), 3..255, naive_skewness);
+	}
+
+	#[test]
+	fn test_kurtosis() {
+		test_naive::<Kurtosis>(&source(), 4..255, naive_kurtosis);
+	}
+
+	#[test]
+	fn test_moments_bernoulli() {
+		// window always holds `ones` values `1.0` and `length - ones` values `0.0`
+		for length in 4..50 {
+			for ones in 1..length {
+				let values: Vec<ValueType> = (0..length)
+					.map(|i| if i < ones { 1.0 } else { 0.0 })
+					.collect();
+
+				let p = ones as ValueType / length as ValueType;
+				let pq = p * (1.0 - p);
+
+				test_periodic::<Skewness>(&values, (1.0 - 2.0 * p) / pq.sqrt());
+				test_periodic::<Kurtosis>(&values, (1.0 - 6.0 * pq) / pq);
+			}
+		}
+	}
+
+	#[test]
+	fn test_moments_uniform() {
+		// window always holds all the values of the discrete uniform distribution over `0..length`
+		for length in 4..255_u16 {
+			let values: Vec<ValueType> = (0..length).map(ValueType::from).collect();
+			let n2 = ValueType::from(length).powi(2);
+
+			test_periodic::<Skewness>(&values, 0.0);
+			test_periodic::<Kurtosis>(&values, -6.0 * (n2 + 1.0) / (5.0 * (n2 - 1.0)));
+		}
+	}
+
+	#[test]
+	fn test_moments_parameters() {
+		assert!(Skewness::new(2, &1.0).is_err());
+		assert!(Skewness::new(3, &1.0).is_ok());
+		assert!(Kurtosis::new(3, &1.0).is_err());
+		assert!(Kurtosis::new(4, &1.0).is_ok());
+	}
+
+	#[test]
+	fn test_skewness_warmup() {
+		for length in 3..100 {
+			test_warmup::<Skewness>(length);
+		}
+	}
+
+	#[test]
+	fn test_kurtosis_warmup() {
+		for length in 4..100 {
+			test_warmup::<Kurtosis>(length);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_skewness_stable_sums() {
+		test_stable_sums::<Skewness>(47, naive_skewness);
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_kurtosis_stable_sums() {
+		test_stable_sums::<Kurtosis>(47, naive_kurtosis);
+	}
+}
@@ -82,7 +82,7 @@ impl Method for Momentum {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
 				window: Window::new(length, value),
 			}),
@@ -93,6 +93,10 @@ impl Method for Momentum {
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
 		value - self.window.push(value)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
 }
 
 #[cfg(test)]
@@ -100,7 +104,7 @@ mod tests {
 	use super::{Method, Momentum as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_warmup};
 
 	#[test]
 	fn test_momentum_const() {
@@ -142,4 +146,11 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_momentum_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
 }
@@ -71,7 +71,7 @@ where
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self(Window::new(length, value.clone()))),
 		}
 	}
@@ -80,6 +80,10 @@ where
 	fn next(&mut self, value: &Self::Input) -> T {
 		self.0.push(value.clone())
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.len() as usize
+	}
 }
 
 impl<T> Peekable<<Self as Method>::Output> for Past<T>
@@ -0,0 +1,170 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Percent Rank](https://www.tradingview.com/pine-script-reference/v5/#fun_ta.percentrank) of the current value over the previous `length` values for timeseries of type [`ValueType`]
+///
+/// Percent rank is the percentage of the previous `length` values, which are less than or equal to the current value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in \[`0.0`; `100.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PercentRank;
+///
+/// let mut percent_rank = PercentRank::new(4, &1.0).unwrap();
+///
+/// percent_rank.next(&2.0);
+/// percent_rank.next(&3.0);
+/// percent_rank.next(&4.0);
+///
+/// // previous values are [1.0, 2.0, 3.0, 4.0]
+/// assert_eq!(percent_rank.next(&3.0), 75.0);
+/// // previous values are [2.0, 3.0, 4.0, 3.0]
+/// assert_eq!(percent_rank.next(&1.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// Unlike [`Quantile`](crate::methods::Quantile), it does not use an order-statistics tree. Counting over the contiguous window
+/// has a very small constant, so it is still fast for the short windows. For the windows of thousands of values prefer
+/// [`Quantile`](crate::methods::Quantile), which is O(log(`length`)). Also it accepts `NaN` values, which are never counted.
+///
+/// # See also
+///
+/// [`ZScore`](crate::methods::ZScore), [`Quantile`](crate::methods::Quantile)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank {
+	value: ValueType,
+	window: Window<ValueType>,
+}
+
+impl PercentRank {
+	/// Returns inner [`Window`](crate::core::Window) of the previous values. Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_window(&self) -> &Window<ValueType> {
+		&self.window
+	}
+}
+
+impl Method for PercentRank {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
+			length => Ok(Self {
+				value: 100.0,
+				window: Window::new(length, value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let count = self.window.iter().filter(|&&x| x <= value).count();
+		self.window.push(value);
+
+		self.value = count as ValueType * 100.0 / self.window.len() as ValueType;
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for PercentRank {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentRank as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const, test_warmup};
+
+	#[test]
+	fn test_percent_rank_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const(&mut method, &input, &100.0);
+		}
+	}
+
+	#[test]
+	fn test_percent_rank1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method = TestingMethod::new(1, &candles.first().close).unwrap();
+		let mut prev = candles.first().close;
+
+		candles.take(100).map(|x| x.close).for_each(|x| {
+			let value2 = if prev <= x { 100.0 } else { 0.0 };
+			prev = x;
+
+			assert_eq_float(value2, method.next(&x));
+		});
+	}
+
+	#[test]
+	fn test_percent_rank() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let count = (1..=length)
+					.filter(|j| src[i.saturating_sub(*j)] <= x)
+					.count();
+				let value2 = count as ValueType * 100.0 / length as ValueType;
+
+				assert_eq_float(value2, method.next(&x));
+			});
+		});
+	}
+
+	#[test]
+	fn test_percent_rank_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+}
@@ -0,0 +1,542 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::Conv;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Max polynomial order, which still gives precise enough weights
+const MAX_ORDER: u8 = 10;
+
+fn validate(length: PeriodType, order: u8) -> Result<(), Error> {
+	Error::ensure(
+		order <= MAX_ORDER,
+		"order",
+		order,
+		format_args!("must be <= {MAX_ORDER}"),
+	)?;
+	Error::ensure(
+		(length as usize) > order as usize,
+		"length",
+		length,
+		format_args!("must be > order {order}"),
+	)
+}
+
+/// Returns weights of the least squares polynomial fit of order `order` over the window of size `length`
+/// for evaluating `derivative` of the polynomial `at` bars ahead of the current bar (from the oldest value to the newest)
+#[allow(
+	trivial_numeric_casts,
+	clippy::cast_possible_truncation,
+	clippy::cast_possible_wrap,
+	clippy::cast_lossless
+)]
+fn polynomial_weights(length: PeriodType, order: u8, derivative: u8, at: f64) -> Vec<ValueType> {
+	let length = length as usize;
+	let size = order as usize + 1;
+
+	// bars are mapped into [-1; 1] for better conditioning of the normal equations
+	let center = (length - 1) as f64 / 2.0;
+	let scale = center.max(1.0);
+	let position = |x: f64| (x + center) / scale;
+	let points: Vec<f64> = (0..length)
+		.map(|i| position(i as f64 - (length - 1) as f64))
+		.collect();
+
+	let mut gram = vec![vec![0.0; size + 1]; size];
+	for (row, gram_row) in gram.iter_mut().enumerate() {
+		for (column, cell) in gram_row.iter_mut().take(size).enumerate() {
+			*cell = points.iter().map(|u| u.powi((row + column) as i32)).sum();
+		}
+	}
+
+	// right side is `derivative` of every power of `u` at the point `at`, scaled back to bars
+	let (at, derivative) = (position(at), derivative as usize);
+	for (power, gram_row) in gram.iter_mut().enumerate() {
+		gram_row[size] = if power < derivative {
+			0.0
+		} else {
+			let factor: usize = (power + 1 - derivative..=power).product();
+			factor as f64 * at.powi((power - derivative) as i32) / scale.powi(derivative as i32)
+		};
+	}
+
+	// Gauss-Jordan elimination with partial pivoting
+	for column in 0..size {
+		let pivot = (column..size)
+			.max_by(|&a, &b| gram[a][column].abs().total_cmp(&gram[b][column].abs()))
+			.unwrap_or(column);
+		gram.swap(column, pivot);
+
+		let pivot_row = gram[column].clone();
+		for (row, gram_row) in gram.iter_mut().enumerate() {
+			if row != column {
+				let k = gram_row[column] / pivot_row[column];
+				gram_row
+					.iter_mut()
+					.zip(&pivot_row)
+					.for_each(|(cell, &x)| *cell -= k * x);
+			}
+		}
+	}
+
+	let solution: Vec<f64> = gram
+		.iter()
+		.enumerate()
+		.map(|(i, row)| row[size] / row[i])
+		.collect();
+
+	points
+		.iter()
+		.map(|&u| {
+			solution
+				.iter()
+				.enumerate()
+				.map(|(power, z)| z * u.powi(power as i32))
+				.sum::<f64>() as ValueType
+		})
+		.collect()
+}
+
+/// Causal [Savitzky–Golay filter](https://en.wikipedia.org/wiki/Savitzky%E2%80%93Golay_filter) over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// Fits a polynomial of order `order` to the last `length` values by the least squares method
+/// and returns the value (`derivative` = `0`), the slope (`derivative` = `1`) or the curvature (`derivative` = `2`)
+/// of the polynomial at the current bar. Derivatives are measured per bar.
+///
+/// Polynomial fit follows the trend much better than the moving averages do,
+/// and its derivatives are much less noisy than the [`Derivative`](crate::methods::Derivative) of the values.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `order`: [`u8`], `derivative`: [`u8`]\)
+///
+/// `order` should be <= `10` and < `length`
+///
+/// `derivative` should be <= `order`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::SavitzkyGolay;
+///
+/// let mut value = SavitzkyGolay::new((5, 2, 0), &0.0).unwrap();
+/// let mut slope = SavitzkyGolay::new((5, 2, 1), &0.0).unwrap();
+/// let mut curvature = SavitzkyGolay::new((5, 2, 2), &0.0).unwrap();
+///
+/// // y = x² - 1.5x + 3
+/// for x in 0..10 {
+///     let x = x as f64;
+///     let y = x * x - 1.5 * x + 3.0;
+///
+///     assert!((value.next(&y) - y).abs() < 1e-9 || x < 4.0);
+///     assert!((slope.next(&y) - (2.0 * x - 1.5)).abs() < 1e-9 || x < 4.0);
+///     assert!((curvature.next(&y) - 2.0).abs() < 1e-9 || x < 4.0);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`PolyReg`], [`Conv`], [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SavitzkyGolay(Conv);
+
+impl SavitzkyGolay {
+	/// Returns weights of the filter for [`Conv::new_raw`] (from the oldest value to the newest)
+	///
+	/// # Errors
+	///
+	/// Returns an error if parameters are invalid
+	pub fn weights(length: PeriodType, order: u8, derivative: u8) -> Result<Vec<ValueType>, Error> {
+		validate(length, order)?;
+		Error::ensure(
+			derivative <= order,
+			"derivative",
+			derivative,
+			format_args!("must be <= order {order}"),
+		)?;
+
+		Ok(polynomial_weights(length, order, derivative, 0.0))
+	}
+}
+
+impl Method for SavitzkyGolay {
+	type Params = (PeriodType, u8, u8);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, order, derivative): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Conv::new_raw(Self::weights(length, order, derivative)?, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for SavitzkyGolay {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// Rolling [polynomial regression](https://en.wikipedia.org/wiki/Polynomial_regression) over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// Fits a polynomial of order `order` to the last `length` values by the least squares method
+/// and returns its value `ahead` bars ahead of the current bar.
+///
+/// When `ahead` is `0`, it is the same as smoothing [`SavitzkyGolay`] filter.
+/// When `order` is `1`, it is the same as [`LinRegStats::forecast`](crate::methods::LinRegStats::forecast).
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `order`: [`u8`], `ahead`: [`PeriodType`]\)
+///
+/// `order` should be <= `10` and < `length`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PolyReg;
+///
+/// // forecast of the parabola 2 bars ahead
+/// let mut poly_reg = PolyReg::new((4, 2, 2), &1.0).unwrap();
+///
+/// poly_reg.next(&4.0);
+/// poly_reg.next(&9.0);
+///
+/// // values [1.0, 4.0, 9.0, 16.0]
+/// assert!((poly_reg.next(&16.0) - 36.0).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`SavitzkyGolay`], [`LinReg`](crate::methods::LinReg)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolyReg(Conv);
+
+impl Method for PolyReg {
+	type Params = (PeriodType, u8, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, order, ahead): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		validate(length, order)?;
+
+		let weights = polynomial_weights(length, order, 0, ahead as f64);
+		Conv::new_raw(weights, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(value)
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for PolyReg {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops, trivial_numeric_casts)]
+mod tests {
+	use super::{Method, PolyReg, SavitzkyGolay as TestingMethod};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{assert_near, test_const_float, test_warmup};
+	use crate::methods::{LinReg, LinRegStats, SMA};
+
+	/// Checks that the forecast `value` is near to the `expected` one
+	///
+	/// Forecasts extrapolate the rounding errors of the fitted values, which are noticeable with `f32` values,
+	/// so they are compared with a looser precision than [`assert_near`] uses.
+	fn assert_forecast_near(expected: ValueType, value: ValueType) {
+		if cfg!(feature = "value_type_f32") {
+			assert!(
+				(expected - value).abs() <= 1e-2 * expected.abs().max(1.0),
+				"expected={expected}, value={value}"
+			);
+		} else {
+			assert_near(expected, value);
+		}
+	}
+
+	/// Returns value of `derivative` of the polynomial with `coefficients` at `x`
+	fn polynomial(coefficients: &[ValueType], derivative: usize, x: ValueType) -> ValueType {
+		coefficients
+			.iter()
+			.enumerate()
+			.skip(derivative)
+			.map(|(power, c)| {
+				let factor: usize = (power + 1 - derivative..=power).product();
+				#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+				let power = (power - derivative) as i32;
+				c * factor as ValueType * x.powi(power)
+			})
+			.sum()
+	}
+
+	#[test]
+	fn test_savitzky_golay_const() {
+		for order in 0..=4 {
+			for derivative in 0..=order {
+				for i in (order as PeriodType + 1)..255 {
+					let input = (i as ValueType + 56.0) / 16.3251;
+					let mut method = TestingMethod::new((i, order, derivative), &input).unwrap();
+
+					let output = if derivative == 0 { input } else { 0.0 };
+					test_const_float(&mut method, &input, output);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_savitzky_golay_polynomial() {
+		// polynomial of the same order is fitted exactly, so as its derivatives
+		const COEFFICIENTS: [ValueType; 5] = [1.5, -2.0, 0.7, 0.25, -0.1];
+		const STEP: ValueType = 0.02;
+
+		for order in 0..=4_u8 {
+			let coefficients = &COEFFICIENTS[..=order as usize];
+
+			for derivative in 0..=order {
+				for length in [order as PeriodType + 1, 7, 10, 20, 33, 64] {
+					if length <= order as PeriodType {
+						continue;
+					}
+
+					let mut method =
+						TestingMethod::new((length, order, derivative), &coefficients[0]).unwrap();
+
+					for i in 0..100 {
+						let x = i as ValueType * STEP;
+						let value = method.next(&polynomial(coefficients, 0, x));
+
+						if i >= length as usize {
+							let expected = polynomial(coefficients, derivative as usize, x)
+								* STEP.powi(derivative.into());
+							assert_near(expected, value);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_savitzky_golay_low_orders() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in [
+			2, 3, 4, 5, 7, 10, 11, 13, 17, 20, 21, 25, 70, 77, 100, 125, 128, 173, 254,
+		] {
+			let mut sma = SMA::new(length, &src[0]).unwrap();
+			let mut lin_reg = LinReg::new(length, &src[0]).unwrap();
+			let mut smooth0 = TestingMethod::new((length, 0, 0), &src[0]).unwrap();
+			let mut smooth1 = TestingMethod::new((length, 1, 0), &src[0]).unwrap();
+			let mut slope1 = TestingMethod::new((length, 1, 1), &src[0]).unwrap();
+
+			for x in &src {
+				assert_near(sma.next(x), smooth0.next(x));
+				assert_near(lin_reg.next(x), smooth1.next(x));
+				assert_near(lin_reg.tan(), slope1.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_savitzky_golay_weights() {
+		// classic symmetric 5-points quadratic smoothing coefficients are (-3, 12, 17, 12, -3) / 35,
+		// causal filter evaluates the same parabola at the last point
+		let weights = TestingMethod::weights(5, 2, 0).unwrap();
+		let expected = [3.0, -5.0, -3.0, 9.0, 31.0].map(|x| x / 35.0);
+
+		assert_eq!(weights.len(), expected.len());
+		weights
+			.iter()
+			.zip(expected)
+			.for_each(|(&w, e)| assert_near(e, w));
+
+		// derivative weights sum to zero
+		for length in 3..100 {
+			for derivative in 1..=2 {
+				let weights = TestingMethod::weights(length, 2, derivative).unwrap();
+				assert_near(0.0, weights.iter().sum());
+			}
+		}
+	}
+
+	#[test]
+	fn test_savitzky_golay_parameters() {
+		assert!(TestingMethod::new((0, 0, 0), &1.0).is_err());
+		assert!(TestingMethod::new((1, 0, 0), &1.0).is_ok());
+		assert!(TestingMethod::new((3, 3, 0), &1.0).is_err());
+		assert!(TestingMethod::new((4, 3, 3), &1.0).is_ok());
+		assert!(TestingMethod::new((4, 2, 3), &1.0).is_err());
+		assert!(TestingMethod::new((20, 10, 2), &1.0).is_ok());
+		assert!(TestingMethod::new((20, 11, 2), &1.0).is_err());
+	}
+
+	#[test]
+	fn test_savitzky_golay_warmup() {
+		for order in 0..=3 {
+			for derivative in 0..=order {
+				for length in (order as PeriodType + 2)..100 {
+					test_warmup::<TestingMethod>((length, order, derivative));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_poly_reg_const() {
+		for order in 0..=4 {
+			for i in (order as PeriodType + 1)..255 {
+				let input = (i as ValueType + 56.0) / 16.3251;
+				let mut method = PolyReg::new((i, order, 3), &input).unwrap();
+
+				test_const_float(&mut method, &input, input);
+			}
+		}
+	}
+
+	#[test]
+	fn test_poly_reg_polynomial() {
+		const COEFFICIENTS: [ValueType; 4] = [-0.5, 1.0, 0.3, -0.15];
+		const STEP: ValueType = 0.03;
+
+		for order in 0..=3_u8 {
+			let coefficients = &COEFFICIENTS[..=order as usize];
+
+			for ahead in [0, 1, 5, 20] {
+				for length in [order as PeriodType + 1, 8, 15, 40] {
+					if length <= order as PeriodType {
+						continue;
+					}
+
+					let mut method =
+						PolyReg::new((length, order, ahead), &coefficients[0]).unwrap();
+
+					for i in 0..100 {
+						let x = i as ValueType * STEP;
+						let value = method.next(&polynomial(coefficients, 0, x));
+
+						if i >= length as usize {
+							let x = (i + ahead as usize) as ValueType * STEP;
+							assert_forecast_near(polynomial(coefficients, 0, x), value);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_poly_reg_linear() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in [
+			3, 4, 5, 7, 10, 11, 13, 17, 20, 21, 25, 70, 77, 100, 125, 128, 173, 254,
+		] {
+			for ahead in [0, 1, 3, 10] {
+				let mut lin_reg = LinRegStats::new(length, &src[0]).unwrap();
+				let mut poly_reg = PolyReg::new((length, 1, ahead), &src[0]).unwrap();
+
+				for x in &src {
+					lin_reg.next(x);
+					assert_forecast_near(lin_reg.forecast(ahead), poly_reg.next(x));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_poly_reg_parameters() {
+		assert!(PolyReg::new((0, 0, 0), &1.0).is_err());
+		assert!(PolyReg::new((1, 0, 10), &1.0).is_ok());
+		assert!(PolyReg::new((3, 3, 0), &1.0).is_err());
+		assert!(PolyReg::new((4, 3, 1), &1.0).is_ok());
+		assert!(PolyReg::new((20, 11, 0), &1.0).is_err());
+	}
+
+	#[test]
+	fn test_poly_reg_warmup() {
+		for order in 0..=3 {
+			for ahead in [0, 2] {
+				for length in (order as PeriodType + 2)..100 {
+					test_warmup::<PolyReg>((length, order, ahead));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_poly_reg_smoothing() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for order in 0..=4 {
+			let mut poly_reg = PolyReg::new((30, order, 0), &src[0]).unwrap();
+			let mut savitzky_golay = TestingMethod::new((30, order, 0), &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(savitzky_golay.next(x), poly_reg.next(x));
+			}
+		}
+	}
+}
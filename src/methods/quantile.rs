@@ -0,0 +1,603 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Interpolation rule for a quantile, which lies between two sorted values of the window
+///
+/// For the window of `n` values sorted in ascending order `x[0] <= x[1] <= ... <= x[n-1]`
+/// the quantile `p` lies at the position `h = (n - 1) * p`, which is usually between `x[i]` and `x[j]`, where `i = floor(h)` and `j = ceil(h)`.
+///
+/// This is the same set of rules, which is used by `numpy.quantile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Interpolation {
+	/// `x[i] + (h - i) * (x[j] - x[i])`. This is the default rule.
+	#[default]
+	Linear,
+
+	/// `x[i]`
+	Lower,
+
+	/// `x[j]`
+	Higher,
+
+	/// `x[i]` or `x[j]`, whichever is closer to `h`. When `h` is exactly in the middle, `x[j]` is returned.
+	Nearest,
+
+	/// `(x[i] + x[j]) / 2`
+	Midpoint,
+}
+
+/// Rolling [quantile](https://en.wikipedia.org/wiki/Quantile) of specified `length` for timeseries of type [`ValueType`]
+///
+/// Values of the window are kept in an order-statistics tree, so any quantile of the window may be evaluated at any moment.
+/// So, besides the main quantile `p`, which is returned by [`next`](Method::next), you may get any other quantiles of the same window with
+/// [`Quantile::quantile`] without creating another instance of the method.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters (`length`: [`PeriodType`], `p`: [`ValueType`], `interpolation`: [`Interpolation`])
+///
+/// `length` should be > `0`
+///
+/// `p` should be in \[`0.0`; `1.0`\]
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Interpolation, Quantile};
+///
+/// // Rolling 3rd quartile over the last 5 values
+/// let mut q = Quantile::new((5, 0.75, Interpolation::Linear), &1.0).unwrap();
+///
+/// for value in [5.0, 3.0, 2.0, 4.0] {
+///     q.next(&value);
+/// }
+///
+/// // Window is [1, 5, 3, 2, 4]
+/// assert_eq!(q.peek(), 4.0);
+/// assert_eq!(q.quantile(0.125), 1.5);
+/// assert_eq!(q.interquartile_range(), 2.0);
+/// assert_eq!(q.quantile_with(0.3, Interpolation::Nearest), 2.0);
+/// assert_eq!(q.quantile_with(0.3, Interpolation::Higher), 3.0);
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`))
+///
+/// For the median of short windows [`SMM`](crate::methods::SMM) is still faster, because its O(`length`) shift of a sorted array
+/// has a very small constant. `Quantile` pays off on windows of thousands of values or when several quantiles of the same window are needed.
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+pub struct Quantile {
+	p: ValueType,
+	interpolation: Interpolation,
+	window: Window<ValueType>,
+	tree: OrderStatistics,
+}
+
+impl Quantile {
+	fn validate(length: PeriodType, p: ValueType) -> Result<(), Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+		Error::ensure((0.0..=1.0).contains(&p), "p", p, "must be in [0; 1]")
+	}
+
+	/// Returns quantile `p` of the current window using the method's [`Interpolation`]
+	///
+	/// `p` is clamped to \[`0.0`; `1.0`\]
+	#[must_use]
+	pub fn quantile(&self, p: ValueType) -> ValueType {
+		self.quantile_with(p, self.interpolation)
+	}
+
+	/// Returns quantile `p` of the current window using the given [`Interpolation`]
+	///
+	/// `p` is clamped to \[`0.0`; `1.0`\]
+	#[must_use]
+	pub fn quantile_with(&self, p: ValueType, interpolation: Interpolation) -> ValueType {
+		self.tree.quantile(p, interpolation)
+	}
+
+	/// Returns difference between the 3rd and the 1st quartiles of the current window
+	#[must_use]
+	pub fn interquartile_range(&self) -> ValueType {
+		self.quantile(0.75) - self.quantile(0.25)
+	}
+
+	/// Returns inner [`Window`](crate::core::Window). Useful for implementing in other methods and indicators.
+	#[inline]
+	#[must_use]
+	pub const fn get_window(&self) -> &Window<ValueType> {
+		&self.window
+	}
+}
+
+impl Method for Quantile {
+	type Params = (PeriodType, ValueType, Interpolation);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, p, interpolation): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		Self::validate(length, p)?;
+
+		let window = Window::new(length, value);
+
+		Ok(Self {
+			p,
+			interpolation,
+			tree: OrderStatistics::from_values(window.as_slice()),
+			window,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		assert!(
+			value.is_finite(),
+			"Quantile method cannot operate with NAN values"
+		);
+
+		let old_value = self.window.push(value);
+		self.tree.replace(old_value, value);
+
+		self.peek()
+	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Quantile {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.quantile(self.p)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Quantile {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut s = serializer.serialize_struct("Quantile", 3)?;
+		s.serialize_field("p", &self.p)?;
+		s.serialize_field("interpolation", &self.interpolation)?;
+		s.serialize_field("window", &self.window)?;
+		s.end()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quantile {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		#[derive(Deserialize)]
+		struct DeserializedQuantile {
+			p: ValueType,
+			interpolation: Interpolation,
+			window: Window<ValueType>,
+		}
+
+		let de = DeserializedQuantile::deserialize(deserializer)?;
+
+		Self::validate(de.window.len(), de.p).map_err(serde::de::Error::custom)?;
+
+		if !de.window.as_slice().iter().all(|x| x.is_finite()) {
+			return Err(serde::de::Error::custom(
+				"Quantile cannot operate NaN values",
+			));
+		}
+
+		Ok(Self {
+			p: de.p,
+			interpolation: de.interpolation,
+			tree: OrderStatistics::from_values(de.window.as_slice()),
+			window: de.window,
+		})
+	}
+}
+
+/// Index of the sentinel node, which is used instead of missing children
+const NIL: usize = 0;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+	value: ValueType,
+	size: usize,
+	priority: u32,
+	left: usize,
+	right: usize,
+}
+
+/// [Treap](https://en.wikipedia.org/wiki/Treap) of a fixed count of values with subtree sizes
+///
+/// Nodes are stored in a single arena. When the oldest value is replaced by a new one, the node is reused,
+/// so the tree never allocates after creation.
+#[derive(Debug, Clone)]
+struct OrderStatistics {
+	nodes: Vec<Node>,
+	root: usize,
+	seed: u32,
+}
+
+impl OrderStatistics {
+	fn from_values(values: &[ValueType]) -> Self {
+		let sentinel = Node {
+			value: 0.0,
+			size: 0,
+			priority: 0,
+			left: NIL,
+			right: NIL,
+		};
+
+		let mut tree = Self {
+			nodes: Vec::with_capacity(values.len() + 1),
+			root: NIL,
+			seed: 0x9E37_79B9,
+		};
+		tree.nodes.push(sentinel);
+
+		for &value in values {
+			let index = tree.nodes.len();
+			let priority = tree.next_priority();
+
+			tree.nodes.push(Node {
+				value,
+				size: 1,
+				priority,
+				left: NIL,
+				right: NIL,
+			});
+
+			tree.root = tree.insert(tree.root, index);
+		}
+
+		tree
+	}
+
+	/// Removes one of the nodes, which is equal to `old_value` and inserts `value`
+	fn replace(&mut self, old_value: ValueType, value: ValueType) {
+		let mut index = NIL;
+		self.root = self.remove(self.root, old_value, &mut index);
+
+		debug_assert!(
+			index != NIL,
+			"Trying to remove a value, which is not in the tree"
+		);
+
+		let priority = self.next_priority();
+		self.nodes[index] = Node {
+			value,
+			size: 1,
+			priority,
+			left: NIL,
+			right: NIL,
+		};
+
+		self.root = self.insert(self.root, index);
+	}
+
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::cast_precision_loss
+	)]
+	fn quantile(&self, p: ValueType, interpolation: Interpolation) -> ValueType {
+		let last = self.size(self.root) - 1;
+		let h = last as ValueType * p.clamp(0.0, 1.0);
+
+		let lower = (h.floor() as usize).min(last);
+		let higher = (h.ceil() as usize).min(last);
+
+		match interpolation {
+			Interpolation::Lower => self.nth(lower),
+			Interpolation::Higher => self.nth(higher),
+			Interpolation::Nearest => {
+				if h - (lower as ValueType) < 0.5 {
+					self.nth(lower)
+				} else {
+					self.nth(higher)
+				}
+			}
+			Interpolation::Midpoint => (self.nth(lower) + self.nth(higher)) * 0.5,
+			Interpolation::Linear => {
+				let x = self.nth(lower);
+
+				if lower == higher {
+					x
+				} else {
+					(self.nth(higher) - x).mul_add(h - lower as ValueType, x)
+				}
+			}
+		}
+	}
+
+	/// Returns `n`-th smallest value, starting from `0`
+	fn nth(&self, mut n: usize) -> ValueType {
+		let mut index = self.root;
+
+		loop {
+			let node = &self.nodes[index];
+			let left_size = self.size(node.left);
+
+			match n.cmp(&left_size) {
+				Ordering::Less => index = node.left,
+				Ordering::Equal => return node.value,
+				Ordering::Greater => {
+					n -= left_size + 1;
+					index = node.right;
+				}
+			}
+		}
+	}
+
+	fn size(&self, index: usize) -> usize {
+		self.nodes[index].size
+	}
+
+	fn update(&mut self, index: usize) {
+		let Node { left, right, .. } = self.nodes[index];
+		let size = self.size(left) + self.size(right) + 1;
+
+		self.nodes[index].size = size;
+	}
+
+	/// Splits the subtree into `(values <= value, values > value)`
+	///
+	/// Equal values must go to the left, so a new node is always placed after all the equal values (same as [`insert`](Self::insert) does).
+	/// Otherwise a series of equal values would degenerate the tree into a list.
+	fn split(&mut self, index: usize, value: ValueType) -> (usize, usize) {
+		if index == NIL {
+			return (NIL, NIL);
+		}
+
+		if self.nodes[index].value <= value {
+			let (left, right) = self.split(self.nodes[index].right, value);
+			self.nodes[index].right = left;
+			self.update(index);
+			(index, right)
+		} else {
+			let (left, right) = self.split(self.nodes[index].left, value);
+			self.nodes[index].left = right;
+			self.update(index);
+			(left, index)
+		}
+	}
+
+	/// Merges two subtrees, where all values of `left` are <= all values of `right`
+	fn merge(&mut self, left: usize, right: usize) -> usize {
+		if left == NIL {
+			return right;
+		}
+
+		if right == NIL {
+			return left;
+		}
+
+		if self.nodes[left].priority > self.nodes[right].priority {
+			self.nodes[left].right = self.merge(self.nodes[left].right, right);
+			self.update(left);
+			left
+		} else {
+			self.nodes[right].left = self.merge(left, self.nodes[right].left);
+			self.update(right);
+			right
+		}
+	}
+
+	fn insert(&mut self, root: usize, index: usize) -> usize {
+		if root == NIL {
+			return index;
+		}
+
+		let value = self.nodes[index].value;
+
+		if self.nodes[index].priority > self.nodes[root].priority {
+			let (left, right) = self.split(root, value);
+			self.nodes[index].left = left;
+			self.nodes[index].right = right;
+		} else if value < self.nodes[root].value {
+			self.nodes[root].left = self.insert(self.nodes[root].left, index);
+			self.update(root);
+			return root;
+		} else {
+			self.nodes[root].right = self.insert(self.nodes[root].right, index);
+			self.update(root);
+			return root;
+		}
+
+		self.update(index);
+		index
+	}
+
+	/// Removes a node with the `value` from the subtree and stores its index into `removed`
+	fn remove(&mut self, index: usize, value: ValueType, removed: &mut usize) -> usize {
+		if index == NIL {
+			return NIL;
+		}
+
+		let node = self.nodes[index];
+
+		let index = match value.partial_cmp(&node.value) {
+			Some(Ordering::Less) => {
+				self.nodes[index].left = self.remove(node.left, value, removed);
+				index
+			}
+			Some(Ordering::Greater) => {
+				self.nodes[index].right = self.remove(node.right, value, removed);
+				index
+			}
+			_ => {
+				*removed = index;
+				return self.merge(node.left, node.right);
+			}
+		};
+
+		self.update(index);
+		index
+	}
+
+	/// [Xorshift](https://en.wikipedia.org/wiki/Xorshift) pseudo random generator
+	const fn next_priority(&mut self) -> u32 {
+		let mut x = self.seed;
+		x ^= x << 13;
+		x ^= x >> 17;
+		x ^= x << 5;
+		self.seed = x;
+		x
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Interpolation, Method, Quantile as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const, test_warmup};
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	fn naive(slice: &[ValueType], p: ValueType, interpolation: Interpolation) -> ValueType {
+		let mut slice = slice.to_vec();
+		slice.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+		let h = (slice.len() - 1) as ValueType * p;
+		let (i, j) = (h.floor() as usize, h.ceil() as usize);
+
+		let nearest = if h - (i as ValueType) < 0.5 { i } else { j };
+
+		match interpolation {
+			Interpolation::Linear => (h - i as ValueType).mul_add(slice[j] - slice[i], slice[i]),
+			Interpolation::Lower => slice[i],
+			Interpolation::Higher => slice[j],
+			Interpolation::Nearest => slice[nearest],
+			Interpolation::Midpoint => slice[i].midpoint(slice[j]),
+		}
+	}
+
+	#[test]
+	fn test_quantile_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 0.3, Interpolation::Linear), &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_quantile1() {
+		let mut candles = RandomCandles::default();
+
+		let mut method =
+			TestingMethod::new((1, 0.95, Interpolation::Linear), &candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, method.next(&x.close));
+		});
+	}
+
+	#[test]
+	fn test_quantile() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(500)
+			.map(|x| x.close.round())
+			.collect();
+
+		let interpolations = [
+			Interpolation::Linear,
+			Interpolation::Lower,
+			Interpolation::Higher,
+			Interpolation::Nearest,
+			Interpolation::Midpoint,
+		];
+
+		for &length in &[1, 2, 3, 4, 5, 11, 20, 51, 100, 254] {
+			let mut method =
+				TestingMethod::new((length, 0.05, Interpolation::Linear), &src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let value = method.next(x);
+
+				let slice: Vec<ValueType> = (0..length as usize)
+					.map(|j| src[i.saturating_sub(j)])
+					.collect();
+
+				assert_eq_float(naive(&slice, 0.05, Interpolation::Linear), value);
+
+				for &interpolation in &interpolations {
+					for &p in &[0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+						assert_eq_float(
+							naive(&slice, p, interpolation),
+							method.quantile_with(p, interpolation),
+						);
+					}
+				}
+			});
+		}
+	}
+
+	#[test]
+	fn test_quantile_parameters() {
+		assert!(TestingMethod::new((0, 0.5, Interpolation::Linear), &1.0).is_err());
+		assert!(TestingMethod::new((10, -0.1, Interpolation::Linear), &1.0).is_err());
+		assert!(TestingMethod::new((10, 1.1, Interpolation::Linear), &1.0).is_err());
+		assert!(TestingMethod::new((10, 1.0, Interpolation::Linear), &ValueType::NAN).is_err());
+	}
+
+	#[test]
+	fn test_quantile_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>((length, 0.5, Interpolation::default()));
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "period_type_u16")]
+	fn test_quantile_long() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(10000)
+			.map(|x| x.close)
+			.collect();
+
+		let length: crate::core::PeriodType = 3000;
+		let mut method =
+			TestingMethod::new((length, 0.95, Interpolation::Linear), &src[0]).unwrap();
+
+		src.iter().enumerate().for_each(|(i, x)| {
+			let value = method.next(x);
+
+			if i % 97 == 0 {
+				let slice: Vec<ValueType> = (0..length as usize)
+					.map(|j| src[i.saturating_sub(j)])
+					.collect();
+
+				assert_eq_float(naive(&slice, 0.95, Interpolation::Linear), value);
+			}
+		});
+	}
+}
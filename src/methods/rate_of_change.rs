@@ -52,7 +52,7 @@ impl Method for RateOfChange {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self(Window::new(length, value))),
 		}
 	}
@@ -63,6 +63,10 @@ impl Method for RateOfChange {
 
 		(value - prev_value) / prev_value
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.len() as usize
+	}
 }
 
 #[cfg(test)]
@@ -325,7 +325,11 @@ impl Method for Renko {
 				volume: 0.0,
 			})
 		} else {
-			Err(Error::WrongMethodParameters)
+			Err(Error::invalid_parameter(
+				"brick_size",
+				brick_size,
+				format_args!("must be in [{}; 1)", ValueType::EPSILON),
+			))
 		}
 	}
 
@@ -83,6 +83,10 @@ impl Method for ReversalSignal {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		self.low.next(value) - self.high.next(value)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.high.warmup_len().max(self.low.warmup_len())
+	}
 }
 
 /// Searches for upper reversal points over last `left`+`right`+1 values of type [`ValueType`]
@@ -159,9 +163,14 @@ impl Method for UpperReversalSignal {
 	fn new(params: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		let (left, right) = params;
 
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
-		}
+		Error::ensure(left > 0, "left", left, "must be > 0")?;
+		Error::ensure(right > 0, "right", right, "must be > 0")?;
+		Error::ensure(
+			left.saturating_add(right) < PeriodType::MAX,
+			"left",
+			left,
+			format_args!("+ right {right} must be < {}", PeriodType::MAX),
+		)?;
 
 		Ok(Self {
 			left,
@@ -214,6 +223,10 @@ impl Method for UpperReversalSignal {
 		self.index = self.index.saturating_add(1);
 		s
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 /// Searches for lower reversal points over last `left`+`right`+1 values of type [`ValueType`]
@@ -293,9 +306,14 @@ impl Method for LowerReversalSignal {
 	fn new(params: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		let (left, right) = params;
 
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
-		}
+		Error::ensure(left > 0, "left", left, "must be > 0")?;
+		Error::ensure(right > 0, "right", right, "must be > 0")?;
+		Error::ensure(
+			left.saturating_add(right) < PeriodType::MAX,
+			"left",
+			left,
+			format_args!("+ right {right} must be < {}", PeriodType::MAX),
+		)?;
 
 		Ok(Self {
 			left,
@@ -348,6 +366,10 @@ impl Method for LowerReversalSignal {
 		self.index = self.index.saturating_add(1);
 		s
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 #[cfg(test)]
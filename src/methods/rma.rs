@@ -1,5 +1,5 @@
 use crate::core::{Error, PeriodType, ValueType};
-use crate::core::{Method, MovingAverage};
+use crate::core::{Method, MovingAverage, Seed};
 use crate::helpers::Peekable;
 
 #[cfg(feature = "serde")]
@@ -51,9 +51,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RMA {
+	length: PeriodType,
 	alpha: ValueType,
 	alpha_rev: ValueType,
 	prev_value: ValueType,
+	seed_length: PeriodType,
+	seed_count: PeriodType,
 }
 
 /// Just an alias for RMA
@@ -62,32 +65,75 @@ pub type MMA = RMA;
 /// Just an alias for RMA
 pub type SMMA = RMA;
 
-impl Method for RMA {
-	type Params = PeriodType;
-	type Input = ValueType;
-	type Output = Self::Input;
-
-	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+impl RMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// See also [`Seed`]
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Seed;
+	/// use yata::methods::RMA;
+	///
+	/// let mut rma = RMA::new_seeded(3, Seed::SMA, &1.0).unwrap();
+	///
+	/// assert_eq!(rma.next(&1.0), 1.0);
+	/// assert_eq!(rma.next(&2.0), 1.5);
+	/// assert_eq!(rma.next(&6.0), 3.0); // simple average of the first 3 values
+	/// assert_eq!(rma.next(&9.0), 5.0);
+	/// ```
+	pub fn new_seeded(length: PeriodType, seed: Seed, &value: &ValueType) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => {
 				let alpha = (length as ValueType).recip();
+				let seed_length = match seed {
+					Seed::Initial => 0,
+					Seed::SMA => length,
+				};
+
 				Ok(Self {
+					length,
 					alpha,
 					alpha_rev: 1. - alpha,
 					prev_value: value,
+					seed_length,
+					seed_count: 0,
 				})
 			}
 		}
 	}
+}
+
+impl Method for RMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Self::new_seeded(length, Seed::Initial, value)
+	}
 
 	#[inline]
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
-		let value = self.alpha.mul_add(value, self.alpha_rev * self.prev_value);
+		let value = if self.seed_count < self.seed_length {
+			self.seed_count += 1;
+			self.prev_value + (value - self.prev_value) / self.seed_count as ValueType
+		} else {
+			self.alpha.mul_add(value, self.alpha_rev * self.prev_value)
+		};
 		self.prev_value = value;
 
 		value
 	}
+
+	fn warmup_len(&self) -> usize {
+		if self.seed_length > 0 {
+			self.seed_length as usize - 1
+		} else {
+			self.length as usize - 1
+		}
+	}
 }
 
 impl MovingAverage for RMA {}
@@ -102,7 +148,7 @@ impl Peekable<<Self as Method>::Output> for RMA {
 #[allow(clippy::suboptimal_flops)]
 mod tests {
 	use super::{Method, RMA as TestingMethod};
-	use crate::core::ValueType;
+	use crate::core::{Seed, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 
 	#[test]
@@ -149,4 +195,30 @@ mod tests {
 			}
 		});
 	}
+
+	#[test]
+	fn test_rma_seeded() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let length = length as usize;
+
+			let mut value2 = 0.;
+
+			for (i, &x) in src.iter().enumerate() {
+				let value = ma.next(&x);
+
+				if i < length {
+					value2 = src[..=i].iter().sum::<ValueType>() / (i + 1) as ValueType;
+				} else {
+					value2 = (x + (length - 1) as ValueType * value2) / (length as ValueType);
+				}
+
+				assert_eq_float(value2, value);
+			}
+		});
+	}
 }
@@ -1,6 +1,6 @@
 use std::convert::TryInto;
 
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, RunningSum, ValueType, Window};
 use crate::core::{Method, MovingAverage};
 use crate::helpers::{Buffered, Peekable};
 
@@ -50,7 +50,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SMA {
 	divider: ValueType,
-	value: ValueType,
+	value: RunningSum,
 	window: Window<ValueType>,
 }
 
@@ -74,7 +74,14 @@ impl SMA {
 	#[must_use]
 	#[deprecated(since = "0.5.1", note = "Use `Peekable::peek` instead")]
 	pub const fn get_last_value(&self) -> ValueType {
-		self.value
+		self.value.value()
+	}
+
+	fn recompute(&mut self) {
+		let mut sum = RunningSum::new(0.0);
+		self.window.iter().for_each(|&x| sum.add(x));
+
+		self.value = RunningSum::new(sum.value() / self.window.len() as ValueType);
 	}
 }
 
@@ -85,10 +92,10 @@ impl Method for SMA {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
 				divider: (length as ValueType).recip(),
-				value,
+				value: RunningSum::new(value),
 				window: Window::new(length, value),
 			}),
 		}
@@ -97,9 +104,17 @@ impl Method for SMA {
 	#[inline]
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
 		let prev_value = self.window.push(value);
-		self.value += (value - prev_value) * self.divider;
+		self.value.add((value - prev_value) * self.divider);
+
+		if cfg!(feature = "stable_sums") && self.window.is_aligned() {
+			self.recompute();
+		}
+
+		self.value.value()
+	}
 
-		self.value
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
 	}
 }
 
@@ -114,7 +129,7 @@ impl Buffered<ValueType> for SMA {
 
 impl Peekable<<Self as Method>::Output> for SMA {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.value
+		self.value.value()
 	}
 }
 
@@ -123,7 +138,13 @@ mod tests {
 	use super::{Method, SMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	#[cfg(not(feature = "stable_sums"))]
 	use crate::methods::tests::test_const;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_const_float;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
+	use crate::methods::tests::test_warmup;
 
 	#[allow(dead_code)]
 	const SIGMA: ValueType = 1e-5;
@@ -135,7 +156,10 @@ mod tests {
 			let mut method = TestingMethod::new(i, &input).unwrap();
 
 			let output = method.next(&input);
+			#[cfg(not(feature = "stable_sums"))]
 			test_const(&mut method, &input, &output);
+			#[cfg(feature = "stable_sums")]
+			test_const_float(&mut method, &input, output);
 		}
 	}
 
@@ -173,4 +197,19 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_sma_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_sma_stable_sums() {
+		test_stable_sums::<TestingMethod>(47, |window| {
+			window.iter().sum::<ValueType>() / window.len() as ValueType
+		});
+	}
 }
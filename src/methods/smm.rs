@@ -150,7 +150,7 @@ impl Method for SMM {
 		}
 
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => {
 				let half = length / 2;
 
@@ -218,6 +218,10 @@ impl Method for SMM {
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 #[cfg(feature = "serde")]
@@ -293,7 +297,7 @@ mod tests {
 	use super::{Method, SMM as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_warmup};
 
 	#[test]
 	fn test_smm_const() {
@@ -360,4 +364,11 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	fn test_smm_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
 }
@@ -1,5 +1,5 @@
 use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{centered_sums, Error, PeriodType, RunningSum, ValueType, Window};
 use crate::helpers::Peekable;
 
 #[cfg(feature = "serde")]
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 
 /// Moving [Standard Deviation](https://en.wikipedia.org/wiki/Standard_deviation) over the window of size `length` for timeseries of type [`ValueType`]
 ///
+/// It is calculated by the rolling [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm),
+/// which does not suffer from the catastrophic cancellation, when values are large relative to their deviation.
+///
 /// # Parameters
 ///
 /// Has a single parameter `length`: [`PeriodType`]
@@ -39,21 +42,29 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Performance
 ///
-/// O(1)
+/// O(1) amortized: mean and sum of squared deviations are recomputed from the window once per `length` values
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StDev {
-	mean: ValueType,
-	val_sum: ValueType,
-	sq_val_sum: ValueType,
+	mean: RunningSum,
+	m2: RunningSum,
 	divider: ValueType,
 	k: ValueType,
 	window: Window<ValueType>,
 }
 
+impl StDev {
+	fn recompute(&mut self) {
+		let (mean, [m2]) = centered_sums(self.window.iter().copied(), |x| [x * x]);
+
+		self.mean = RunningSum::new(mean);
+		self.m2 = RunningSum::new(m2);
+	}
+}
+
 impl Method for StDev {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -61,23 +72,14 @@ impl Method for StDev {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => {
-				let k = ((length - 1) as ValueType).recip();
-
-				let float_length = length as ValueType;
-				let mean = -value;
-				let divider = -float_length.recip();
-
-				Ok(Self {
-					mean,
-					val_sum: value * float_length,
-					sq_val_sum: value * value * float_length,
-					divider,
-					k,
-					window: Window::new(length, value),
-				})
-			}
+			0 | 1 => Err(Error::invalid_parameter("length", length, "must be > 1")),
+			length => Ok(Self {
+				mean: RunningSum::new(value),
+				m2: RunningSum::new(0.0),
+				divider: (length as ValueType).recip(),
+				k: ((length - 1) as ValueType).recip(),
+				window: Window::new(length, value),
+			}),
 		}
 	}
 
@@ -86,24 +88,29 @@ impl Method for StDev {
 		let prev_value = self.window.push(value);
 		let diff = value - prev_value;
 
-		// same as `value * value - prev_value * prev_value`
-		self.sq_val_sum += diff * (value + prev_value);
+		// rolling Welford's update
+		let prev_mean = self.mean.value();
+		self.mean.add(diff * self.divider);
+		let mean = self.mean.value();
 
-		self.val_sum += diff;
-		self.mean += diff * self.divider;
+		self.m2.add(diff * (value - mean + prev_value - prev_mean));
+
+		if self.window.is_aligned() {
+			self.recompute();
+		}
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for StDev {
 	fn peek(&self) -> <Self as Method>::Output {
-		// self.sq_val_sum - self.val_sum * self.mean;
-		let sum = self.val_sum.mul_add(self.mean, self.sq_val_sum);
-
-		(sum * self.k)
-			.abs() // sometimes float values may produce negative values, when sum is really near to zero value
-			.sqrt()
+		// sum of squared deviations may become slightly negative because of rounding errors, when it is really near to zero value
+		(self.m2.value() * self.k).max(0.0).sqrt()
 	}
 }
 
@@ -113,7 +120,9 @@ mod tests {
 	use super::{Method, StDev as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const_float;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
+	use crate::methods::tests::{test_const_float, test_warmup};
 
 	#[test]
 	fn test_st_dev_const() {
@@ -159,4 +168,22 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_st_dev_warmup() {
+		for length in 2..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_st_dev_stable_sums() {
+		test_stable_sums::<TestingMethod>(47, |window| {
+			let length = window.len() as ValueType;
+			let mean = window.iter().sum::<ValueType>() / length;
+
+			(window.iter().map(|x| (x - mean).powi(2)).sum::<ValueType>() / (length - 1.0)).sqrt()
+		});
+	}
 }
@@ -59,7 +59,7 @@ impl Method for SWMA {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => {
 				let left_length = (length + 1) / 2;
 				let right_length = length / 2;
@@ -107,6 +107,10 @@ impl Method for SWMA {
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.left_window.len() + self.right_window.len()) as usize - 1
+	}
 }
 
 impl MovingAverage for SWMA {}
@@ -123,7 +127,7 @@ mod tests {
 	use super::{Method, SWMA as TestingMethod};
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_warmup};
 	use crate::methods::Conv;
 
 	#[test]
@@ -195,4 +199,11 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	fn test_swma_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
 }
@@ -0,0 +1,207 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Tillson T3 Moving Average](https://www.tradingview.com/support/solutions/43000591346-tillson-t3/) of specified `length` for timeseries of type [`ValueType`]
+///
+/// T3 applies the generalized [DEMA](crate::methods::DEMA) three times:
+/// GD(x) = [EMA](crate::methods::EMA)(x) × (`1` + `volume_factor`) - [EMA](crate::methods::EMA)([EMA](crate::methods::EMA)(x)) × `volume_factor`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `volume_factor`: [`ValueType`]\)
+///
+/// `length` should be > `0`
+///
+/// `volume_factor` should be in \[`0.0`; `1.0`\]. `0.7` is commonly used.
+/// When it is `0.0`, T3 is the same as [TMA](crate::methods::TMA).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::T3;
+///
+/// let mut t3 = T3::new((5, 0.7), &1.0).unwrap();
+///
+/// assert!((t3.next(&1.0) - 1.0).abs() < 1e-9);
+/// assert!(t3.next(&2.0) > 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [EMA], [DEMA](crate::methods::DEMA), [TMA](crate::methods::TMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[doc(alias = "Tillson")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct T3 {
+	emas: [EMA; 6],
+	coefficients: [ValueType; 4],
+	value: ValueType,
+}
+
+impl Method for T3 {
+	type Params = (PeriodType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, volume_factor): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Error::ensure(
+			(0.0..=1.0).contains(&volume_factor),
+			"volume_factor",
+			volume_factor,
+			"must be in [0.0; 1.0]",
+		)?;
+
+		let ema = EMA::new(length, value)?;
+
+		// coefficients of the last 4 EMAs (from the last one) after expanding GD(GD(GD(x)))
+		let v = volume_factor;
+		let (v2, v3) = (v * v, v * v * v);
+		let coefficients = [
+			-v3,
+			3.0 * (v2 + v3),
+			-3.0 * v2.mul_add(2.0, v + v3),
+			(v + v2).mul_add(3.0, 1.0 + v3),
+		];
+
+		Ok(Self {
+			emas: [ema; 6],
+			coefficients,
+			value: *value,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let mut emas = [0.0; 6];
+		let mut value = value;
+		for (output, ema) in emas.iter_mut().zip(&mut self.emas) {
+			value = ema.next(&value);
+			*output = value;
+		}
+
+		self.value = self
+			.coefficients
+			.iter()
+			.zip(emas[2..].iter().rev())
+			.fold(0.0, |sum, (&coefficient, &ema)| {
+				coefficient.mul_add(ema, sum)
+			});
+
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.emas.iter().map(Method::warmup_len).sum()
+	}
+}
+
+impl MovingAverage for T3 {}
+
+impl Peekable<<Self as Method>::Output> for T3 {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, T3 as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{EMA, TMA};
+
+	#[test]
+	fn test_t3_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 0.7), &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+		}
+	}
+
+	#[test]
+	fn test_t3_1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new((1, 0.7), &candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(&x.close));
+		});
+	}
+
+	#[test]
+	fn test_t3_tma() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..50 {
+			let mut ma = TestingMethod::new((length, 0.0), &src[0]).unwrap();
+			let mut tma = TMA::new(length, &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(tma.next(x), ma.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_t3() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for volume_factor in [0.0, 0.3, 0.7, 1.0] {
+			for length in 1..50 {
+				let mut ma = TestingMethod::new((length, volume_factor), &src[0]).unwrap();
+
+				let mut emas = [EMA::new(length, &src[0]).unwrap(); 6];
+				let gd = |x: ValueType, emas: &mut [EMA]| {
+					let e1 = emas[0].next(&x);
+					let e2 = emas[1].next(&e1);
+					e1.mul_add(1.0 + volume_factor, -e2 * volume_factor)
+				};
+
+				for x in &src {
+					let (first, rest) = emas.split_at_mut(2);
+					let (second, third) = rest.split_at_mut(2);
+					let value = gd(gd(gd(*x, first), second), third);
+
+					assert_eq_float(value, ma.next(x));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_t3_parameters() {
+		assert!(TestingMethod::new((0, 0.7), &1.0).is_err());
+		assert!(TestingMethod::new((5, -0.1), &1.0).is_err());
+		assert!(TestingMethod::new((5, 1.1), &1.0).is_err());
+	}
+}
@@ -64,6 +64,10 @@ impl Method for TR {
 
 		result
 	}
+
+	fn warmup_len(&self) -> usize {
+		1
+	}
 }
 
 #[cfg(test)]
@@ -68,6 +68,10 @@ impl Method for TRIMA {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		self.sma2.next(&self.sma1.next(value))
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.sma1.warmup_len() + self.sma2.warmup_len()
+	}
 }
 
 impl MovingAverage for TRIMA {}
@@ -89,7 +93,11 @@ mod tests {
 	use super::{Method, TRIMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	#[cfg(not(feature = "stable_sums"))]
 	use crate::methods::tests::test_const;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_const_float;
+	use crate::methods::tests::test_warmup;
 
 	#[test]
 	fn test_trima_const() {
@@ -98,7 +106,10 @@ mod tests {
 			let mut method = TestingMethod::new(i, &input).unwrap();
 
 			let output = method.next(&input);
+			#[cfg(not(feature = "stable_sums"))]
 			test_const(&mut method, &input, &output);
+			#[cfg(feature = "stable_sums")]
+			test_const_float(&mut method, &input, output);
 		}
 	}
 
@@ -145,4 +156,11 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_trima_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
 }
@@ -102,6 +102,10 @@ impl Method for TSI {
 
 		self.peek()
 	}
+
+	fn warmup_len(&self) -> usize {
+		1 + self.ema11.warmup_len() + self.ema12.warmup_len()
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for TSI {
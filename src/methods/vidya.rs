@@ -75,7 +75,11 @@ impl Method for Vidya {
 
 	fn new(length: Self::Params, &input: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 | PeriodType::MAX => Err(Error::WrongMethodParameters),
+			0 | PeriodType::MAX => Err(Error::invalid_parameter(
+				"length",
+				length,
+				format_args!("must be in [1; {})", PeriodType::MAX),
+			)),
 			length => Ok(Self {
 				f: 2. / (1 + length) as ValueType,
 				up_sum: 0.,
@@ -110,6 +114,10 @@ impl Method for Vidya {
 
 		self.last_output
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
 }
 
 impl MovingAverage for Vidya {}
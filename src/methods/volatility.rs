@@ -61,7 +61,7 @@ impl Method for LinearVolatility {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
 				window: Window::new(length, 0.),
 				prev_value: value,
@@ -81,6 +81,10 @@ impl Method for LinearVolatility {
 
 		self.volatility
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for LinearVolatility {
@@ -60,7 +60,7 @@ impl Method for VWMA {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => Ok(Self {
 				sum: value.0 * value.1 * length as ValueType,
 				vol_sum: value.1 * length as ValueType,
@@ -78,6 +78,10 @@ impl Method for VWMA {
 
 		self.sum / self.vol_sum
 	}
+
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
+	}
 }
 
 impl Peekable<<Self as Method>::Output> for VWMA {
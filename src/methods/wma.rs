@@ -1,4 +1,4 @@
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Error, PeriodType, RunningSum, ValueType, Window};
 use crate::core::{Method, MovingAverage};
 use crate::helpers::Peekable;
 
@@ -52,11 +52,28 @@ use serde::{Deserialize, Serialize};
 pub struct WMA {
 	invert_sum: ValueType,
 	float_length: ValueType,
-	total: ValueType,
-	numerator: ValueType,
+	total: RunningSum,
+	numerator: RunningSum,
 	window: Window<ValueType>,
 }
 
+impl WMA {
+	fn recompute(&mut self) {
+		let mut total = RunningSum::new(0.0);
+		let mut numerator = RunningSum::new(0.0);
+		let mut weight = 0.0;
+
+		for &x in self.window.iter_rev() {
+			weight += 1.0;
+			total.add(-x);
+			numerator.add(x * weight);
+		}
+
+		self.total = total;
+		self.numerator = numerator;
+	}
+}
+
 impl Method for WMA {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -64,7 +81,7 @@ impl Method for WMA {
 
 	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::invalid_parameter("length", length, "must be > 0")),
 			length => {
 				let length2 = length as usize;
 				let sum = ((length2 * (length2 + 1)) / 2) as ValueType;
@@ -72,8 +89,8 @@ impl Method for WMA {
 				Ok(Self {
 					invert_sum: sum.recip(),
 					float_length,
-					total: -value * float_length,
-					numerator: value * sum,
+					total: RunningSum::new(-value * float_length),
+					numerator: RunningSum::new(value * sum),
 					window: Window::new(length, value),
 				})
 			}
@@ -84,10 +101,19 @@ impl Method for WMA {
 	fn next(&mut self, &value: &Self::Input) -> Self::Output {
 		let prev_value = self.window.push(value);
 
-		self.numerator += self.float_length.mul_add(value, self.total);
-		self.total += prev_value - value;
+		self.numerator
+			.add(self.float_length.mul_add(value, self.total.value()));
+		self.total.add(prev_value - value);
+
+		if cfg!(feature = "stable_sums") && self.window.is_aligned() {
+			self.recompute();
+		}
+
+		self.peek()
+	}
 
-		self.numerator * self.invert_sum
+	fn warmup_len(&self) -> usize {
+		(self.window.len() as usize).saturating_sub(1)
 	}
 }
 
@@ -95,7 +121,7 @@ impl MovingAverage for WMA {}
 
 impl Peekable<<Self as Method>::Output> for WMA {
 	fn peek(&self) -> <Self as Method>::Output {
-		self.numerator * self.invert_sum
+		self.numerator.value() * self.invert_sum
 	}
 }
 
@@ -105,7 +131,13 @@ mod tests {
 	use super::{Method, WMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	#[cfg(not(feature = "stable_sums"))]
 	use crate::methods::tests::test_const;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_const_float;
+	#[cfg(feature = "stable_sums")]
+	use crate::methods::tests::test_stable_sums;
+	use crate::methods::tests::test_warmup;
 	use crate::methods::Conv;
 
 	#[test]
@@ -115,7 +147,10 @@ mod tests {
 			let mut method = TestingMethod::new(i, &input).unwrap();
 
 			let output = method.next(&input);
+			#[cfg(not(feature = "stable_sums"))]
 			test_const(&mut method, &input, &output);
+			#[cfg(feature = "stable_sums")]
+			test_const_float(&mut method, &input, output);
 		}
 	}
 
@@ -156,4 +191,28 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_wma_warmup() {
+		for length in 1..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "stable_sums")]
+	fn test_wma_stable_sums() {
+		test_stable_sums::<TestingMethod>(47, |window| {
+			let (sum, weights) =
+				window
+					.iter()
+					.zip(1_u16..)
+					.fold((0.0, 0.0), |(sum, weights), (x, weight)| {
+						let weight = ValueType::from(weight);
+						(sum + x * weight, weights + weight)
+					});
+
+			sum / weights
+		});
+	}
 }
@@ -1,5 +1,5 @@
 use crate::core::{Error, PeriodType, ValueType};
-use crate::core::{Method, MovingAverage};
+use crate::core::{Method, MovingAverage, Seed};
 use crate::helpers::Peekable;
 use crate::methods::EMA;
 
@@ -51,23 +51,51 @@ pub struct WSMA(EMA);
 
 const MAX_PERIOD: PeriodType = PeriodType::MAX / 2;
 
+impl WSMA {
+	/// Creates an instance of the method with the given initialization policy
+	///
+	/// With [`Seed::SMA`] the method is seeded with the simple average of the first `length` values, as Wilder did.
+	///
+	/// See also [`Seed`]
+	pub fn new_seeded(length: PeriodType, seed: Seed, value: &ValueType) -> Result<Self, Error> {
+		Error::ensure(length > 0, "length", length, "must be > 0")?;
+		Error::ensure(
+			length <= MAX_PERIOD,
+			"length",
+			length,
+			format_args!("must be <= {MAX_PERIOD}"),
+		)?;
+
+		let seed_length = match seed {
+			Seed::Initial => 0,
+			Seed::SMA => length,
+		};
+
+		Ok(Self(EMA::with_seed_length(
+			length * 2 - 1,
+			seed_length,
+			*value,
+		)?))
+	}
+}
+
 impl Method for WSMA {
 	type Params = PeriodType;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
-		if length > MAX_PERIOD {
-			return Err(Error::WrongMethodParameters);
-		}
-
-		Ok(Self(EMA::new(length * 2 - 1, value)?))
+		Self::new_seeded(length, Seed::Initial, value)
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		self.0.next(value)
 	}
+
+	fn warmup_len(&self) -> usize {
+		self.0.warmup_len()
+	}
 }
 
 impl MovingAverage for WSMA {}
@@ -129,4 +157,25 @@ mod tests {
 			}
 		});
 	}
+
+	#[test]
+	fn test_wsma_seeded() {
+		use crate::core::Seed;
+		use crate::methods::RMA;
+
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..=(255 / 2)).for_each(|length| {
+			let mut wsma = TestingMethod::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+			let mut rma = RMA::new_seeded(length, Seed::SMA, &src[0]).unwrap();
+
+			assert_eq!(wsma.warmup_len(), rma.warmup_len());
+
+			for x in &src {
+				assert_eq_float(rma.next(x), wsma.next(x));
+			}
+		});
+	}
 }
@@ -0,0 +1,153 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::{StDev, SMA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Standard score](https://en.wikipedia.org/wiki/Standard_score) of the current value over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// Z-score is a distance between the current value and [`SMA`] in units of [`StDev`].
+/// When all the values in the window are equal, output value is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZScore;
+///
+/// let mut z_score = ZScore::new(3, &1.0).unwrap();
+///
+/// assert_eq!(z_score.next(&1.0), 0.0);
+///
+/// z_score.next(&2.0);
+///
+/// // mean = 2.0, standard deviation = 1.0
+/// assert_eq!(z_score.next(&3.0), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`PercentRank`](crate::methods::PercentRank), [`SMA`], [`StDev`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZScore {
+	ma: SMA,
+	st_dev: StDev,
+	value: ValueType,
+}
+
+impl Method for ZScore {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			st_dev: StDev::new(length, value)?,
+			ma: SMA::new(length, value)?,
+			value: 0.0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let mean = self.ma.next(value);
+		let st_dev = self.st_dev.next(value);
+
+		// standard deviation of equal values may be a bit greater than zero because of rounding errors
+		self.value = if st_dev > mean.abs() * ValueType::EPSILON {
+			(value - mean) / st_dev
+		} else {
+			0.0
+		};
+
+		self.value
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.st_dev.warmup_len()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for ZScore {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, ZScore as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const_float, test_warmup};
+
+	#[test]
+	fn test_z_score_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_z_score() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let window: Vec<ValueType> =
+					(0..length).map(|j| src[i.saturating_sub(j)]).collect();
+
+				let mean = window.iter().sum::<ValueType>() / length as ValueType;
+				let st_dev = (window.iter().map(|v| (v - mean).powi(2)).sum::<ValueType>()
+					/ (length - 1) as ValueType)
+					.sqrt();
+
+				let value = method.next(x);
+
+				if st_dev > 1e-6 {
+					assert_eq_float((x - mean) / st_dev, value);
+				}
+			});
+		});
+	}
+
+	#[test]
+	fn test_z_score_warmup() {
+		// for `length` = 2 output is always ±1/√2, which is too sensitive to the rounding errors of `StDev`
+		for length in 3..100 {
+			test_warmup::<TestingMethod>(length);
+		}
+	}
+}
@@ -0,0 +1,146 @@
+use crate::core::{Error, Method, MovingAverage, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Zero Lag Exponential Moving Average](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
+///
+/// Removes the lag of [EMA] by applying it to the de-lagged values `2` × value - value\[`lag`\],
+/// where `lag` = (`length` - `1`) / `2`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZLEMA;
+///
+/// // ZLEMA of length=3 with lag=1
+/// let mut zlema = ZLEMA::new(3, &3.0).unwrap();
+///
+/// assert_eq!(zlema.next(&3.0), 3.0);
+/// // no lag on the linear trend
+/// assert_eq!(zlema.next(&6.0), 6.0);
+/// assert_eq!(zlema.next(&9.0), 9.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [EMA], [DEMA](crate::methods::DEMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[doc(alias = "ZeroLag")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZLEMA {
+	ema: EMA,
+	window: Window<ValueType>,
+}
+
+impl Method for ZLEMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		let ema = EMA::new(length, value)?;
+		let lag = (length - 1) / 2;
+
+		Ok(Self {
+			ema,
+			window: Window::new(lag + 1, *value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.window.push(value);
+		let lagged = self.window[self.window.len() - 1];
+
+		self.ema.next(&value.mul_add(2.0, -lagged))
+	}
+
+	fn warmup_len(&self) -> usize {
+		self.window.len() as usize - 1 + self.ema.warmup_len()
+	}
+}
+
+impl MovingAverage for ZLEMA {}
+
+impl Peekable<<Self as Method>::Output> for ZLEMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.ema.peek()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, ZLEMA as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_zlema_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, input);
+		}
+	}
+
+	#[test]
+	fn test_zlema1() {
+		let mut candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(&x.close));
+		});
+	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_zlema() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
+			let lag = (length as usize - 1) / 2;
+			let alpha = 2.0 / (length as ValueType + 1.0);
+
+			let mut value = src[0];
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let delagged = 2.0 * x - src[i.saturating_sub(lag)];
+				value = alpha * delagged + (1.0 - alpha) * value;
+
+				assert_eq_float(value, ma.next(&x));
+			});
+		});
+	}
+}